@@ -0,0 +1,154 @@
+use crate::config::Config;
+use crate::jira::{Board, BoardColumn, Issue, Project, Sprint, User};
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// What was last fetched from Jira, kept in `cache.json` in the config
+/// directory so the next launch can show it before Jira answers, and
+/// `--offline` can show it without Jira at all
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Cache {
+    pub saved_at: Option<DateTime<Utc>>,
+    pub projects: Vec<Project>,
+    pub boards: Vec<Board>,
+    pub current_user: Option<User>,
+    /// Keyed by board id
+    pub board_data: HashMap<u32, BoardCache>,
+}
+
+/// Sprints and issues of one board
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BoardCache {
+    pub saved_at: DateTime<Utc>,
+    pub sprints: Vec<Sprint>,
+    /// Whether `sprints` includes the closed ones
+    pub closed_sprints: bool,
+    pub columns: Vec<BoardColumn>,
+    /// Issues of each sprint that was viewed, keyed by sprint id
+    pub sprint_issues: HashMap<u32, Vec<Issue>>,
+    /// The backlog, or all issues of a kanban board
+    pub backlog: Option<Vec<Issue>>,
+}
+
+impl BoardCache {
+    pub fn new() -> Self {
+        Self {
+            saved_at: Utc::now(),
+            sprints: Vec::new(),
+            closed_sprints: false,
+            columns: Vec::new(),
+            sprint_issues: HashMap::new(),
+            backlog: None,
+        }
+    }
+}
+
+impl Cache {
+    /// The saved cache without entries older than `ttl`, or all of it when
+    /// `ttl` is `None`; empty when nothing was saved yet or the file can't be read
+    pub fn load(ttl: Option<Duration>) -> Self {
+        Self::path().map(|path| Self::load_from(&path, ttl)).unwrap_or_default()
+    }
+
+    fn load_from(path: &Path, ttl: Option<Duration>) -> Self {
+        let Some(mut cache) = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<Cache>(&content).ok())
+        else {
+            return Self::default();
+        };
+        let Some(ttl) = ttl else {
+            return cache;
+        };
+        let oldest = Utc::now() - ttl;
+        if cache.saved_at.is_none_or(|saved| saved < oldest) {
+            return Self::default();
+        }
+        cache.board_data.retain(|_, board| board.saved_at >= oldest);
+        cache
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path()?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Entry for `board_id` to update, created empty if there is none
+    pub fn board(&mut self, board_id: u32) -> &mut BoardCache {
+        let board = self.board_data.entry(board_id).or_insert_with(BoardCache::new);
+        board.saved_at = Utc::now();
+        board
+    }
+
+    fn path() -> Result<PathBuf> {
+        Ok(Config::dir()?.join("cache.json"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Write `cache` to a file of its own in the temp directory
+    fn saved(name: &str, cache: &Cache) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("jira-tui-{}-{}.json", std::process::id(), name));
+        std::fs::write(&path, serde_json::to_string(cache).unwrap()).unwrap();
+        path
+    }
+
+    fn board_saved_at(saved_at: DateTime<Utc>) -> BoardCache {
+        BoardCache { saved_at, ..BoardCache::new() }
+    }
+
+    #[test]
+    fn expired_cache_is_dropped_unless_loaded_without_ttl() {
+        let cache = Cache {
+            saved_at: Some(Utc::now() - Duration::hours(48)),
+            board_data: HashMap::from([(1, board_saved_at(Utc::now() - Duration::hours(48)))]),
+            ..Cache::default()
+        };
+        let path = saved("expired", &cache);
+
+        let fresh = Cache::load_from(&path, Some(Duration::hours(24)));
+        assert!(fresh.saved_at.is_none());
+        assert!(fresh.board_data.is_empty());
+
+        let offline = Cache::load_from(&path, None);
+        assert_eq!(offline.saved_at, cache.saved_at);
+        assert!(offline.board_data.contains_key(&1));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn boards_expire_on_their_own() {
+        let cache = Cache {
+            saved_at: Some(Utc::now()),
+            board_data: HashMap::from([
+                (1, board_saved_at(Utc::now() - Duration::hours(1))),
+                (2, board_saved_at(Utc::now() - Duration::hours(48))),
+            ]),
+            ..Cache::default()
+        };
+        let path = saved("boards", &cache);
+
+        let loaded = Cache::load_from(&path, Some(Duration::hours(24)));
+        assert!(loaded.board_data.contains_key(&1));
+        assert!(!loaded.board_data.contains_key(&2));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn missing_file_loads_empty() {
+        let path = std::env::temp_dir().join("jira-tui-no-such-cache.json");
+        let cache = Cache::load_from(&path, None);
+        assert!(cache.saved_at.is_none());
+        assert!(cache.board_data.is_empty());
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -10,16 +11,269 @@ pub struct Config {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct JiraConfig {
+    /// Empty or "$ENV" to read `JIRA_DOMAIN`
     pub domain: String,
+    /// Empty or "$ENV" to read `JIRA_USERNAME`
     pub username: String,
+    /// Empty or "$ENV" to read `JIRA_API_TOKEN`; see `resolve_token`
     pub api_token: String,
     pub default_board_id: Option<u32>,
+    /// Where the API token is read from: "config" (default) or "keyring"
+    pub token_source: Option<String>,
+    /// Path the instance lives under, e.g. "/jira" for `https://host/jira`
+    #[serde(default)]
+    pub context_path: String,
+    /// "cloud" (default) or "server" for self-hosted Server/Data Center
+    #[serde(default = "default_deployment")]
+    pub deployment: String,
+    /// Platform REST API version, overriding the one `deployment` implies
+    #[serde(default)]
+    pub api_version: Option<String>,
+    /// Custom field holding the "Flagged" impediment marker; the id varies by instance
+    #[serde(default = "default_flag_field")]
+    pub flag_field: String,
+    /// Custom field holding story point estimates; the id varies by instance
+    #[serde(default = "default_story_points_field")]
+    pub story_points_field: String,
+    /// Send requests here instead of to `domain`, e.g. a staging proxy or fixture server
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Extra fields shown in the issue details, label → field id (e.g. "customfield_10016")
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
+    /// File values of `domain` and `username` that were replaced from the
+    /// environment, restored by `Config::save`
+    #[serde(skip)]
+    from_env: FromEnv,
+}
+
+#[derive(Debug, Clone, Default)]
+struct FromEnv {
+    domain: Option<String>,
+    username: Option<String>,
+}
+
+/// Config value telling jira-tui to read the setting from its environment variable
+const ENV_SENTINEL: &str = "$ENV";
+
+/// Whether a config value is left to its environment variable
+fn wants_env(value: &str) -> bool {
+    value.is_empty() || value == ENV_SENTINEL
+}
+
+/// `var` when it is set to something other than blanks
+fn env_var(var: &str) -> Option<String> {
+    std::env::var(var).ok().filter(|value| !value.trim().is_empty())
+}
+
+fn default_deployment() -> String {
+    "cloud".to_string()
+}
+
+fn default_flag_field() -> String {
+    "customfield_10021".to_string()
+}
+
+fn default_story_points_field() -> String {
+    "customfield_10016".to_string()
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct UiConfig {
+    /// "default", "dark", "light" or "high-contrast"
     pub theme: String,
     pub refresh_interval: u64,
+    /// chrono strftime format used for dates in lists
+    #[serde(default = "default_date_format")]
+    pub date_format: String,
+    /// maxResults requested per page from paginated endpoints
+    #[serde(default = "default_page_size")]
+    pub page_size: u32,
+    /// Most pages fetched for one sprint, backlog or board list; 0 for no limit
+    #[serde(default = "default_max_pages")]
+    pub max_pages: u32,
+    /// Times a failed read is retried, and after how many milliseconds (doubling)
+    #[serde(default = "default_retries")]
+    pub retries: u32,
+    #[serde(default = "default_retry_delay_ms")]
+    pub retry_delay_ms: u64,
+    /// Hours fetched boards, sprints and issues are kept on disk; 0 turns the cache off
+    #[serde(default = "default_cache_ttl_hours")]
+    pub cache_ttl_hours: u64,
+    /// Restrict the backlog to one project's keys on multi-project boards, e.g. "ABC"
+    pub backlog_key_prefix: Option<String>,
+    /// "auto" (from $COLORTERM/$TERM), "truecolor", "256" or "16"
+    #[serde(default = "default_color_depth")]
+    pub color_depth: String,
+    /// Sprint order, newest first: "id" or "start_date"
+    #[serde(default = "default_sprint_sort")]
+    pub sprint_sort: String,
+    /// Open the active sprint rather than the most recent one
+    #[serde(default = "default_prefer_active_sprint")]
+    pub prefer_active_sprint: bool,
+    /// Fetch closed sprints up front instead of on demand (`o` in the sprint selector)
+    #[serde(default)]
+    pub load_closed_sprints: bool,
+    /// Standup summary grouping: "category" (In Progress/Done) or "status"
+    #[serde(default = "default_standup_group")]
+    pub standup_group: String,
+    /// Standup line per issue, with {key}, {summary} and {status} placeholders
+    #[serde(default = "default_standup_item")]
+    pub standup_item: String,
+    /// Parts of an issue copied with `m`: "link", "status", "assignee", "description", "comments"
+    #[serde(default = "default_markdown_sections")]
+    pub markdown_sections: Vec<String>,
+    /// Rows given to the comments pane of the issue details
+    #[serde(default = "default_comments_height")]
+    pub comments_height: u16,
+    /// Wrap long description lines; `false` truncates them for sideways scrolling
+    #[serde(default = "default_wrap_description")]
+    pub wrap_description: bool,
+    /// One-line tab bar and no status bar outside of prompts, for small terminals
+    #[serde(default)]
+    pub compact: bool,
+    /// Rank prompt suggestions and board names by fuzzy match; `false` falls back to substring
+    #[serde(default = "default_fuzzy_matching")]
+    pub fuzzy_matching: bool,
+    /// Emoji markers (🚩, board and project types); `false` uses ASCII such as [!] and [K]
+    #[serde(default = "default_use_emoji")]
+    pub use_emoji: bool,
+    /// Count issues per status category under the sprint, backlog and search lists
+    #[serde(default)]
+    pub show_status_counts: bool,
+    /// Start with done-category issues hidden from the sprint view (`d` shows them)
+    #[serde(default)]
+    pub hide_done_in_sprint: bool,
+    /// Reopen the board, sprint and view (sprint or backlog) that were shown on quit
+    #[serde(default)]
+    pub restore_last_view: bool,
+    /// Glyph and color per issue type name (case-insensitive)
+    #[serde(default = "default_issue_type_icons")]
+    pub issue_type_icons: BTreeMap<String, IssueTypeIcon>,
+    /// Which actions ask before they run
+    #[serde(default)]
+    pub confirm: ConfirmConfig,
+    /// Sprint view groups folded with Space or `zc`, by grouping ("status",
+    /// "assignee" or "epic"); kept up to date by the app
+    #[serde(default)]
+    pub collapsed_groups: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// Which actions ask for confirmation; the destructive ones do by default
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ConfirmConfig {
+    /// Applying a workflow transition from the issue details
+    pub transition: bool,
+    /// Deleting an issue, by typing its key
+    pub delete: bool,
+    /// Actions on all marked issues, such as flagging them
+    pub bulk: bool,
+    /// Closing a sprint once its open issues have a destination
+    pub close_sprint: bool,
+}
+
+impl Default for ConfirmConfig {
+    fn default() -> Self {
+        Self {
+            transition: false,
+            delete: true,
+            bulk: true,
+            close_sprint: true,
+        }
+    }
+}
+
+/// How an issue type is marked in lists and the detail header
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IssueTypeIcon {
+    pub glyph: String,
+    /// A color name ("red", "lightblue") or "#rrggbb"
+    pub color: String,
+}
+
+fn default_date_format() -> String {
+    "%d/%b/%y".to_string()
+}
+
+fn default_page_size() -> u32 {
+    50
+}
+
+fn default_max_pages() -> u32 {
+    40
+}
+
+fn default_retries() -> u32 {
+    3
+}
+
+fn default_retry_delay_ms() -> u64 {
+    500
+}
+
+fn default_cache_ttl_hours() -> u64 {
+    24
+}
+
+fn default_color_depth() -> String {
+    "auto".to_string()
+}
+
+fn default_sprint_sort() -> String {
+    "id".to_string()
+}
+
+fn default_prefer_active_sprint() -> bool {
+    true
+}
+
+fn default_fuzzy_matching() -> bool {
+    true
+}
+
+fn default_use_emoji() -> bool {
+    true
+}
+
+fn default_standup_group() -> String {
+    "category".to_string()
+}
+
+fn default_standup_item() -> String {
+    "- {key}: {summary}".to_string()
+}
+
+fn default_markdown_sections() -> Vec<String> {
+    ["link", "status", "assignee", "description", "comments"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+fn default_comments_height() -> u16 {
+    8
+}
+
+fn default_wrap_description() -> bool {
+    true
+}
+
+fn default_issue_type_icons() -> BTreeMap<String, IssueTypeIcon> {
+    [
+        ("Story", "■", "green"),
+        ("Bug", "●", "red"),
+        ("Task", "✓", "blue"),
+        ("Epic", "⚡", "magenta"),
+        ("Sub-task", "↳", "cyan"),
+        ("Subtask", "↳", "cyan"),
+    ]
+    .into_iter()
+    .map(|(name, glyph, color)| {
+        let icon = IssueTypeIcon { glyph: glyph.to_string(), color: color.to_string() };
+        (name.to_string(), icon)
+    })
+    .collect()
 }
 
 impl Default for Config {
@@ -30,15 +284,194 @@ impl Default for Config {
                 username: "".to_string(),
                 api_token: "".to_string(),
                 default_board_id: None,
+                token_source: None,
+                context_path: String::new(),
+                deployment: default_deployment(),
+                api_version: None,
+                flag_field: default_flag_field(),
+                story_points_field: default_story_points_field(),
+                base_url: None,
+                custom_fields: BTreeMap::new(),
+                from_env: FromEnv::default(),
             },
             ui: UiConfig {
                 theme: "default".to_string(),
                 refresh_interval: 30,
+                date_format: default_date_format(),
+                page_size: default_page_size(),
+                max_pages: default_max_pages(),
+                retries: default_retries(),
+                retry_delay_ms: default_retry_delay_ms(),
+                cache_ttl_hours: default_cache_ttl_hours(),
+                backlog_key_prefix: None,
+                color_depth: default_color_depth(),
+                sprint_sort: default_sprint_sort(),
+                prefer_active_sprint: default_prefer_active_sprint(),
+                load_closed_sprints: false,
+                standup_group: default_standup_group(),
+                standup_item: default_standup_item(),
+                markdown_sections: default_markdown_sections(),
+                comments_height: default_comments_height(),
+                wrap_description: default_wrap_description(),
+                compact: false,
+                fuzzy_matching: default_fuzzy_matching(),
+                use_emoji: default_use_emoji(),
+                show_status_counts: false,
+                hide_done_in_sprint: false,
+                restore_last_view: false,
+                issue_type_icons: default_issue_type_icons(),
+                confirm: ConfirmConfig::default(),
+                collapsed_groups: BTreeMap::new(),
             },
         }
     }
 }
 
+/// Where the app was when it quit, restored on launch with `ui.restore_last_view`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LastView {
+    /// "sprint" or "backlog"
+    pub mode: String,
+    pub board_id: u32,
+    pub sprint_id: Option<u32>,
+}
+
+impl LastView {
+    /// `None` when nothing was saved yet or the file can't be read
+    pub fn load() -> Option<Self> {
+        let content = std::fs::read_to_string(Self::path().ok()?).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path()?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    fn path() -> Result<PathBuf> {
+        Ok(Config::dir()?.join("last_view.json"))
+    }
+}
+
+#[cfg(feature = "keyring")]
+const KEYRING_SERVICE: &str = "jira-tui";
+
+impl JiraConfig {
+    /// Whether this is a self-hosted Server/Data Center install rather than Cloud
+    pub fn is_server(&self) -> bool {
+        matches!(self.deployment.to_lowercase().as_str(), "server" | "datacenter" | "data-center")
+    }
+
+    /// The REST API version to call: `api_version` when set, else "3" on
+    /// Cloud and "2" on Server/Data Center, which have no v3
+    pub fn api_version(&self) -> &str {
+        match &self.api_version {
+            Some(version) => version,
+            None if self.is_server() => "2",
+            None => "3",
+        }
+    }
+
+    pub fn uses_keyring(&self) -> bool {
+        self.token_source.as_deref() == Some("keyring")
+    }
+
+    /// The API token to authenticate with. A token written in the config file
+    /// wins, then `JIRA_API_TOKEN`, then the keyring when `token_source` is
+    /// "keyring". The result is never stored in the config.
+    pub fn resolve_token(&self) -> Result<String> {
+        self.resolve_token_with(env_var, Self::keyring_token)
+    }
+
+    /// `resolve_token` with the environment and keyring looked up through `env`
+    /// and `keyring`
+    fn resolve_token_with(
+        &self,
+        env: impl Fn(&str) -> Option<String>,
+        keyring: impl FnOnce(&Self) -> Result<String>,
+    ) -> Result<String> {
+        if !self.uses_keyring() && !wants_env(&self.api_token) {
+            return Ok(self.api_token.clone());
+        }
+        if let Some(token) = env("JIRA_API_TOKEN") {
+            return Ok(token);
+        }
+        if self.uses_keyring() {
+            keyring(self)
+        } else if self.api_token == ENV_SENTINEL {
+            Err(anyhow!("api_token is \"{}\" but JIRA_API_TOKEN is not set", ENV_SENTINEL))
+        } else {
+            Ok(self.api_token.clone())
+        }
+    }
+
+    /// Take `domain` and `username` from `JIRA_DOMAIN` and `JIRA_USERNAME`
+    /// where the file leaves them empty or "$ENV", read through `env`
+    fn apply_env(&mut self, env: impl Fn(&str) -> Option<String>) {
+        if wants_env(&self.domain)
+            && let Some(domain) = env("JIRA_DOMAIN")
+        {
+            self.from_env.domain = Some(std::mem::replace(&mut self.domain, domain));
+        }
+        if wants_env(&self.username)
+            && let Some(username) = env("JIRA_USERNAME")
+        {
+            self.from_env.username = Some(std::mem::replace(&mut self.username, username));
+        }
+    }
+
+    /// Runs a keyring operation on a plain OS thread. The Linux secret-service
+    /// backend blocks on its own runtime, which panics inside a tokio worker.
+    #[cfg(feature = "keyring")]
+    fn with_keyring_entry<T, F>(&self, op: F) -> keyring::Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(keyring::Entry) -> keyring::Result<T> + Send + 'static,
+    {
+        // One entry per Jira site so several instances can share a username
+        let service = format!("{}:{}", KEYRING_SERVICE, self.domain);
+        let username = self.username.clone();
+        std::thread::spawn(move || op(keyring::Entry::new(&service, &username)?))
+            .join()
+            .unwrap_or_else(|_| Err(keyring::Error::PlatformFailure("keyring thread panicked".into())))
+    }
+
+    #[cfg(feature = "keyring")]
+    pub fn keyring_token(&self) -> Result<String> {
+        self.with_keyring_entry(|entry| entry.get_password()).map_err(|e| {
+            anyhow!(
+                "Could not read the API token for {} from the system keyring: {}. \
+                 Run `jira-tui --store-token` to save it, or set token_source to \"config\".",
+                self.username,
+                e
+            )
+        })
+    }
+
+    #[cfg(feature = "keyring")]
+    pub fn store_keyring_token(&self, token: &str) -> Result<()> {
+        let token = token.to_string();
+        self.with_keyring_entry(move |entry| entry.set_password(&token))
+            .map_err(|e| anyhow!("Could not store the API token in the system keyring: {}", e))
+    }
+
+    #[cfg(not(feature = "keyring"))]
+    pub fn keyring_token(&self) -> Result<String> {
+        Err(anyhow!(
+            "token_source is \"keyring\" but jira-tui was built without the `keyring` feature"
+        ))
+    }
+
+    #[cfg(not(feature = "keyring"))]
+    pub fn store_keyring_token(&self, _token: &str) -> Result<()> {
+        Err(anyhow!("jira-tui was built without the `keyring` feature"))
+    }
+}
+
 impl Config {
     pub fn load() -> Result<Self> {
         let config_path = Self::config_path()?;
@@ -50,7 +483,8 @@ impl Config {
         }
 
         let content = std::fs::read_to_string(&config_path)?;
-        let config: Config = serde_json::from_str(&content)?;
+        let mut config: Config = serde_json::from_str(&content)?;
+        config.jira.apply_env(env_var);
         Ok(config)
     }
 
@@ -60,14 +494,103 @@ impl Config {
         if let Some(parent) = config_path.parent() {
             std::fs::create_dir_all(parent)?;
         }
-
-        let content = serde_json::to_string_pretty(self)?;
-        std::fs::write(&config_path, content)?;
+        std::fs::write(&config_path, self.file_content()?)?;
         Ok(())
     }
 
-    fn config_path() -> Result<PathBuf> {
+    /// The config as `save` writes it: values from the environment stay out of the file
+    fn file_content(&self) -> Result<String> {
+        let mut on_disk = self.clone();
+        let jira = &mut on_disk.jira;
+        if let Some(domain) = jira.from_env.domain.take() {
+            jira.domain = domain;
+        }
+        if let Some(username) = jira.from_env.username.take() {
+            jira.username = username;
+        }
+        Ok(serde_json::to_string_pretty(&on_disk)?)
+    }
+
+    /// Persist only the `ui` section, leaving the rest of the file as it is on disk
+    pub fn save_ui(ui: &UiConfig) -> Result<()> {
+        let mut config = Self::load()?;
+        config.ui = ui.clone();
+        config.save()
+    }
+
+    /// Directory holding the config file and other files written by the app
+    pub fn dir() -> Result<PathBuf> {
         let home = std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE"))?;
-        Ok(PathBuf::from(home).join(".config").join("jira-tui").join("config.json"))
+        Ok(PathBuf::from(home).join(".config").join("jira-tui"))
+    }
+
+    pub fn config_path() -> Result<PathBuf> {
+        Ok(Self::dir()?.join("config.json"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An environment holding only `vars`
+    fn env(vars: &'static [(&'static str, &'static str)]) -> impl Fn(&str) -> Option<String> {
+        |name| vars.iter().find(|(var, _)| *var == name).map(|(_, value)| value.to_string())
+    }
+
+    fn jira(api_token: &str, token_source: Option<&str>) -> JiraConfig {
+        let mut jira = Config::default().jira;
+        jira.api_token = api_token.to_string();
+        jira.token_source = token_source.map(str::to_string);
+        jira
+    }
+
+    fn keyring(_: &JiraConfig) -> Result<String> {
+        Ok("from-keyring".to_string())
+    }
+
+    fn resolve(jira: &JiraConfig, vars: &'static [(&'static str, &'static str)]) -> Result<String> {
+        jira.resolve_token_with(env(vars), keyring)
+    }
+
+    #[test]
+    fn config_token_wins_over_env() {
+        let jira = jira("from-config", None);
+        assert_eq!(resolve(&jira, &[("JIRA_API_TOKEN", "from-env")]).unwrap(), "from-config");
+    }
+
+    #[test]
+    fn env_token_fills_an_empty_or_sentinel_config_token() {
+        for token in ["", ENV_SENTINEL] {
+            let jira = jira(token, None);
+            assert_eq!(resolve(&jira, &[("JIRA_API_TOKEN", "from-env")]).unwrap(), "from-env");
+        }
+    }
+
+    #[test]
+    fn env_token_wins_over_keyring() {
+        let jira = jira("", Some("keyring"));
+        assert_eq!(resolve(&jira, &[("JIRA_API_TOKEN", "from-env")]).unwrap(), "from-env");
+        assert_eq!(resolve(&jira, &[]).unwrap(), "from-keyring");
+    }
+
+    #[test]
+    fn sentinel_token_without_env_is_an_error() {
+        let jira = jira(ENV_SENTINEL, None);
+        assert!(resolve(&jira, &[]).unwrap_err().to_string().contains("JIRA_API_TOKEN is not set"));
+    }
+
+    #[test]
+    fn save_keeps_file_values_over_env_values() {
+        let mut config = Config::default();
+        config.jira.domain = ENV_SENTINEL.to_string();
+        config.jira.username = String::new();
+        config.jira.apply_env(env(&[("JIRA_DOMAIN", "acme.atlassian.net"), ("JIRA_USERNAME", "jane@acme.com")]));
+        assert_eq!(config.jira.domain, "acme.atlassian.net");
+        assert_eq!(config.jira.username, "jane@acme.com");
+
+        let saved: Config = serde_json::from_str(&config.file_content().unwrap()).unwrap();
+        assert_eq!(saved.jira.domain, ENV_SENTINEL);
+        assert_eq!(saved.jira.username, "");
     }
 }
@@ -0,0 +1,288 @@
+// REST API v3 returns rich-text fields as Atlassian Document Format (ADF)
+// documents; v2 and the agile endpoints return plain strings.
+
+use serde::{Deserialize, Deserializer};
+use serde_json::{json, Value};
+
+/// Fence language marking a flattened table, so only real tables are laid out
+/// again rather than any text that happens to start and end with `|`
+pub const TABLE_FENCE: &str = "table";
+
+/// Flatten an ADF document (or any node within one) to plain text
+pub fn to_plain_text(node: &Value) -> String {
+    let mut out = String::new();
+    write_node(node, &mut out);
+    out.trim_end().to_string()
+}
+
+fn write_node(node: &Value, out: &mut String) {
+    match node_type(node) {
+        "text" => out.push_str(node["text"].as_str().unwrap_or("")),
+        "hardBreak" => out.push('\n'),
+        "mention" => out.push_str(&mention_text(node)),
+        "emoji" => out.push_str(
+            attr(node, "text")
+                .or_else(|| attr(node, "shortName"))
+                .unwrap_or(""),
+        ),
+        "inlineCard" => out.push_str(attr(node, "url").unwrap_or("")),
+        "status" => out.push_str(&format!("[{}]", attr(node, "text").unwrap_or("").to_uppercase())),
+        "date" => out.push_str(&date_text(node)),
+        "media" => match attr(node, "alt") {
+            Some(alt) => out.push_str(&format!("[attachment: {}]\n", alt)),
+            None => out.push_str("[attachment]\n"),
+        },
+        "rule" => out.push_str("---\n"),
+        "paragraph" | "heading" => {
+            write_children(node, out);
+            out.push('\n');
+        }
+        // Fenced like Markdown so the detail view can style the block
+        "codeBlock" => {
+            out.push_str("```");
+            out.push_str(attr(node, "language").unwrap_or(""));
+            out.push('\n');
+            write_children(node, out);
+            out.push_str("\n```\n");
+        }
+        "table" => write_table(node, out),
+        "bulletList" | "orderedList" => write_list(node, out),
+        "blockquote" => {
+            let mut quoted = String::new();
+            write_children(node, &mut quoted);
+            write_indented(&quoted, "> ", "> ", out);
+        }
+        _ => write_children(node, out),
+    }
+}
+
+/// Items are marked "• " or "1. "; an item's further lines (more paragraphs,
+/// nested lists) are indented under its text
+fn write_list(node: &Value, out: &mut String) {
+    let ordered = node_type(node) == "orderedList";
+    let start = node["attrs"]["order"].as_u64().unwrap_or(1);
+    let items = node["content"].as_array().map_or(&[][..], |items| items.as_slice());
+    for (i, item) in items.iter().enumerate() {
+        let marker = if ordered { format!("{}. ", start + i as u64) } else { "• ".to_string() };
+        let indent = " ".repeat(marker.chars().count());
+        write_indented(&to_plain_text(item), &marker, &indent, out);
+    }
+}
+
+/// `text` with `first` before its first line and `rest` before the others
+fn write_indented(text: &str, first: &str, rest: &str, out: &mut String) {
+    for (i, line) in text.lines().enumerate() {
+        out.push_str(if i == 0 { first } else { rest });
+        out.push_str(line);
+        out.push('\n');
+    }
+}
+
+/// Date nodes hold a UTC timestamp in milliseconds, as a string
+fn date_text(node: &Value) -> String {
+    attr(node, "timestamp")
+        .and_then(|ms| ms.parse::<i64>().ok())
+        .and_then(chrono::DateTime::from_timestamp_millis)
+        .map(|at| at.format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
+
+/// Tables become Markdown pipe rows, with a `|---|` rule under a header row,
+/// fenced like code so the detail view can lay them out again
+fn write_table(node: &Value, out: &mut String) {
+    let rows = node["content"].as_array().map_or(&[][..], |rows| rows.as_slice());
+    out.push_str(&format!("```{}\n", TABLE_FENCE));
+    for (i, row) in rows.iter().enumerate() {
+        let cells = row["content"].as_array().map_or(&[][..], |cells| cells.as_slice());
+        let texts: Vec<String> = cells
+            .iter()
+            .map(|cell| {
+                // Cells hold paragraphs; keep each row on one line
+                let text = to_plain_text(cell);
+                let lines: Vec<&str> = text.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+                lines.join(" ").replace('|', "\\|")
+            })
+            .collect();
+        out.push_str(&format!("| {} |\n", texts.join(" | ")));
+        if i == 0 && !cells.is_empty() && cells.iter().all(|cell| node_type(cell) == "tableHeader") {
+            out.push_str(&format!("|{}\n", "---|".repeat(cells.len())));
+        }
+    }
+    out.push_str("```\n");
+}
+
+fn write_children(node: &Value, out: &mut String) {
+    if let Some(children) = node["content"].as_array() {
+        for child in children {
+            write_node(child, out);
+        }
+    }
+}
+
+fn node_type(node: &Value) -> &str {
+    node["type"].as_str().unwrap_or("")
+}
+
+fn attr<'a>(node: &'a Value, name: &str) -> Option<&'a str> {
+    node["attrs"][name].as_str().filter(|s| !s.is_empty())
+}
+
+/// Mentions carry the display name at mention time in `attrs.text`
+/// (usually already prefixed with `@`); only the account id is guaranteed.
+fn mention_text(node: &Value) -> String {
+    match attr(node, "text").map(|t| t.trim_start_matches('@')) {
+        Some(name) if !name.is_empty() => format!("@{}", name),
+        _ => "@unknown".to_string(),
+    }
+}
+
+/// Whether `node` holds more than unformatted paragraphs and line breaks, i.e.
+/// flattening it loses mentions, links, tables, code blocks or other markup.
+/// Plain strings (v2 bodies) have nothing to lose.
+pub fn has_formatting(node: &Value) -> bool {
+    if !node.is_object() {
+        return false;
+    }
+    match node_type(node) {
+        "doc" | "paragraph" => node["content"].as_array().is_some_and(|children| children.iter().any(has_formatting)),
+        "text" => node["marks"].as_array().is_some_and(|marks| !marks.is_empty()),
+        "hardBreak" => false,
+        _ => true,
+    }
+}
+
+/// How rich-text fields (descriptions, comments, environment) are written for
+/// a platform REST API version
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextFormat {
+    /// v2 and "latest" on Server/Data Center: plain (wiki markup) strings
+    Plain,
+    /// v3 on Cloud: ADF documents
+    Adf,
+}
+
+impl TextFormat {
+    pub fn for_api_version(api_version: &str) -> Self {
+        if api_version == "3" {
+            TextFormat::Adf
+        } else {
+            TextFormat::Plain
+        }
+    }
+
+    /// Field value for `text` in this format
+    pub fn encode(self, text: &str) -> Value {
+        match self {
+            TextFormat::Plain => json!(text),
+            TextFormat::Adf => from_plain_text(text),
+        }
+    }
+}
+
+/// Wrap plain text in an ADF document, one paragraph per line
+pub fn from_plain_text(text: &str) -> Value {
+    let paragraphs: Vec<Value> = text
+        .lines()
+        .map(|line| {
+            // ADF rejects empty text nodes
+            let content: Vec<Value> = if line.is_empty() {
+                Vec::new()
+            } else {
+                vec![json!({ "type": "text", "text": line })]
+            };
+            json!({ "type": "paragraph", "content": content })
+        })
+        .collect();
+    json!({ "type": "doc", "version": 1, "content": paragraphs })
+}
+
+/// Deserialize a rich-text field that may be a plain string or an ADF
+/// document, keeping a missing/null field as `None`. This goes by the shape of
+/// the value rather than the configured API version: the agile endpoints
+/// return plain strings even where v3 returns ADF.
+pub fn deserialize_optional_text<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(value_text(Value::deserialize(deserializer)?))
+}
+
+/// Text of a rich-text field value: a plain string as is, an ADF document
+/// flattened, `None` for null
+pub fn value_text(value: Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(text),
+        doc => Some(to_plain_text(&doc)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(content: Vec<Value>) -> Value {
+        json!({ "type": "doc", "version": 1, "content": content })
+    }
+
+    fn paragraph(text: &str) -> Value {
+        json!({ "type": "paragraph", "content": [{ "type": "text", "text": text }] })
+    }
+
+    #[test]
+    fn lists_keep_numbering_and_nesting() {
+        let item = |content: Vec<Value>| json!({ "type": "listItem", "content": content });
+        let description = doc(vec![
+            json!({ "type": "orderedList", "attrs": { "order": 1 }, "content": [
+                item(vec![paragraph("Reproduce")]),
+                item(vec![
+                    paragraph("Fix"),
+                    json!({ "type": "bulletList", "content": [item(vec![paragraph("server")]), item(vec![paragraph("client")])] })
+                ])
+            ]}),
+            json!({ "type": "blockquote", "content": [paragraph("Quoted")] }),
+        ]);
+        assert_eq!(
+            to_plain_text(&description),
+            "1. Reproduce\n2. Fix\n   • server\n   • client\n> Quoted"
+        );
+    }
+
+    #[test]
+    fn mentions_show_the_display_name() {
+        let mention = |attrs: Value| json!({ "type": "mention", "attrs": attrs });
+        let comment = doc(vec![json!({ "type": "paragraph", "content": [
+            mention(json!({ "id": "a1", "text": "@Ann" })),
+            { "type": "text", "text": ", " },
+            mention(json!({ "id": "a2", "text": "Bob" })),
+            { "type": "text", "text": " and " },
+            // Only the account id is guaranteed
+            mention(json!({ "id": "a3" })),
+            { "type": "text", "text": ", " },
+            mention(json!({ "id": "a4", "text": "@" }))
+        ]})]);
+        assert_eq!(to_plain_text(&comment), "@Ann, @Bob and @unknown, @unknown");
+    }
+
+    #[test]
+    fn tables_become_pipe_rows() {
+        let cell = |kind: &str, text: &str| json!({ "type": kind, "content": [paragraph(text)] });
+        let row = |cells: Vec<Value>| json!({ "type": "tableRow", "content": cells });
+        let table = doc(vec![json!({ "type": "table", "content": [
+            row(vec![cell("tableHeader", "Env"), cell("tableHeader", "Result")]),
+            row(vec![cell("tableCell", "staging"), cell("tableCell", "pass | flaky")]),
+            row(vec![
+                cell("tableCell", "prod"),
+                json!({ "type": "tableCell", "content": [paragraph("fail"), paragraph("see logs")] })
+            ])
+        ]})]);
+        assert_eq!(
+            to_plain_text(&table),
+            "```table\n| Env | Result |\n|---|---|\n| staging | pass \\| flaky |\n| prod | fail see logs |\n```"
+        );
+
+        // Without a header row there is no rule
+        let table = doc(vec![json!({ "type": "table", "content": [row(vec![cell("tableCell", "a"), cell("tableCell", "b")])] })]);
+        assert_eq!(to_plain_text(&table), "```table\n| a | b |\n```");
+    }
+}
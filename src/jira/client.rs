@@ -1,57 +1,381 @@
-use reqwest::{Client, Method};
+use reqwest::{Client, Method, StatusCode};
 use serde_json::json;
+use crate::jira::adf::TextFormat;
 use crate::jira::models::*;
+use crate::jira::user_cache::UserCache;
+use crate::ui::fuzzy::Matcher;
 use anyhow::Result;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+/// Agile API limit on issues per move-to-sprint/backlog request
+const MAX_MOVE_ISSUES: usize = 50;
+
+/// JQL searches stop paging after this many issues; unscoped queries can match thousands
+const MAX_SEARCH_RESULTS: usize = 200;
+
+/// Longest backoff between retries of failed requests
+const MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Longest `Retry-After` waited out on 429; a longer one fails the request instead
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Quota reported by Jira Cloud's `X-RateLimit-*` / `Retry-After` headers
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RateLimit {
+    pub limit: Option<u32>,
+    pub remaining: Option<u32>,
+    /// Seconds to wait, sent with 429 Too Many Requests
+    pub retry_after: Option<u64>,
+}
+
+impl RateLimit {
+    /// Under 10% of the quota (at least 10 requests) left, or already throttled
+    pub fn is_low(&self) -> bool {
+        if self.retry_after.is_some() {
+            return true;
+        }
+        match (self.remaining, self.limit) {
+            (Some(remaining), Some(limit)) => remaining <= (limit / 10).max(10),
+            (Some(remaining), None) => remaining <= 10,
+            _ => false,
+        }
+    }
+}
+
+/// Cheap to clone: clones share the HTTP connection pool and dry-run state
+#[derive(Clone)]
 pub struct JiraClient {
     client: Client,
     username: String,
     api_token: String,
-    domain: String,
+    /// Scheme and host (plus any proxy path), without the context path
+    base_url: String,
+    context_path: String,
+    api_version: String,
+    /// Follows `api_version`, which decides whether rich text is sent as ADF
+    text_format: TextFormat,
+    page_size: u32,
+    /// Most pages read from one paginated list; 0 for no limit
+    max_pages: u32,
+    dry_run_log: Option<PathBuf>,
+    last_dry_run: Arc<Mutex<Option<String>>>,
+    users: Arc<Mutex<UserCache>>,
+    rate_limit: Arc<Mutex<Option<RateLimit>>>,
+    /// Requests in a row that never reached the server
+    network_failures: Arc<AtomicU32>,
+    /// End of the `Retry-After` wait a rate-limited request is sitting out
+    rate_limited_until: Arc<Mutex<Option<Instant>>>,
+    /// Extra attempts for requests that failed in a way worth retrying
+    retries: u32,
+    /// Wait before the first retry, doubled for each one after
+    retry_delay: Duration,
+    /// Refuse every request, for `--offline`
+    offline: bool,
 }
 
 impl JiraClient {
+    /// Client for a Jira site given by domain; `https://` is assumed when the
+    /// domain has no scheme
     pub fn new(username: String, api_token: String, domain: String) -> Self {
+        let domain = domain.trim_end_matches('/');
+        let base_url = if domain.contains("://") {
+            domain.to_string()
+        } else {
+            format!("https://{}", domain)
+        };
+        Self::with_base_url(&base_url, username, api_token)
+    }
+
+    /// Client for an explicit base URL, e.g. a staging proxy or a local mock server
+    pub fn with_base_url(base_url: &str, username: String, api_token: String) -> Self {
         let client = Client::new();
-        Self { client, username, api_token, domain }
+        Self {
+            client,
+            username,
+            api_token,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            context_path: String::new(),
+            api_version: "3".to_string(),
+            text_format: TextFormat::Adf,
+            page_size: 50,
+            max_pages: 40,
+            dry_run_log: None,
+            last_dry_run: Arc::new(Mutex::new(None)),
+            users: Arc::new(Mutex::new(UserCache::new())),
+            rate_limit: Arc::new(Mutex::new(None)),
+            network_failures: Arc::new(AtomicU32::new(0)),
+            rate_limited_until: Arc::new(Mutex::new(None)),
+            retries: 3,
+            retry_delay: Duration::from_millis(500),
+            offline: false,
+        }
+    }
+
+    /// Path the Jira instance is served under, e.g. "/jira" for `https://host/jira`
+    pub fn with_context_path(mut self, context_path: &str) -> Self {
+        self.context_path = context_path.trim_matches('/').to_string();
+        self
+    }
+
+    /// Platform REST API version used by `send_request` ("2", "3" or "latest")
+    pub fn with_api_version(mut self, api_version: &str) -> Self {
+        self.api_version = api_version.to_string();
+        self.text_format = TextFormat::for_api_version(api_version);
+        self
+    }
+
+    /// maxResults requested from the paginated list endpoints
+    pub fn set_page_size(&mut self, page_size: u32) {
+        self.page_size = page_size;
+    }
+
+    /// Stop paginated list fetches after `max_pages` pages (0 for no limit),
+    /// so a board with thousands of issues can't keep the app fetching forever
+    pub fn set_max_pages(&mut self, max_pages: u32) {
+        self.max_pages = max_pages;
+    }
+
+    /// Retry reads that failed to connect, timed out or got 502/503/504 up to
+    /// `retries` times, waiting `delay`, then twice as long each time. Requests
+    /// answered with 429 are retried whatever the method, since Jira didn't
+    /// run them, after the `Retry-After` wait.
+    pub fn set_retries(&mut self, retries: u32, delay: Duration) {
+        self.retries = retries;
+        self.retry_delay = delay;
+    }
+
+    /// Fail every request from now on instead of sending it
+    pub fn set_offline(&mut self) {
+        self.offline = true;
+    }
+
+    fn last_allowed_page(&self, pages: u32) -> bool {
+        self.max_pages != 0 && pages >= self.max_pages
+    }
+
+    /// In dry-run mode every non-GET request is appended to `log_path`
+    /// instead of being sent, and reported back as a successful empty response.
+    pub fn enable_dry_run(&mut self, log_path: PathBuf) {
+        self.dry_run_log = Some(log_path);
+    }
+
+    pub fn dry_run_log(&self) -> Option<&PathBuf> {
+        self.dry_run_log.as_ref()
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run_log.is_some()
+    }
+
+    /// The most recent request that was logged instead of sent
+    pub fn last_dry_run(&self) -> Option<String> {
+        self.last_dry_run.lock().ok().and_then(|last| last.clone())
+    }
+
+    /// Quota from the most recent response that carried rate-limit headers
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        self.rate_limit.lock().ok().and_then(|limit| *limit)
+    }
+
+    /// Time left before a rate-limited request is sent again, while one is waiting
+    pub fn rate_limit_wait(&self) -> Option<Duration> {
+        let until = self.rate_limited_until.lock().ok().and_then(|until| *until)?;
+        Some(until.saturating_duration_since(Instant::now())).filter(|left| !left.is_zero())
+    }
+
+    /// Requests in a row that failed to connect or timed out; any response resets it
+    pub fn network_failures(&self) -> u32 {
+        self.network_failures.load(Ordering::Relaxed)
+    }
+
+    /// Users seen so far matching `query`, answered locally without a request
+    pub fn cached_users(&self, query: &str, matcher: Matcher) -> Vec<User> {
+        self.users.lock().map(|mut users| users.search(query, matcher)).unwrap_or_default()
+    }
+
+    fn remember_users(&self, issues: &[Issue]) {
+        if let Ok(mut users) = self.users.lock() {
+            users.insert_from_issues(issues);
+        }
     }
 
     pub async fn get_issue(&self, issue_id: &str) -> Result<Issue> {
-        self.send_request(Method::GET, &format!("/issue/{}", issue_id), None).await
+        let issue: Issue = self.send_request(Method::GET, &format!("/issue/{}", issue_id), None).await?;
+        self.remember_users(std::slice::from_ref(&issue));
+        Ok(issue)
     }
 
-    pub async fn get_sprint_issues(&self, board_id: u32, sprint_id: u32) -> Result<Vec<Issue>> {
-        let response: IssuesResponse = self
-            .send_agile_request(
-                Method::GET,
-                &format!(
-                    "/board/{}/sprint/{}/issue",
-                    board_id, sprint_id
-                ),
-                None,
-            )
-            .await?;
+    /// Issues in a sprint, narrowed by `jql` (e.g. a quick filter's) when given
+    pub async fn get_sprint_issues(&self, board_id: u32, sprint_id: u32, jql: Option<&str>) -> Result<Vec<Issue>> {
+        let mut all_issues = Vec::new();
+        let mut pages = 0;
+        loop {
+            let response: IssuesResponse = self
+                .send_agile_request(
+                    Method::GET,
+                    &format!(
+                        "/board/{}/sprint/{}/issue?startAt={}&maxResults={}{}",
+                        board_id,
+                        sprint_id,
+                        all_issues.len(),
+                        self.page_size,
+                        jql_param(jql)
+                    ),
+                    None,
+                )
+                .await?;
 
-        Ok(response.issues)
+            pages += 1;
+            let page_len = response.issues.len();
+            all_issues.extend(response.issues);
+            if response.is_last == Some(true)
+                || page_len == 0
+                || all_issues.len() >= response.total as usize
+                || self.last_allowed_page(pages)
+            {
+                break;
+            }
+        }
+
+        self.remember_users(&all_issues);
+        Ok(all_issues)
+    }
+
+    pub async fn get_backlog(&self, board_id: u32, jql: Option<&str>) -> Result<Vec<Issue>> {
+        let mut all_issues = Vec::new();
+        let mut pages = 0;
+        loop {
+            let response: SearchResponse = self
+                .send_agile_request(
+                    Method::GET,
+                    &format!(
+                        "/board/{}/backlog?startAt={}&maxResults={}{}",
+                        board_id,
+                        all_issues.len(),
+                        self.page_size,
+                        jql_param(jql)
+                    ),
+                    None,
+                )
+                .await?;
+
+            pages += 1;
+            let page_len = response.issues.len();
+            all_issues.extend(response.issues);
+            // The backlog has no isLast flag, so stop on a short page or at the total
+            if page_len == 0 || all_issues.len() >= response.total as usize || self.last_allowed_page(pages) {
+                break;
+            }
+        }
+
+        self.remember_users(&all_issues);
+        Ok(all_issues)
+    }
+
+    /// Every issue on a board, for kanban boards which have no sprints
+    pub async fn get_board_issues(&self, board_id: u32, jql: Option<&str>) -> Result<Vec<Issue>> {
+        let mut all_issues = Vec::new();
+        let mut pages = 0;
+        loop {
+            let response: SearchResponse = self
+                .send_agile_request(
+                    Method::GET,
+                    &format!(
+                        "/board/{}/issue?startAt={}&maxResults={}{}",
+                        board_id,
+                        all_issues.len(),
+                        self.page_size,
+                        jql_param(jql)
+                    ),
+                    None,
+                )
+                .await?;
+
+            pages += 1;
+            let page_len = response.issues.len();
+            all_issues.extend(response.issues);
+            if page_len == 0 || all_issues.len() >= response.total as usize || self.last_allowed_page(pages) {
+                break;
+            }
+        }
+
+        self.remember_users(&all_issues);
+        Ok(all_issues)
+    }
+
+    /// One page of issues matching a JQL query. The query goes in the body,
+    /// so long queries don't run into URL length limits.
+    pub async fn search(&self, jql: &str, start_at: u32, max_results: u32) -> Result<SearchResponse> {
+        self.send_request(
+            Method::POST,
+            "/search",
+            Some(json!({ "jql": jql, "startAt": start_at, "maxResults": max_results })),
+        )
+        .await
+    }
+
+    /// Issues matching a JQL query, up to `MAX_SEARCH_RESULTS`
+    pub async fn search_issues(&self, jql: &str) -> Result<Vec<Issue>> {
+        let mut all_issues = Vec::new();
+        loop {
+            let response = self.search(jql, all_issues.len() as u32, self.page_size).await?;
+
+            let page_len = response.issues.len();
+            all_issues.extend(response.issues);
+            if page_len == 0
+                || all_issues.len() >= response.total as usize
+                || all_issues.len() >= MAX_SEARCH_RESULTS
+            {
+                break;
+            }
+        }
+        all_issues.truncate(MAX_SEARCH_RESULTS);
+
+        self.remember_users(&all_issues);
+        Ok(all_issues)
+    }
+
+    /// Everything assigned to the authenticated user on any board, most
+    /// recently updated first
+    pub async fn get_my_issues(&self) -> Result<Vec<Issue>> {
+        self.search_issues("assignee = currentUser() ORDER BY updated DESC").await
     }
 
-    pub async fn get_backlog(&self, board_id: u32) -> Result<Vec<Issue>> {
-        let response: SearchResponse = self
+    /// Move issues directly before or after `anchor` in rank order
+    pub async fn rank_issues(&self, issue_keys: &[&str], anchor: &str, before: bool) -> Result<()> {
+        let anchor_field = if before { "rankBeforeIssue" } else { "rankAfterIssue" };
+        let response: serde_json::Value = self
             .send_agile_request(
-                Method::GET,
-                &format!("/board/{}/backlog", board_id),
-                None,
+                Method::PUT,
+                "/issue/rank",
+                Some(json!({ "issues": issue_keys, anchor_field: anchor })),
             )
             .await?;
 
-        Ok(response.issues)
+        // A partial failure comes back as 207 Multi-Status with per-issue entries
+        let failed: Vec<String> = response["entries"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|entry| entry["status"].as_u64().is_some_and(|status| status >= 400))
+            .map(|entry| format!("{}: {}", entry["issueKey"].as_str().unwrap_or("?"), entry["errors"]))
+            .collect();
+        if !failed.is_empty() {
+            anyhow::bail!("Ranking failed for {}", failed.join(", "));
+        }
+        Ok(())
     }
 
     pub async fn get_transitions(&self, issue_id: &str) -> Result<Vec<Transition>> {
         let response: TransitionsResponse = self
             .send_request(
                 Method::GET,
-                &format!("/issue/{}/transitions", issue_id),
+                &format!("/issue/{}/transitions?expand=transitions.fields", issue_id),
                 None,
             )
             .await?;
@@ -59,12 +383,67 @@ impl JiraClient {
         Ok(response.transitions)
     }
 
-    pub async fn transition_issue(&self, issue_id: &str, transition_id: &str) -> Result<()> {
+    /// Issue types that can be created in a project, without the sub-task types
+    pub async fn get_issue_types(&self, project_key: &str) -> Result<Vec<IssueType>> {
+        let project: ProjectIssueTypes = self
+            .send_request(Method::GET, &format!("/project/{}", project_key), None)
+            .await?;
+        Ok(project.issue_types.into_iter().filter(|t| !t.subtask).collect())
+    }
+
+    /// Create an issue and fetch it back; `None` in dry-run mode, where nothing is created
+    pub async fn create_issue(
+        &self,
+        project_key: &str,
+        issuetype: &str,
+        summary: &str,
+        description: Option<&str>,
+    ) -> Result<Option<Issue>> {
+        let mut fields = json!({
+            "project": { "key": project_key },
+            "issuetype": { "name": issuetype },
+            "summary": summary,
+        });
+        if let Some(description) = description {
+            fields["description"] = self.rich_text(description);
+        }
+        let created: Option<CreatedIssue> = self
+            .send_request(Method::POST, "/issue", Some(json!({ "fields": fields })))
+            .await?;
+        match created {
+            Some(created) => Ok(Some(self.get_issue(&created.key).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Resolutions defined on the site
+    pub async fn get_resolutions(&self) -> Result<Vec<Resolution>> {
+        self.send_request(Method::GET, "/resolution", None).await
+    }
+
+    /// Transition an issue, optionally adding a comment in the same request
+    /// so the note and the status change are applied together. `resolution`
+    /// is a resolution id, for transitions that require one.
+    pub async fn transition_issue(
+        &self,
+        issue_id: &str,
+        transition_id: &str,
+        comment: Option<&str>,
+        resolution: Option<&str>,
+    ) -> Result<()> {
         let update = IssueUpdate {
-            fields: None,
+            fields: resolution.map(|id| json!({ "resolution": { "id": id } })),
             transition: Some(TransitionRequest {
                 id: transition_id.to_string(),
             }),
+            update: comment.map(|body| {
+                let comment = CommentRequest {
+                    body: self.rich_text(body),
+                    properties: Vec::new(),
+                    visibility: None,
+                };
+                json!({ "comment": [{ "add": comment }] })
+            }),
         };
 
         self.send_request(
@@ -86,18 +465,301 @@ impl JiraClient {
         .map(|_: serde_json::Value| ())
     }
 
-    pub async fn add_comment(&self, issue_id: &str, comment: &str) -> Result<()> {
+    /// Assign an issue to `account_id`, or unassign it with `None`
+    pub async fn assign_issue(&self, issue_id: &str, account_id: Option<&str>) -> Result<()> {
+        self.send_request(
+            Method::PUT,
+            &format!("/issue/{}/assignee", issue_id),
+            Some(json!({ "accountId": account_id })),
+        )
+        .await
+        .map(|_: serde_json::Value| ())
+    }
+
+    /// Set or clear the flag (impediment) field; Jira stores it as a checkbox value
+    pub async fn set_flagged(&self, issue_id: &str, flag_field: &str, flagged: bool) -> Result<()> {
+        let value = if flagged {
+            json!([{ "value": "Impediment" }])
+        } else {
+            serde_json::Value::Null
+        };
+        self.update_issue(
+            issue_id,
+            IssueUpdate {
+                fields: Some(json!({ flag_field: value })),
+                transition: None,
+                update: None,
+            },
+        )
+        .await
+    }
+
+    /// Set the story point estimate; `None` clears it
+    pub async fn set_story_points(&self, issue_id: &str, field: &str, points: Option<f64>) -> Result<()> {
+        self.update_issue(
+            issue_id,
+            IssueUpdate {
+                fields: Some(json!({ field: points })),
+                transition: None,
+                update: None,
+            },
+        )
+        .await
+    }
+
+    /// Set or clear (`None`) the due date; Jira takes it as "YYYY-MM-DD"
+    pub async fn set_due_date(&self, issue_id: &str, due: Option<chrono::NaiveDate>) -> Result<()> {
+        let value = due.map(|date| date.format("%Y-%m-%d").to_string());
+        self.update_issue(
+            issue_id,
+            IssueUpdate {
+                fields: Some(json!({ "duedate": value })),
+                transition: None,
+                update: None,
+            },
+        )
+        .await
+    }
+
+    pub async fn set_summary(&self, issue_id: &str, summary: &str) -> Result<()> {
+        self.update_issue(
+            issue_id,
+            IssueUpdate {
+                fields: Some(json!({ "summary": summary })),
+                transition: None,
+                update: None,
+            },
+        )
+        .await
+    }
+
+    /// Replace the whole description; an empty string clears it
+    pub async fn set_description(&self, issue_id: &str, description: &str) -> Result<()> {
+        let value = if description.is_empty() {
+            serde_json::Value::Null
+        } else {
+            self.rich_text(description)
+        };
+        self.update_issue(
+            issue_id,
+            IssueUpdate {
+                fields: Some(json!({ "description": value })),
+                transition: None,
+                update: None,
+            },
+        )
+        .await
+    }
+
+    /// Set the environment field; an empty string clears it
+    pub async fn set_environment(&self, issue_id: &str, environment: &str) -> Result<()> {
+        let value = if environment.is_empty() {
+            serde_json::Value::Null
+        } else {
+            self.rich_text(environment)
+        };
+        self.update_issue(
+            issue_id,
+            IssueUpdate {
+                fields: Some(json!({ "environment": value })),
+                transition: None,
+                update: None,
+            },
+        )
+        .await
+    }
+
+    /// Add a comment; `internal` marks it as an agent-only note on service desk
+    /// projects instead of a reply visible to the customer, and `visibility`
+    /// limits it to a project role or group
+    pub async fn add_comment(
+        &self,
+        issue_id: &str,
+        comment: &str,
+        internal: bool,
+        visibility: Option<CommentVisibility>,
+    ) -> Result<()> {
+        let properties = if internal {
+            vec![json!({ "key": "sd.public.comment", "value": { "internal": true } })]
+        } else {
+            Vec::new()
+        };
         self.send_request(
             Method::POST,
             &format!("/issue/{}/comment", issue_id),
             Some(json!(CommentRequest {
-                body: comment.to_string(),
+                body: self.rich_text(comment),
+                properties,
+                visibility,
+            })),
+        )
+        .await
+        .map(|_: serde_json::Value| ())
+    }
+
+    /// Replace the body of a comment; its visibility is left as it was
+    pub async fn update_comment(&self, issue_key: &str, comment_id: &str, body: &str) -> Result<()> {
+        self.send_request(
+            Method::PUT,
+            &format!("/issue/{}/comment/{}", issue_key, comment_id),
+            Some(json!({ "body": self.rich_text(body) })),
+        )
+        .await
+        .map(|_: serde_json::Value| ())
+    }
+
+    pub async fn delete_comment(&self, issue_key: &str, comment_id: &str) -> Result<()> {
+        self.send_request(Method::DELETE, &format!("/issue/{}/comment/{}", issue_key, comment_id), None)
+            .await
+            .map(|_: serde_json::Value| ())
+    }
+
+    /// Work logged on an issue, oldest first
+    pub async fn get_worklogs(&self, issue_key: &str) -> Result<Vec<WorklogEntry>> {
+        let mut all_worklogs = Vec::new();
+        loop {
+            let response: Worklog = self
+                .send_request(
+                    Method::GET,
+                    &format!("/issue/{}/worklog?startAt={}&maxResults={}", issue_key, all_worklogs.len(), self.page_size),
+                    None,
+                )
+                .await?;
+
+            let page_len = response.worklogs.len();
+            all_worklogs.extend(response.worklogs);
+            if page_len == 0 || all_worklogs.len() >= response.total as usize {
+                break;
+            }
+        }
+        Ok(all_worklogs)
+    }
+
+    /// Log `time_spent` (Jira's notation, e.g. "1h 30m") against an issue, starting now
+    pub async fn add_worklog(&self, issue_key: &str, time_spent: &str, comment: Option<&str>) -> Result<()> {
+        self.send_request(
+            Method::POST,
+            &format!("/issue/{}/worklog", issue_key),
+            Some(json!(WorklogRequest {
+                time_spent: time_spent.to_string(),
+                comment: comment.map(|c| self.rich_text(c)),
             })),
         )
         .await
         .map(|_: serde_json::Value| ())
     }
 
+    /// Roles of `project_key` and the groups the user can see, as comment visibility choices
+    pub async fn get_comment_visibilities(&self, project_key: &str) -> Result<Vec<CommentVisibility>> {
+        // Role name → role URL
+        let roles: HashMap<String, String> = self
+            .send_request(Method::GET, &format!("/project/{}/role", project_key), None)
+            .await?;
+        let mut role_names: Vec<String> = roles.into_keys().collect();
+        role_names.sort();
+
+        // Browsing groups needs a global permission many users lack; roles alone still work
+        let groups = self
+            .send_request::<GroupPickerResponse>(Method::GET, "/groups/picker?maxResults=50", None)
+            .await
+            .map(|response| response.groups)
+            .unwrap_or_default();
+
+        let roles = role_names
+            .into_iter()
+            .map(|value| CommentVisibility { kind: "role".to_string(), value });
+        let groups = groups
+            .into_iter()
+            .map(|group| CommentVisibility { kind: "group".to_string(), value: group.name });
+        Ok(roles.chain(groups).collect())
+    }
+
+    pub async fn get_myself(&self) -> Result<User> {
+        let user: User = self.send_request(Method::GET, "/myself", None).await?;
+        if let Ok(mut users) = self.users.lock() {
+            users.insert(&user);
+        }
+        Ok(user)
+    }
+
+    /// Full profile of a user; cached, so reopening a profile card is free
+    pub async fn get_user(&self, account_id: &str) -> Result<User> {
+        if let Some(user) = self.users.lock().ok().and_then(|users| users.profile(account_id)) {
+            return Ok(user);
+        }
+        let id: String = url::form_urlencoded::byte_serialize(account_id.as_bytes()).collect();
+        let user: User = self
+            .send_request(Method::GET, &format!("/user?accountId={}", id), None)
+            .await?;
+        if let Ok(mut users) = self.users.lock() {
+            users.insert_profile(&user);
+        }
+        Ok(user)
+    }
+
+    /// Users that can be assigned `issue_key`
+    pub async fn get_assignable_users(&self, issue_key: &str) -> Result<Vec<User>> {
+        self.search_assignable_users(issue_key, "").await
+    }
+
+    /// Users that can be assigned `issue_key`, matched server-side against `query`
+    pub async fn search_assignable_users(&self, issue_key: &str, query: &str) -> Result<Vec<User>> {
+        let query: String = url::form_urlencoded::byte_serialize(query.as_bytes()).collect();
+        let users: Vec<User> = self
+            .send_request(
+                Method::GET,
+                &format!("/user/assignable/search?issueKey={}&query={}", issue_key, query),
+                None,
+            )
+            .await?;
+        if let Ok(mut cache) = self.users.lock() {
+            for user in &users {
+                cache.insert(user);
+            }
+        }
+        Ok(users)
+    }
+
+    /// Users in the directory whose name or email matches `query`
+    pub async fn search_users(&self, query: &str) -> Result<Vec<User>> {
+        let query: String = url::form_urlencoded::byte_serialize(query.as_bytes()).collect();
+        let users: Vec<User> = self
+            .send_request(Method::GET, &format!("/user/search?query={}", query), None)
+            .await?;
+        if let Ok(mut cache) = self.users.lock() {
+            for user in &users {
+                cache.insert(user);
+            }
+        }
+        Ok(users)
+    }
+
+    /// Fetch the raw bytes behind an attachment's `content` URL
+    #[cfg(feature = "image-preview")]
+    pub async fn download_attachment(&self, content_url: &str) -> Result<Vec<u8>> {
+        if self.offline {
+            anyhow::bail!("Offline: attachments can't be downloaded");
+        }
+        let response = self
+            .client
+            .get(content_url)
+            .basic_auth(&self.username, Some(&self.api_token))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    pub async fn delete_issue(&self, issue_key: &str, delete_subtasks: bool) -> Result<()> {
+        self.send_request(
+            Method::DELETE,
+            &format!("/issue/{}?deleteSubtasks={}", issue_key, delete_subtasks),
+            None,
+        )
+        .await
+        .map(|_: serde_json::Value| ())
+    }
+
     // New Jira Software specific methods
     pub async fn get_projects(&self) -> Result<Vec<Project>> {
         let response: ProjectsResponse = self
@@ -113,19 +775,54 @@ impl JiraClient {
         Ok(response.values)
     }
 
+    #[allow(dead_code)]
     pub async fn get_board(&self, board_id: u32) -> Result<Board> {
         self.send_agile_request(Method::GET, &format!("/board/{}", board_id), None)
             .await
     }
 
+    pub async fn get_board_configuration(&self, board_id: u32) -> Result<BoardConfiguration> {
+        self.send_agile_request(Method::GET, &format!("/board/{}/configuration", board_id), None)
+            .await
+    }
+
+    /// Columns of a board in order; limits are dropped when the board has WIP limits turned off
+    pub async fn get_board_columns(&self, board_id: u32) -> Result<Vec<BoardColumn>> {
+        let config = self.get_board_configuration(board_id).await?.column_config;
+        let limits_off = config.constraint_type.as_deref() == Some("none");
+        Ok(config
+            .columns
+            .into_iter()
+            .map(|column| match limits_off {
+                true => BoardColumn { min: None, max: None, ..column },
+                false => column,
+            })
+            .collect())
+    }
+
     pub async fn get_board_sprints(&self, board_id: u32) -> Result<Vec<Sprint>> {
+        self.fetch_board_sprints(board_id, None).await
+    }
+
+    /// Active and future sprints only; long-lived boards have hundreds of closed ones
+    pub async fn get_open_board_sprints(&self, board_id: u32) -> Result<Vec<Sprint>> {
+        self.fetch_board_sprints(board_id, Some("active,future")).await
+    }
+
+    /// Every page of a board's sprints, optionally narrowed to `state`
+    /// (a comma-separated list of "active", "future" and "closed")
+    async fn fetch_board_sprints(&self, board_id: u32, state: Option<&str>) -> Result<Vec<Sprint>> {
+        let state = state.map(|state| format!("&state={}", state)).unwrap_or_default();
         let mut all_sprints = Vec::new();
         let mut start_at = 0;
         loop {
             let response: SprintsResponse = self
                 .send_agile_request(
                     Method::GET,
-                    &format!("/board/{}/sprint?startAt={}", board_id, start_at),
+                    &format!(
+                        "/board/{}/sprint?startAt={}&maxResults={}{}",
+                        board_id, start_at, self.page_size, state
+                    ),
                     None,
                 )
                 .await?;
@@ -140,20 +837,76 @@ impl JiraClient {
         Ok(all_sprints)
     }
 
+    #[allow(dead_code)]
     pub async fn get_sprint(&self, sprint_id: u32) -> Result<Sprint> {
         self.send_agile_request(Method::GET, &format!("/sprint/{}", sprint_id), None)
             .await
     }
 
-    pub async fn update_sprint(&self, sprint_id: u32, update: &SprintUpdate) -> Result<Sprint> {
+    pub async fn update_sprint(&self, sprint_id: u32, update: &SprintUpdate) -> Result<()> {
         self.send_agile_request(
             Method::POST,
             &format!("/sprint/{}", sprint_id),
             Some(json!(update)),
         )
         .await
+        .map(|_: serde_json::Value| ())
+    }
+
+    pub async fn get_board_quick_filters(&self, board_id: u32) -> Result<Vec<QuickFilter>> {
+        let mut filters = Vec::new();
+        let mut start_at = 0;
+        loop {
+            let response: QuickFiltersResponse = self
+                .send_agile_request(
+                    Method::GET,
+                    &format!(
+                        "/board/{}/quickfilter?startAt={}&maxResults={}",
+                        board_id, start_at, self.page_size
+                    ),
+                    None,
+                )
+                .await?;
+
+            filters.extend(response.values);
+
+            if response.is_last.unwrap_or(true) {
+                break;
+            }
+            start_at = response.start_at + response.max_results;
+        }
+        Ok(filters)
     }
 
+    /// Move issues into a sprint (at most 50 per request)
+    pub async fn move_issues_to_sprint(&self, sprint_id: u32, issue_keys: &[&str]) -> Result<()> {
+        for chunk in issue_keys.chunks(MAX_MOVE_ISSUES) {
+            self.send_agile_request(
+                Method::POST,
+                &format!("/sprint/{}/issue", sprint_id),
+                Some(json!({ "issues": chunk })),
+            )
+            .await
+            .map(|_: serde_json::Value| ())?;
+        }
+        Ok(())
+    }
+
+    /// Move issues out of their sprint into the backlog (at most 50 per request)
+    pub async fn move_issues_to_backlog(&self, issue_keys: &[&str]) -> Result<()> {
+        for chunk in issue_keys.chunks(MAX_MOVE_ISSUES) {
+            self.send_agile_request(
+                Method::POST,
+                "/backlog/issue",
+                Some(json!({ "issues": chunk })),
+            )
+            .await
+            .map(|_: serde_json::Value| ())?;
+        }
+        Ok(())
+    }
+
+    #[allow(dead_code)]
     pub async fn get_board_epics(&self, board_id: u32) -> Result<Vec<Epic>> {
         let response: EpicsResponse = self
             .send_agile_request(
@@ -165,6 +918,7 @@ impl JiraClient {
         Ok(response.values)
     }
 
+    #[allow(dead_code)]
     pub async fn get_epic_issues(&self, epic_id: u32) -> Result<Vec<Issue>> {
         let response: IssuesResponse = self
             .send_agile_request(
@@ -177,14 +931,32 @@ impl JiraClient {
     }
 
     // Private Methods
+    /// Rich-text field value: API v3 only accepts ADF documents, while v2
+    /// (and "latest" on Server/Data Center) takes plain strings
+    fn rich_text(&self, text: &str) -> serde_json::Value {
+        self.text_format.encode(text)
+    }
+
+    /// Call the platform API under `/rest/api/{api_version}`. Cloud serves
+    /// v3, where descriptions and comment bodies are ADF documents; Server and
+    /// Data Center only serve v2, where they are wiki-markup strings. Bodies are
+    /// encoded per `text_format`, and responses are read as text in either shape.
     async fn send_request<T: serde::de::DeserializeOwned>(
         &self,
         method: Method,
         path: &str,
         body: Option<serde_json::Value>,
     ) -> Result<T> {
-        let api_base = format!("{}/rest/api/3", self.domain.trim_end_matches('/'));
+        if self.offline {
+            anyhow::bail!("Offline: only cached data can be shown");
+        }
+        let api_base = format!("{}/rest/api/{}", self.site_url(), self.api_version);
         let url = format!("{}{}", api_base, path);
+        // Searching is a POST but changes nothing, so it still runs in dry-run mode
+        let read_only = method == Method::GET || (method == Method::POST && path == "/search");
+        if self.is_dry_run() && !read_only {
+            return self.log_dry_run(&method, &url, body.as_ref());
+        }
         let request = self
             .client
             .request(method, &url)
@@ -198,7 +970,7 @@ impl JiraClient {
             request
         };
 
-        Ok(request.send().await?.error_for_status()?.json().await?)
+        self.execute(request, read_only).await
     }
 
     async fn send_agile_request<T: serde::de::DeserializeOwned>(
@@ -207,8 +979,15 @@ impl JiraClient {
         path: &str,
         body: Option<serde_json::Value>,
     ) -> Result<T> {
-        let api_base = format!("{}/rest/agile/1.0", self.domain.trim_end_matches('/'));
+        if self.offline {
+            anyhow::bail!("Offline: only cached data can be shown");
+        }
+        let api_base = format!("{}/rest/agile/1.0", self.site_url());
         let url = format!("{}{}", api_base, path);
+        if self.is_dry_run() && method != Method::GET {
+            return self.log_dry_run(&method, &url, body.as_ref());
+        }
+        let read_only = method == Method::GET;
         let request = self
             .client
             .request(method, &url)
@@ -222,7 +1001,783 @@ impl JiraClient {
             request
         };
 
-        Ok(request.send().await?.error_for_status()?.json().await?)
+        self.execute(request, read_only).await
+    }
+
+    /// Web page of an issue
+    pub fn browse_url(&self, issue_key: &str) -> String {
+        format!("{}/browse/{}", self.site_url(), issue_key)
+    }
+
+    /// Base URL and context path that the REST paths are appended to
+    fn site_url(&self) -> String {
+        if self.context_path.is_empty() {
+            self.base_url.clone()
+        } else {
+            format!("{}/{}", self.base_url, self.context_path)
+        }
+    }
+
+    fn log_dry_run<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &Method,
+        url: &str,
+        body: Option<&serde_json::Value>,
+    ) -> Result<T> {
+        let summary = format!("{} {}", method, url);
+        if let Some(path) = &self.dry_run_log {
+            let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+            writeln!(file, "{} {}", chrono::Utc::now().to_rfc3339(), summary)?;
+            if let Some(body) = body {
+                writeln!(file, "{}", serde_json::to_string_pretty(body)?)?;
+            }
+        }
+        if let Ok(mut last) = self.last_dry_run.lock() {
+            *last = Some(summary);
+        }
+        Ok(serde_json::from_value(serde_json::Value::Null)?)
+    }
+
+    /// Send `request`, retrying per `set_retries`; only `read_only` requests
+    /// are retried after failures that may have reached Jira
+    async fn execute<T: serde::de::DeserializeOwned>(
+        &self,
+        mut request: reqwest::RequestBuilder,
+        read_only: bool,
+    ) -> Result<T> {
+        let mut attempt = 0;
+        loop {
+            let retry = if attempt < self.retries { request.try_clone() } else { None };
+            let response = match request.send().await {
+                Ok(response) => response,
+                Err(e) => {
+                    let network = e.is_connect() || e.is_timeout();
+                    if let Some(next) = retry.filter(|_| network && read_only) {
+                        tokio::time::sleep(self.backoff(attempt)).await;
+                        attempt += 1;
+                        request = next;
+                        continue;
+                    }
+                    if network {
+                        self.network_failures.fetch_add(1, Ordering::Relaxed);
+                    }
+                    match attempt {
+                        0 => return Err(e.into()),
+                        _ => anyhow::bail!("{} (tried {} times)", e, attempt + 1),
+                    }
+                }
+            };
+            self.network_failures.store(0, Ordering::Relaxed);
+            self.record_rate_limit(&response);
+
+            let status = response.status();
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                let retry_after: Option<u64> = response
+                    .headers()
+                    .get("Retry-After")
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.trim().parse().ok());
+                let wait = retry_after.map_or(self.backoff(attempt), Duration::from_secs);
+                if let Some(next) = retry.filter(|_| wait <= MAX_RETRY_AFTER) {
+                    self.set_rate_limited_until(Some(Instant::now() + wait));
+                    tokio::time::sleep(wait).await;
+                    self.set_rate_limited_until(None);
+                    attempt += 1;
+                    request = next;
+                    continue;
+                }
+                let retry = retry_after.map(|secs| format!(", retry in {}s", secs)).unwrap_or_default();
+                anyhow::bail!("Rate limited by Jira{}", retry);
+            }
+            let unavailable = matches!(
+                status,
+                StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT
+            );
+            if let Some(next) = retry.filter(|_| unavailable && read_only) {
+                tokio::time::sleep(self.backoff(attempt)).await;
+                attempt += 1;
+                request = next;
+                continue;
+            }
+            return Self::parse_response(response).await;
+        }
+    }
+
+    /// `retry_delay` doubled for each earlier retry, at most `MAX_BACKOFF`
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.retry_delay.saturating_mul(2u32.saturating_pow(attempt)).min(MAX_BACKOFF)
+    }
+
+    fn set_rate_limited_until(&self, until: Option<Instant>) {
+        if let Ok(mut current) = self.rate_limited_until.lock() {
+            *current = until;
+        }
     }
+
+    fn record_rate_limit(&self, response: &reqwest::Response) {
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+        };
+        let limit = RateLimit {
+            limit: header("X-RateLimit-Limit").map(|v: u64| v as u32),
+            remaining: header("X-RateLimit-Remaining").map(|v: u64| v as u32),
+            retry_after: header("Retry-After"),
+        };
+        // Not every endpoint sends the headers; keep the last known quota
+        if limit.limit.is_none() && limit.remaining.is_none() && limit.retry_after.is_none() {
+            return;
+        }
+        if let Ok(mut current) = self.rate_limit.lock() {
+            *current = Some(limit);
+        }
+    }
+
+    // Mutating endpoints (transitions, updates, deletes) answer 204 No Content,
+    // so an empty body is treated as JSON `null`.
+    async fn parse_response<T: serde::de::DeserializeOwned>(response: reqwest::Response) -> Result<T> {
+        let status = response.status();
+        if status.is_client_error() || status.is_server_error() {
+            let body = response.text().await.unwrap_or_default();
+            match error_messages(&body).or_else(|| plain_error(&body)) {
+                Some(messages) => anyhow::bail!("Jira returned {}: {}", status, messages),
+                None => anyhow::bail!("Jira returned {}", status),
+            }
+        }
+        let body = response.text().await?;
+        if body.trim().is_empty() {
+            Ok(serde_json::from_value(serde_json::Value::Null)?)
+        } else {
+            Ok(serde_json::from_str(&body)?)
+        }
+    }
+}
+
+/// Messages from a Jira error body such as `{"errorMessages": [...], "errors": {...}}`,
+/// e.g. why a JQL query is invalid
+fn error_messages(body: &str) -> Option<String> {
+    let body: serde_json::Value = serde_json::from_str(body).ok()?;
+    let general = body["errorMessages"].as_array().into_iter().flatten().filter_map(|m| m.as_str());
+    let per_field = body["errors"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(field, m)| Some(format!("{}: {}", field, m.as_str()?)));
+    let messages: Vec<String> = general.map(str::to_string).chain(per_field).collect();
+    (!messages.is_empty()).then(|| messages.join("; "))
+}
+
+/// A short non-JSON error body such as a proxy's "Service Unavailable"; HTML
+/// error pages are left out
+fn plain_error(body: &str) -> Option<String> {
+    let body = body.trim();
+    if body.is_empty() || body.starts_with('<') || body.starts_with('{') {
+        return None;
+    }
+    let mut message: String = body.chars().take(200).collect();
+    if message.len() < body.len() {
+        message.push('…');
+    }
+    Some(message)
 }
 
+/// `&jql=...` query suffix; the agile endpoints AND it with the board's filter
+fn jql_param(jql: Option<&str>) -> String {
+    jql.map(|jql| {
+        let encoded: String = url::form_urlencoded::byte_serialize(jql.as_bytes()).collect();
+        format!("&jql={}", encoded)
+    })
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client(server: &MockServer) -> JiraClient {
+        let mut client = JiraClient::with_base_url(&server.uri(), "user".to_string(), "token".to_string());
+        client.set_page_size(2);
+        client.set_retries(2, Duration::from_millis(1));
+        client
+    }
+
+    fn sprint(id: u32) -> serde_json::Value {
+        json!({ "id": id, "name": format!("Sprint {}", id), "state": "closed" })
+    }
+
+    fn issue(key: &str) -> serde_json::Value {
+        json!({
+            "id": key,
+            "key": key,
+            "fields": {
+                "summary": format!("Summary of {}", key),
+                "status": {
+                    "id": "1",
+                    "name": "To Do",
+                    "statusCategory": { "id": 2, "name": "To Do", "key": "new" }
+                },
+                "issuetype": { "id": "10001", "name": "Story" }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn get_board_sprints_walks_all_pages() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/agile/1.0/board/7/sprint"))
+            .and(query_param("startAt", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "maxResults": 2, "startAt": 0, "total": 3, "isLast": false,
+                "values": [sprint(1), sprint(2)]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/agile/1.0/board/7/sprint"))
+            .and(query_param("startAt", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "maxResults": 2, "startAt": 2, "total": 3, "isLast": true,
+                "values": [sprint(3)]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let sprints = client(&server).get_board_sprints(7).await.unwrap();
+        let ids: Vec<u32> = sprints.iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn open_sprints_leave_out_closed_ones() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/agile/1.0/board/7/sprint"))
+            .and(query_param("state", "active,future"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "maxResults": 2, "startAt": 0, "total": 1, "isLast": true,
+                "values": [{ "id": 9, "name": "Sprint 9", "state": "active" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let sprints = client(&server).get_open_board_sprints(7).await.unwrap();
+        let ids: Vec<u32> = sprints.iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![9]);
+    }
+
+    #[tokio::test]
+    async fn user_profiles_are_fetched_once() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/user"))
+            .and(query_param("accountId", "abc:123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "accountId": "abc:123", "displayName": "Ada", "emailAddress": null,
+                "timeZone": "Europe/Berlin", "active": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        client.get_user("abc:123").await.unwrap();
+        let user = client.get_user("abc:123").await.unwrap();
+        assert_eq!(user.time_zone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(user.active, Some(true));
+    }
+
+    #[tokio::test]
+    async fn sprints_without_a_state_still_load() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/agile/1.0/board/7/sprint"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "maxResults": 50, "startAt": 0, "total": 3, "isLast": true,
+                "values": [
+                    { "id": 1, "name": "Sprint 1" },
+                    { "id": 2, "name": "Sprint 2", "state": null },
+                    sprint(3)
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let sprints = client(&server).get_board_sprints(7).await.unwrap();
+        let states: Vec<&str> = sprints.iter().map(|s| s.state.as_str()).collect();
+        assert_eq!(states, vec!["", "", "closed"]);
+    }
+
+    #[tokio::test]
+    async fn get_backlog_collects_every_page() {
+        let server = MockServer::start().await;
+        for (start_at, keys) in [("0", vec!["A-1", "A-2"]), ("2", vec!["A-3", "A-4"]), ("4", vec!["A-5"])] {
+            Mock::given(method("GET"))
+                .and(path("/rest/agile/1.0/board/7/backlog"))
+                .and(query_param("startAt", start_at))
+                .and(query_param("maxResults", "2"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "maxResults": 2,
+                    "startAt": start_at.parse::<u32>().unwrap(),
+                    "total": 5,
+                    "issues": keys.iter().map(|k| issue(k)).collect::<Vec<_>>()
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let issues = client(&server).get_backlog(7, None).await.unwrap();
+        let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["A-1", "A-2", "A-3", "A-4", "A-5"]);
+    }
+
+    #[tokio::test]
+    async fn get_sprint_issues_stops_at_max_pages() {
+        let server = MockServer::start().await;
+        for (start_at, keys) in [("0", vec!["A-1", "A-2"]), ("2", vec!["A-3", "A-4"])] {
+            Mock::given(method("GET"))
+                .and(path("/rest/agile/1.0/board/7/sprint/3/issue"))
+                .and(query_param("startAt", start_at))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "maxResults": 2,
+                    "startAt": start_at.parse::<u32>().unwrap(),
+                    "total": 9,
+                    "isLast": false,
+                    "issues": keys.iter().map(|k| issue(k)).collect::<Vec<_>>()
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let mut client = client(&server);
+        client.set_max_pages(2);
+        let issues = client.get_sprint_issues(7, 3, None).await.unwrap();
+        let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["A-1", "A-2", "A-3", "A-4"]);
+    }
+
+    #[tokio::test]
+    async fn worklogs_are_read_and_logged() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/A-1/worklog"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0,
+                "maxResults": 2,
+                "total": 1,
+                "worklogs": [{
+                    "id": "100",
+                    "author": { "accountId": "u1", "displayName": "Sam" },
+                    "timeSpent": "1h 30m",
+                    "timeSpentSeconds": 5400,
+                    "started": "2024-05-02T09:00:00.000+0000",
+                    "comment": {
+                        "type": "doc",
+                        "version": 1,
+                        "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "review" }] }]
+                    }
+                }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/A-1/worklog"))
+            .and(body_partial_json(json!({ "timeSpent": "2h" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "id": "101" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let worklogs = client.get_worklogs("A-1").await.unwrap();
+        assert_eq!(worklogs.len(), 1);
+        assert_eq!(worklogs[0].time_spent_seconds, 5400);
+        assert_eq!(worklogs[0].comment.as_deref(), Some("review"));
+        client.add_worklog("A-1", "2h", None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn comments_are_updated_and_deleted() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/rest/api/2/issue/A-1/comment/10"))
+            .and(body_partial_json(json!({ "body": "fixed typo" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": "10" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/rest/api/2/issue/A-1/comment/10"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server).with_api_version("2");
+        client.update_comment("A-1", "10", "fixed typo").await.unwrap();
+        client.delete_comment("A-1", "10").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn server_errors_are_reported() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/A-1"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let error = client(&server).get_issue("A-1").await.unwrap_err();
+        assert!(error.to_string().contains("500"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn my_issues_search_for_the_current_user() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_partial_json(json!({ "jql": "assignee = currentUser() ORDER BY updated DESC" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "startAt": 0, "maxResults": 2, "total": 1, "issues": [issue("A-1")]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let issues = client(&server).get_my_issues().await.unwrap();
+        assert_eq!(issues.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn offline_clients_send_nothing() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let mut client = client(&server);
+        client.set_offline();
+        assert!(client.get_issue("A-1").await.is_err());
+        assert!(client.get_board_sprints(7).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn unavailable_reads_are_retried_but_writes_are_not() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/myself"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/myself"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "accountId": "abc:123", "displayName": "Ada"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/rest/api/3/issue/A-1/comment/10"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("Service Unavailable"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        assert_eq!(client.get_myself().await.unwrap().display_name, "Ada");
+        let error = client.delete_comment("A-1", "10").await.unwrap_err();
+        assert_eq!(error.to_string(), "Jira returned 503 Service Unavailable: Service Unavailable");
+    }
+
+    #[tokio::test]
+    async fn rate_limited_requests_wait_for_retry_after() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/rest/api/3/issue/A-1/comment/10"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/rest/api/3/issue/A-1/comment/10"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client(&server).update_comment("A-1", "10", "fixed typo").await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn long_retry_after_waits_are_sat_out() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/myself"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "30"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/myself"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "accountId": "abc:123", "displayName": "Ada"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let start = tokio::time::Instant::now();
+        assert_eq!(client.get_myself().await.unwrap().display_name, "Ada");
+        assert!(start.elapsed() >= Duration::from_secs(30));
+        assert!(client.rate_limit_wait().is_none());
+    }
+
+    #[tokio::test]
+    async fn invalid_jql_reports_the_jira_message() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/search"))
+            .and(body_partial_json(json!({ "jql": "project = NOPE", "startAt": 0 })))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "errorMessages": ["The value 'NOPE' does not exist for the field 'project'."],
+                "errors": {}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let error = client(&server).search_issues("project = NOPE").await.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Jira returned 400 Bad Request: The value 'NOPE' does not exist for the field 'project'."
+        );
+    }
+
+    #[tokio::test]
+    async fn unassigning_sends_a_null_account() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/rest/api/3/issue/A-1/assignee"))
+            .and(body_partial_json(json!({ "accountId": null })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client(&server).assign_issue("A-1", None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn created_issues_are_fetched_back() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue"))
+            .and(body_partial_json(json!({ "fields": {
+                "project": { "key": "A" },
+                "issuetype": { "name": "Bug" },
+                "summary": "Summary of A-7"
+            }})))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "id": "10007", "key": "A-7" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/A-7"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issue("A-7")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let issue = client(&server).create_issue("A", "Bug", "Summary of A-7", None).await.unwrap();
+        assert_eq!(issue.map(|i| i.key).as_deref(), Some("A-7"));
+    }
+
+    #[tokio::test]
+    async fn rate_limited_responses_record_retry_after() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/myself"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "120")
+                    .insert_header("X-RateLimit-Remaining", "0"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let error = client.get_myself().await.unwrap_err();
+        assert!(error.to_string().contains("retry in 120s"), "{}", error);
+        let rate_limit = client.rate_limit().unwrap();
+        assert_eq!(rate_limit.remaining, Some(0));
+        assert!(rate_limit.is_low());
+    }
+
+    #[tokio::test]
+    async fn empty_success_bodies_are_accepted() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/A-1/transitions"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client(&server).transition_issue("A-1", "31", None, None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn transitions_send_the_picked_resolution() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/A-1/transitions"))
+            .and(body_partial_json(json!({
+                "transition": { "id": "41" },
+                "fields": { "resolution": { "id": "10001" } }
+            })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client(&server)
+            .transition_issue("A-1", "41", None, Some("10001"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn unreachable_servers_count_as_network_failures() {
+        // Nothing listens on the port once the listener is dropped
+        let address = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+        let mut client = JiraClient::with_base_url(&format!("http://{}", address), "user".to_string(), "token".to_string());
+        client.set_retries(1, Duration::from_millis(1));
+
+        assert!(client.get_myself().await.is_err());
+        assert!(client.get_myself().await.is_err());
+        assert_eq!(client.network_failures(), 2);
+    }
+
+    #[tokio::test]
+    async fn context_path_is_inserted_before_rest() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/jira/rest/api/3/issue/A-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issue("A-1")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let issue = client(&server)
+            .with_context_path("/jira/")
+            .get_issue("A-1")
+            .await
+            .unwrap();
+        assert_eq!(issue.key, "A-1");
+    }
+
+    fn issue_with_description(description: serde_json::Value) -> serde_json::Value {
+        let mut issue = issue("A-1");
+        issue["fields"]["description"] = description;
+        issue
+    }
+
+    #[tokio::test]
+    async fn v2_descriptions_are_plain_strings() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/issue/A-1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(issue_with_description(json!("First line\nSecond line"))),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let issue = client(&server).with_api_version("2").get_issue("A-1").await.unwrap();
+        assert_eq!(issue.fields.description.as_deref(), Some("First line\nSecond line"));
+    }
+
+    #[tokio::test]
+    async fn v3_descriptions_are_adf_documents() {
+        let server = MockServer::start().await;
+        let description = json!({
+            "type": "doc",
+            "version": 1,
+            "content": [
+                { "type": "paragraph", "content": [{ "type": "text", "text": "First line" }] },
+                { "type": "paragraph", "content": [{ "type": "text", "text": "Second line" }] }
+            ]
+        });
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/A-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issue_with_description(description)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let issue = client(&server).with_api_version("3").get_issue("A-1").await.unwrap();
+        assert_eq!(issue.fields.description.as_deref(), Some("First line\nSecond line"));
+    }
+
+    #[tokio::test]
+    async fn due_dates_are_sent_date_only() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/rest/api/3/issue/A-1"))
+            .and(body_partial_json(json!({ "fields": { "duedate": "2024-06-07" } })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let due = chrono::NaiveDate::from_ymd_opt(2024, 6, 7).unwrap();
+        client(&server).set_due_date("A-1", Some(due)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn comments_follow_the_api_versions_text_format() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/2/issue/A-1/comment"))
+            .and(body_partial_json(json!({ "body": "Looks good" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/A-1/comment"))
+            .and(body_partial_json(json!({ "body": { "type": "doc", "version": 1 } })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        for version in ["2", "3"] {
+            client(&server)
+                .with_api_version(version)
+                .add_comment("A-1", "Looks good", false, None)
+                .await
+                .unwrap();
+        }
+    }
+}
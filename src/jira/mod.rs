@@ -1,5 +1,7 @@
+pub mod adf;
 pub mod client;
 pub mod models;
+pub mod user_cache;
 
 pub use client::JiraClient;
 pub use models::*;
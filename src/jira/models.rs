@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use crate::jira::adf;
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Issue {
@@ -11,7 +13,11 @@ pub struct Issue {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct IssueFields {
     pub summary: String,
+    #[serde(default, deserialize_with = "adf::deserialize_optional_text")]
     pub description: Option<String>,
+    /// Where a bug was seen (OS, browser, version, ...)
+    #[serde(default, deserialize_with = "adf::deserialize_optional_text")]
+    pub environment: Option<String>,
     pub status: Status,
     pub assignee: Option<User>,
     pub reporter: Option<User>,
@@ -19,7 +25,184 @@ pub struct IssueFields {
     pub issuetype: IssueType,
     pub created: Option<DateTime<Utc>>,
     pub updated: Option<DateTime<Utc>>,
+    /// Set once the issue has a resolution
+    pub resolutiondate: Option<DateTime<Utc>>,
+    /// Date only, e.g. "2024-05-31"
+    pub duedate: Option<NaiveDate>,
     pub comment: Option<Comments>,
+    pub subtasks: Option<Vec<Subtask>>,
+    pub labels: Option<Vec<String>>,
+    pub attachment: Option<Vec<Attachment>>,
+    /// Links to other issues ("blocks", "relates to", ...)
+    pub issuelinks: Option<Vec<IssueLink>>,
+    /// Fields without a typed member, e.g. instance-specific custom fields
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+impl Issue {
+    /// Whether the flag (impediment) field holds a value, e.g. `[{"value": "Impediment"}]`
+    pub fn is_flagged(&self, flag_field: &str) -> bool {
+        match self.fields.other.get(flag_field) {
+            None | Some(serde_json::Value::Null) => false,
+            Some(serde_json::Value::Array(values)) => !values.is_empty(),
+            Some(_) => true,
+        }
+    }
+
+    /// `projectTypeKey` of the issue's project ("software", "service_desk", ...)
+    pub fn project_type(&self) -> Option<&str> {
+        self.fields.other.get("project")?["projectTypeKey"].as_str()
+    }
+
+    /// Rank of Jira's default priorities, Highest first (0) and Lowest last (4);
+    /// other or missing priorities sort after them
+    pub fn priority_rank(&self) -> u8 {
+        match self.fields.priority.as_ref().map(|p| p.name.as_str()) {
+            Some("Highest") => 0,
+            Some("High") => 1,
+            Some("Medium") => 2,
+            Some("Low") => 3,
+            Some("Lowest") => 4,
+            _ => 5,
+        }
+    }
+
+    /// Whether the status is in the "Done" category
+    pub fn is_done(&self) -> bool {
+        self.fields.status.status_category.key == "done"
+    }
+
+    /// Issues this one "is blocked by" that aren't done yet
+    pub fn open_blockers(&self) -> Vec<&LinkedIssue> {
+        self.fields
+            .issuelinks
+            .iter()
+            .flatten()
+            .filter(|link| link.link_type.is_blocks())
+            // On the blocked issue, the blocker is the inward end of the link
+            .filter_map(|link| link.inward_issue.as_ref())
+            .filter(|blocker| blocker.fields.status.status_category.key != "done")
+            .collect()
+    }
+
+    /// Due before `today` and not done yet
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.fields.duedate.is_some_and(|due| due < today) && !self.is_done()
+    }
+
+    /// Best-effort display text of a field outside the typed model, `None` when unset
+    pub fn field_text(&self, field: &str) -> Option<String> {
+        self.fields.other.get(field).and_then(value_text)
+    }
+
+    /// Estimate held in the story points custom `field`, `None` when unestimated
+    pub fn story_points(&self, field: &str) -> Option<f64> {
+        self.fields.other.get(field).and_then(serde_json::Value::as_f64)
+    }
+}
+
+fn null_as_empty<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Stringify custom field values: options, users, sprints and lists of them
+fn value_text(value: &serde_json::Value) -> Option<String> {
+    use serde_json::Value;
+    match value {
+        Value::Null => None,
+        Value::Bool(b) => Some(if *b { "Yes" } else { "No" }.to_string()),
+        // Story points come back as floats, e.g. 3.0
+        Value::Number(n) => Some(match n.as_f64() {
+            Some(f) if f.fract() == 0.0 => format!("{}", f as i64),
+            _ => n.to_string(),
+        }),
+        Value::String(s) => Some(s.clone()),
+        Value::Array(values) => {
+            let parts: Vec<String> = values.iter().filter_map(value_text).collect();
+            (!parts.is_empty()).then(|| parts.join(", "))
+        }
+        Value::Object(map) if map.get("type").and_then(Value::as_str) == Some("doc") => {
+            Some(adf::to_plain_text(value))
+        }
+        Value::Object(map) => ["displayName", "name", "value", "key"]
+            .iter()
+            .find_map(|key| map.get(*key).and_then(Value::as_str))
+            .map(str::to_string)
+            .or_else(|| Some(value.to_string())),
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Attachment {
+    pub id: String,
+    pub filename: String,
+    #[serde(rename = "mimeType")]
+    pub mime_type: Option<String>,
+    pub size: Option<u64>,
+    /// Download URL; requires the same authentication as the REST API
+    pub content: String,
+}
+
+impl Attachment {
+    pub fn is_image(&self) -> bool {
+        self.mime_type.as_deref().is_some_and(|m| m.starts_with("image/"))
+    }
+}
+
+/// A link to another issue; `inward_issue` or `outward_issue` is the issue at
+/// the other end, read with the type's `inward` or `outward` phrase
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IssueLink {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub link_type: IssueLinkType,
+    #[serde(rename = "inwardIssue")]
+    pub inward_issue: Option<LinkedIssue>,
+    #[serde(rename = "outwardIssue")]
+    pub outward_issue: Option<LinkedIssue>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IssueLinkType {
+    pub name: String,
+    /// e.g. "is blocked by"
+    #[serde(default)]
+    pub inward: String,
+    /// e.g. "blocks"
+    #[serde(default)]
+    pub outward: String,
+}
+
+impl IssueLinkType {
+    /// The built-in "Blocks" type, also when renamed but still phrased the same
+    pub fn is_blocks(&self) -> bool {
+        self.name.eq_ignore_ascii_case("blocks") || self.inward.eq_ignore_ascii_case("is blocked by")
+    }
+}
+
+/// The other end of a link, with just the fields Jira includes for it
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LinkedIssue {
+    pub id: String,
+    pub key: String,
+    pub fields: LinkedIssueFields,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LinkedIssueFields {
+    #[serde(default)]
+    pub summary: String,
+    pub status: Status,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Subtask {
+    pub id: String,
+    pub key: String,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -45,6 +228,12 @@ pub struct User {
     pub display_name: String,
     #[serde(rename = "emailAddress")]
     pub email_address: Option<String>,
+    /// IANA zone name such as "Europe/Berlin"; hidden by some privacy settings
+    #[serde(rename = "timeZone", default)]
+    pub time_zone: Option<String>,
+    /// Deactivated accounts can't be assigned or mentioned
+    #[serde(default)]
+    pub active: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -57,6 +246,9 @@ pub struct Priority {
 pub struct IssueType {
     pub id: String,
     pub name: String,
+    /// Sub-task types can only be created under a parent issue
+    #[serde(default)]
+    pub subtask: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -66,18 +258,72 @@ pub struct Comments {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(from = "RawComment")]
 pub struct Comment {
     pub id: String,
     pub body: String,
+    /// The ADF body had mentions, links, tables or other formatting that
+    /// `body` leaves out, so saving an edit of `body` would drop it
+    pub rich: bool,
     pub author: User,
     pub created: DateTime<Utc>,
     pub updated: DateTime<Utc>,
 }
 
+/// A comment as Jira (or the offline cache) sends it, before the body is flattened
+#[derive(Deserialize)]
+struct RawComment {
+    id: String,
+    #[serde(default)]
+    body: serde_json::Value,
+    /// Only in cached comments, whose body is already flattened
+    #[serde(default)]
+    rich: bool,
+    author: User,
+    created: DateTime<Utc>,
+    updated: DateTime<Utc>,
+}
+
+impl From<RawComment> for Comment {
+    fn from(raw: RawComment) -> Self {
+        Comment {
+            id: raw.id,
+            rich: raw.rich || adf::has_formatting(&raw.body),
+            body: adf::value_text(raw.body).unwrap_or_default(),
+            author: raw.author,
+            created: raw.created,
+            updated: raw.updated,
+        }
+    }
+}
+
+/// One page of `/issue/{key}/worklog`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Worklog {
+    pub worklogs: Vec<WorklogEntry>,
+    pub total: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorklogEntry {
+    pub id: String,
+    pub author: User,
+    /// Jira's own rendering of the duration, e.g. "1h 30m"
+    #[serde(rename = "timeSpent")]
+    pub time_spent: String,
+    #[serde(rename = "timeSpentSeconds")]
+    pub time_spent_seconds: u64,
+    pub started: DateTime<Utc>,
+    #[serde(default, deserialize_with = "adf::deserialize_optional_text")]
+    pub comment: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Sprint {
     pub id: u32,
     pub name: String,
+    /// "active", "closed" or "future"; empty when the response omits it or sends null
+    #[serde(default, deserialize_with = "null_as_empty")]
     pub state: String,
     #[serde(rename = "startDate")]
     pub start_date: Option<DateTime<Utc>>,
@@ -141,6 +387,47 @@ pub struct BoardLocation {
     pub avatar_uri: Option<String>,
 }
 
+/// Board settings from `/board/{id}/configuration`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BoardConfiguration {
+    #[serde(rename = "columnConfig")]
+    pub column_config: ColumnConfig,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ColumnConfig {
+    pub columns: Vec<BoardColumn>,
+    /// "issueCount", "issueCountExclSubs" or "none" when WIP limits are off
+    #[serde(rename = "constraintType")]
+    pub constraint_type: Option<String>,
+}
+
+/// A board column, the statuses mapped to it and its WIP limits
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BoardColumn {
+    pub name: String,
+    #[serde(default)]
+    pub statuses: Vec<ColumnStatus>,
+    pub min: Option<u32>,
+    pub max: Option<u32>,
+}
+
+impl BoardColumn {
+    pub fn contains(&self, status_id: &str) -> bool {
+        self.statuses.iter().any(|s| s.id == status_id)
+    }
+
+    /// The WIP limit, when `count` issues in the column are over it
+    pub fn exceeded_limit(&self, count: usize) -> Option<u32> {
+        self.max.filter(|&max| count > max as usize)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ColumnStatus {
+    pub id: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SearchResponse {
     pub issues: Vec<Issue>,
@@ -156,6 +443,46 @@ pub struct Transition {
     pub id: String,
     pub name: String,
     pub to: Status,
+    /// Fields on the transition screen, keyed by field id
+    /// (only returned with `expand=transitions.fields`)
+    #[serde(default)]
+    pub fields: HashMap<String, TransitionField>,
+}
+
+impl Transition {
+    /// Whether the workflow rejects this transition without a resolution
+    pub fn requires_resolution(&self) -> bool {
+        self.fields.get("resolution").is_some_and(|field| field.required)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TransitionField {
+    #[serde(default)]
+    pub required: bool,
+    pub name: Option<String>,
+}
+
+/// How an issue was resolved, e.g. Fixed or Won't Fix
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Resolution {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// The parts of `/project/{key}` used when creating issues
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProjectIssueTypes {
+    #[serde(rename = "issueTypes", default)]
+    pub issue_types: Vec<IssueType>,
+}
+
+/// Reply to creating an issue
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CreatedIssue {
+    pub id: String,
+    pub key: String,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -167,6 +494,9 @@ pub struct TransitionsResponse {
 pub struct IssueUpdate {
     pub fields: Option<serde_json::Value>,
     pub transition: Option<TransitionRequest>,
+    /// Field operations, e.g. `{"comment": [{"add": {...}}]}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -176,7 +506,49 @@ pub struct TransitionRequest {
 
 #[derive(Debug, Clone, Serialize)]
 pub struct CommentRequest {
-    pub body: String,
+    /// A plain string or an ADF document, per `TextFormat`
+    pub body: serde_json::Value,
+    /// Entity properties, e.g. `sd.public.comment` for service desk visibility
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<serde_json::Value>,
+    /// Restricts the comment to a project role or group; unrestricted when absent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<CommentVisibility>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorklogRequest {
+    /// Duration in Jira's notation, e.g. "1h 30m"
+    #[serde(rename = "timeSpent")]
+    pub time_spent: String,
+    /// A plain string or an ADF document, per `TextFormat`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct CommentVisibility {
+    /// "role" or "group"
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub value: String,
+}
+
+impl CommentVisibility {
+    pub fn label(&self) -> String {
+        format!("{}: {}", self.kind, self.value)
+    }
+}
+
+/// Response of `/groups/picker`
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupPickerResponse {
+    pub groups: Vec<GroupSuggestion>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroupSuggestion {
+    pub name: String,
 }
 
 #[derive(Debug, Clone, Serialize, Default)]
@@ -220,6 +592,26 @@ pub struct SprintsResponse {
     pub values: Vec<Sprint>,
 }
 
+/// A board's saved JQL chip
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QuickFilter {
+    pub id: u32,
+    pub name: String,
+    pub jql: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QuickFiltersResponse {
+    #[serde(rename = "maxResults")]
+    pub max_results: u32,
+    #[serde(rename = "startAt")]
+    pub start_at: u32,
+    #[serde(rename = "isLast")]
+    pub is_last: Option<bool>,
+    pub values: Vec<QuickFilter>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct IssuesResponse {
     #[serde(rename = "maxResults")]
@@ -233,6 +625,7 @@ pub struct IssuesResponse {
 }
 
 // Epic model for Jira Software
+#[allow(dead_code)]
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Epic {
     pub id: u32,
@@ -243,6 +636,7 @@ pub struct Epic {
     pub done: bool,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EpicColor {
     pub key: String,
@@ -260,6 +654,7 @@ pub struct ProjectsResponse {
     pub values: Vec<Project>,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EpicsResponse {
     #[serde(rename = "maxResults")]
@@ -271,3 +666,111 @@ pub struct EpicsResponse {
     pub is_last: Option<bool>,
     pub values: Vec<Epic>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn comment(body: serde_json::Value) -> Comment {
+        serde_json::from_value(json!({
+            "id": "10",
+            "body": body,
+            "author": { "accountId": "a1", "displayName": "Ann" },
+            "created": "2024-05-01T10:00:00.000+0000",
+            "updated": "2024-05-01T10:00:00.000+0000"
+        }))
+        .unwrap()
+    }
+
+    fn issue(key: &str) -> serde_json::Value {
+        json!({
+            "id": key,
+            "key": key,
+            "fields": {
+                "summary": format!("Summary of {}", key),
+                "status": {
+                    "id": "1",
+                    "name": "To Do",
+                    "statusCategory": { "id": 2, "name": "To Do", "key": "new" }
+                },
+                "issuetype": { "id": "10001", "name": "Story" }
+            }
+        })
+    }
+
+    #[test]
+    fn open_blockers_come_from_is_blocked_by_links() {
+        let blocks = json!({ "name": "Blocks", "inward": "is blocked by", "outward": "blocks" });
+        let done = json!({ "id": "3", "name": "Done", "statusCategory": { "id": 3, "name": "Done", "key": "done" } });
+        let mut body = issue("A-1");
+        body["fields"]["issuelinks"] = json!([
+            { "id": "1", "type": blocks, "inwardIssue": issue("B-1") },
+            { "id": "2", "type": blocks, "inwardIssue": { "id": "B-2", "key": "B-2", "fields": { "summary": "Shipped", "status": done } } },
+            { "id": "3", "type": blocks, "outwardIssue": issue("C-1") },
+            { "id": "4", "type": { "name": "Relates", "inward": "relates to", "outward": "relates to" }, "inwardIssue": issue("D-1") }
+        ]);
+
+        let issue: Issue = serde_json::from_value(body).unwrap();
+        let blockers: Vec<&str> = issue.open_blockers().iter().map(|b| b.key.as_str()).collect();
+        assert_eq!(blockers, vec!["B-1"]);
+    }
+
+    #[test]
+    fn due_dates_are_date_only() {
+        let mut body = issue("A-1");
+        body["fields"]["duedate"] = json!("2024-05-31");
+        let issue: Issue = serde_json::from_value(body).unwrap();
+        assert_eq!(issue.fields.duedate, NaiveDate::from_ymd_opt(2024, 5, 31));
+    }
+
+    #[test]
+    fn overdue_means_due_before_today_and_not_done() {
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+        let mut body = issue("A-1");
+        body["fields"]["duedate"] = json!("2024-05-31");
+        let due: Issue = serde_json::from_value(body.clone()).unwrap();
+        assert!(due.is_overdue(date(6, 1)));
+        assert!(!due.is_overdue(date(5, 31)));
+
+        body["fields"]["status"]["statusCategory"]["key"] = json!("done");
+        let done: Issue = serde_json::from_value(body).unwrap();
+        assert!(!done.is_overdue(date(6, 1)));
+
+        let undated: Issue = serde_json::from_value(issue("A-2")).unwrap();
+        assert!(!undated.is_overdue(date(6, 1)));
+    }
+
+    #[test]
+    fn wip_limit_is_exceeded_above_max() {
+        let column = |max: Option<u32>| BoardColumn { name: "Doing".to_string(), statuses: Vec::new(), min: None, max };
+        assert_eq!(column(Some(3)).exceeded_limit(4), Some(3));
+        assert_eq!(column(Some(3)).exceeded_limit(3), None);
+        assert_eq!(column(None).exceeded_limit(40), None);
+    }
+
+    #[test]
+    fn comments_with_formatting_are_marked_rich() {
+        let plain = comment(json!({
+            "type": "doc",
+            "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "looks good" }] }]
+        }));
+        assert_eq!(plain.body, "looks good");
+        assert!(!plain.rich);
+        assert!(!comment(json!("v2 bodies are plain strings")).rich);
+
+        let mention = comment(json!({
+            "type": "doc",
+            "content": [{ "type": "paragraph", "content": [
+                { "type": "mention", "attrs": { "id": "a2", "text": "@Bob" } },
+                { "type": "text", "text": " please check" }
+            ] }]
+        }));
+        assert_eq!(mention.body, "@Bob please check");
+        assert!(mention.rich);
+
+        // The flag survives the offline cache, where the body is already flattened
+        let cached: Comment = serde_json::from_value(serde_json::to_value(&mention).unwrap()).unwrap();
+        assert!(cached.rich);
+    }
+}
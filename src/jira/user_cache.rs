@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use crate::jira::models::{Issue, User};
+use crate::ui::fuzzy::Matcher;
+
+/// How long a user seen in a response stays suggestible
+const USER_TTL: Duration = Duration::from_secs(30 * 60);
+
+/// Users seen in issue payloads and user searches, keyed by account id, so
+/// pickers can offer local suggestions before a server search returns
+pub struct UserCache {
+    entries: HashMap<String, (User, Instant)>,
+    /// Users fetched from `/user` for a profile card, by account id
+    profiles: HashMap<String, (User, Instant)>,
+    ttl: Duration,
+}
+
+impl UserCache {
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            profiles: HashMap::new(),
+            ttl: USER_TTL,
+        }
+    }
+
+    pub fn insert(&mut self, user: &User) {
+        // Locally built placeholder users (dry-run) have no account id
+        if user.account_id.is_empty() {
+            return;
+        }
+        self.entries
+            .insert(user.account_id.clone(), (user.clone(), Instant::now()));
+    }
+
+    /// A full profile fetched within the TTL
+    pub fn profile(&self, account_id: &str) -> Option<User> {
+        self.profiles
+            .get(account_id)
+            .filter(|(_, fetched)| fetched.elapsed() < self.ttl)
+            .map(|(user, _)| user.clone())
+    }
+
+    pub fn insert_profile(&mut self, user: &User) {
+        self.insert(user);
+        self.profiles
+            .insert(user.account_id.clone(), (user.clone(), Instant::now()));
+    }
+
+    /// Remember assignees, reporters and comment authors
+    pub fn insert_from_issues(&mut self, issues: &[Issue]) {
+        for issue in issues {
+            let fields = &issue.fields;
+            let authors = fields
+                .comment
+                .iter()
+                .flat_map(|c| c.comments.iter().map(|comment| &comment.author));
+            for user in fields.assignee.iter().chain(fields.reporter.iter()).chain(authors) {
+                self.insert(user);
+            }
+        }
+    }
+
+    /// Unexpired users whose name or email matches `query` through `matcher`,
+    /// best match first
+    pub fn search(&mut self, query: &str, matcher: Matcher) -> Vec<User> {
+        let ttl = self.ttl;
+        self.entries.retain(|_, (_, seen)| seen.elapsed() < ttl);
+
+        let mut users: Vec<User> = self.entries.values().map(|(user, _)| user.clone()).collect();
+        // Equal scores keep this order, and an empty query lists everyone by name
+        users.sort_by(|a, b| a.display_name.cmp(&b.display_name));
+        let choices: Vec<String> = users
+            .iter()
+            .map(|user| match &user.email_address {
+                Some(email) => format!("{} {}", user.display_name, email),
+                None => user.display_name.clone(),
+            })
+            .collect();
+        matcher
+            .rank(&choices, query)
+            .into_iter()
+            .map(|(i, _)| users[i].clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(account_id: &str, name: &str, email: Option<&str>) -> User {
+        User {
+            account_id: account_id.to_string(),
+            display_name: name.to_string(),
+            email_address: email.map(str::to_string),
+            time_zone: None,
+            active: None,
+        }
+    }
+
+    fn names(users: &[User]) -> Vec<&str> {
+        users.iter().map(|user| user.display_name.as_str()).collect()
+    }
+
+    #[test]
+    fn search_follows_the_matcher() {
+        let mut cache = UserCache::new();
+        cache.insert(&user("1", "Jane Doe", Some("jane@example.com")));
+        cache.insert(&user("2", "John Smith", None));
+        cache.insert(&user("3", "Ann Lee", Some("ann.lee@example.com")));
+
+        assert_eq!(names(&cache.search("", Matcher::new(true))), vec!["Ann Lee", "Jane Doe", "John Smith"]);
+        // Fuzzy: the letters in order, not necessarily next to each other
+        assert_eq!(names(&cache.search("jsmth", Matcher::new(true))), vec!["John Smith"]);
+        assert!(cache.search("jsmth", Matcher::new(false)).is_empty());
+        assert_eq!(names(&cache.search("SMITH", Matcher::new(false))), vec!["John Smith"]);
+        assert_eq!(names(&cache.search("ann.lee@", Matcher::new(false))), vec!["Ann Lee"]);
+    }
+}
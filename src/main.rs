@@ -6,9 +6,14 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
-use std::{error::Error, io, time::Duration};
+use std::{
+    error::Error,
+    io,
+    time::Duration,
+};
 use ui::{App, EventHandler};
 
+mod cache;
 mod config;
 mod jira;
 mod ui;
@@ -18,12 +23,52 @@ mod ui;
 struct Opt {
     #[clap(short, long)]
     config: Option<String>,
+
+    /// Prompt for the Jira API token, save it in the system keyring and exit
+    #[clap(long)]
+    store_token: bool,
+
+    /// Log write requests to dry-run.log in the config directory instead of sending them
+    #[clap(long)]
+    dry_run: bool,
+
+    /// Send API requests to this URL instead of the configured domain (staging proxy, mock server)
+    #[clap(long)]
+    base_url: Option<String>,
+
+    /// Open this board instead of the configured default
+    #[clap(long)]
+    board: Option<u32>,
+
+    /// Open this sprint instead of the most recent one
+    #[clap(long)]
+    sprint: Option<u32>,
+
+    /// Show the cached boards, sprints and issues without contacting Jira
+    #[clap(long)]
+    offline: bool,
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    let _opt = Opt::parse();
-    let config = config::Config::load()?;
+    let opt = Opt::parse();
+    let mut config = config::Config::load()?;
+    if opt.base_url.is_some() {
+        config.jira.base_url = opt.base_url.clone();
+    }
+
+    if opt.store_token {
+        store_token(&config.jira)?;
+        return Ok(());
+    }
+
+    // Offline nothing is sent, so a token that can't be read doesn't stop the cached view
+    let api_token = if opt.offline { String::new() } else { config.jira.resolve_token()? };
+    let dry_run_log = if opt.dry_run {
+        Some(config::Config::dir()?.join("dry-run.log"))
+    } else {
+        None
+    };
 
     // setup terminal
     enable_raw_mode()?;
@@ -33,16 +78,25 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let mut terminal = Terminal::new(backend)?;
 
     // create app and run it
-    let mut app = App::new(config);
-    
-    // Initialize the app (load boards, sprints, etc.)
-    if let Err(e) = app.initialize().await {
-        eprintln!("Failed to initialize app: {}", e);
-        eprintln!("Please check your configuration and network connectivity.");
+    let mut app = App::new(config, api_token);
+    if let Some(log_path) = dry_run_log {
+        app.jira_client.enable_dry_run(log_path);
+    }
+    app.base_url_override = opt.base_url;
+    app.startup = ui::app::StartupView { board_id: opt.board, sprint_id: opt.sprint };
+    if opt.offline {
+        app.set_offline();
     }
-    
+
+    // Must run before the event handler starts reading stdin
+    #[cfg(feature = "image-preview")]
+    app.image_preview.detect();
+
+    // Draw immediately; boards, sprints, etc. load in the background
     let mut event_handler = EventHandler::new(Duration::from_millis(250));
+    app.start_initialize(event_handler.sender());
     let res = run_app(&mut terminal, app, &mut event_handler).await;
+    event_handler.stop();
 
     // restore terminal
     disable_raw_mode()?;
@@ -60,6 +114,19 @@ async fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+fn store_token(jira: &config::JiraConfig) -> Result<()> {
+    // Not echoed, so the token stays out of the terminal and its scrollback
+    let token = rpassword::prompt_password(format!("API token for {} on {}: ", jira.username, jira.domain))?;
+    let token = token.trim();
+    if token.is_empty() {
+        anyhow::bail!("No token entered");
+    }
+
+    jira.store_keyring_token(token)?;
+    println!("Token stored. Set \"token_source\": \"keyring\" in your config to use it.");
+    Ok(())
+}
+
 async fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     mut app: App,
@@ -68,11 +135,44 @@ async fn run_app<B: ratatui::backend::Backend>(
     loop {
         terminal.draw(|f| app.render(f))?;
 
-        if let Some(event) = event_handler.next().await {
-            if app.handle_event(event).await? {
-                break;
-            }
+        if let Some(event) = event_handler.next().await
+            && app.handle_event(event).await?
+        {
+            app.remember_last_view();
+            app.save_cache();
+            break;
         }
+
+        if let Some(edit) = app.external_edit.take() {
+            event_handler.pause().await;
+            let edited = edit.prepare().and_then(|path| edit_file(terminal, &path).map(|()| path));
+            event_handler.resume();
+            app.finish_external_edit(edit, edited);
+        }
+    }
+    Ok(())
+}
+
+/// Hand the terminal to `$VISUAL`/`$EDITOR` (vi by default) on `path` and
+/// take it back once the editor exits
+fn edit_file<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, path: &std::path::Path) -> Result<()> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    // Editors are often configured with arguments, e.g. "code --wait"
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or("vi");
+
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    let status = std::process::Command::new(program).args(words).arg(path).status();
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    terminal.clear()?;
+
+    let status = status?;
+    if !status.success() {
+        anyhow::bail!("{} exited with {}", program, status);
     }
     Ok(())
 }
@@ -2,29 +2,430 @@ use anyhow::Result;
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Tabs},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Tabs, Wrap},
     Frame,
 };
 
-use crate::config::Config;
+use crate::cache::Cache;
+use crate::config::{Config, LastView};
 use crate::jira::JiraClient;
-use crate::ui::components::{BacklogView, HelpView, InputView, IssueDetailView, SprintView, SprintSelector, BoardSelector, ProjectSelector};
+use crate::ui::components::search_view::SearchScope;
+use crate::ui::components::{BacklogView, HelpView, InputView, IssueDetailView, SprintView, SprintSelector, BoardSelector, ProjectSelector, SettingsView, ImagePreview, BatchSummary, BlockedView, BoardView, CloseSprintView, ConfirmDialog, CreateIssueForm, MoveTarget, SearchView, Toasts};
+use crate::ui::color_depth::ColorDepth;
+use crate::ui::fuzzy::Matcher;
+use crate::ui::glyphs::Glyphs;
+use crate::ui::icons::IssueTypeIcons;
+use crate::ui::theme::Theme;
+use crate::ui::{clipboard, csv_export, diff, due_date, markdown, standup, time_spent};
+use crate::ui::components::sprint_selector::default_sprint;
 use crate::ui::events::Event;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// Auto-refresh slows down by this factor while the API quota is low
+const RATE_LIMIT_BACKOFF: u64 = 4;
+
+/// Network failures in a row before the connection is reported as lost
+const CONNECTION_LOST_AFTER: u32 = 2;
+
+/// Description lines shown by the quick peek popup
+const PEEK_DESCRIPTION_LINES: usize = 12;
+
+/// Recently opened issue keys offered by the go-to prompt
+const RECENT_ISSUES: usize = 10;
+
+/// Length of a sprint started with no planned end date
+const DEFAULT_SPRINT_DAYS: i64 = 14;
+
+/// Everything the first frame needs, fetched off the UI task at startup
+#[derive(Debug, Clone)]
+pub struct InitialData {
+    pub projects: Vec<crate::jira::Project>,
+    pub boards: Vec<crate::jira::Board>,
+    pub current_user: Option<crate::jira::User>,
+    /// Board the load was started for, to tell whether the user switched meanwhile
+    pub requested_board_id: Option<u32>,
+    pub board_id: Option<u32>,
+    pub sprints: Vec<crate::jira::Sprint>,
+    pub sprint: Option<(crate::jira::Sprint, Vec<crate::jira::Issue>)>,
+    /// Backlog issues when the restored view is the backlog
+    pub backlog: Option<Vec<crate::jira::Issue>>,
+    pub columns: Vec<crate::jira::BoardColumn>,
+    /// Whether `sprints` includes the closed ones
+    pub closed_sprints: bool,
+    /// Why the `--board`/`--sprint` ids could not be honored
+    pub notices: Vec<String>,
+}
+
+/// A list fetched on a background task, delivered as `Event::DataLoaded`.
+/// `generation` tells whether a newer fetch of the same view replaced it.
+#[derive(Debug, Clone)]
+pub enum AppData {
+    SprintIssues {
+        generation: u64,
+        sprint_id: u32,
+        /// Issue to select once the list is in, e.g. the one selected before a refresh
+        select_key: Option<String>,
+        issues: Result<Vec<crate::jira::Issue>, String>,
+    },
+    Backlog {
+        generation: u64,
+        kanban: bool,
+        /// Issue to select once the list is in, e.g. one that was just ranked
+        select_key: Option<String>,
+        issues: Result<Vec<crate::jira::Issue>, String>,
+    },
+}
+
+/// In-flight state of one view's background fetch
+#[derive(Debug, Default)]
+struct ListLoad {
+    generation: u64,
+    in_flight: bool,
+}
+
+impl ListLoad {
+    fn start(&mut self) -> u64 {
+        self.generation += 1;
+        self.in_flight = true;
+        self.generation
+    }
+
+    /// Whether a result of `generation` is the latest and should be shown
+    fn finish(&mut self, generation: u64) -> bool {
+        if generation != self.generation {
+            return false;
+        }
+        self.in_flight = false;
+        true
+    }
+}
+
+/// Board and sprint given with `--board`/`--sprint`, ahead of the config and
+/// the remembered view
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StartupView {
+    pub board_id: Option<u32>,
+    pub sprint_id: Option<u32>,
+}
+
+/// A file handed to `$VISUAL`/`$EDITOR` by the main loop once the key is handled
+#[derive(Debug, Clone)]
+pub enum ExternalEdit {
+    /// The config file, reloaded afterwards
+    Config,
+    /// An issue description, previewed as a diff before it is saved
+    Description { issue_key: String, text: String },
+}
+
+impl ExternalEdit {
+    /// File to open in the editor; descriptions are written to a temporary file
+    pub fn prepare(&self) -> Result<PathBuf> {
+        match self {
+            ExternalEdit::Config => Config::config_path(),
+            ExternalEdit::Description { issue_key, text } => {
+                let path = std::env::temp_dir().join(format!("jira-tui-{}.txt", issue_key));
+                std::fs::write(&path, text)?;
+                Ok(path)
+            }
+        }
+    }
+}
+
+/// Issue field whose edit waits for confirmation in the diff preview
+#[derive(Debug, Clone, PartialEq)]
+pub enum EditedField {
+    Summary,
+    Description,
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingEdit {
+    pub issue_key: String,
+    pub field: EditedField,
+    pub before: String,
+    pub after: String,
+}
+
+/// An action applied to each marked issue in turn
+#[derive(Debug, Clone)]
+pub enum BatchAction {
+    SetFlag(bool),
+    /// Move the issues to `target`, then close the sprint
+    CloseSprint {
+        sprint_id: u32,
+        sprint_name: String,
+        target: MoveTarget,
+    },
+}
+
+/// An action held back until the confirm dialog is answered with `y`
+#[derive(Debug, Clone)]
+pub enum ConfirmAction {
+    /// Apply the transition selected in the issue details
+    Transition { comment: Option<String> },
+    DeleteComment { issue_key: String, comment_id: String },
+    /// Start a future sprint now, ending at `end_date`
+    StartSprint { sprint_id: u32, sprint_name: String, end_date: chrono::DateTime<chrono::Utc> },
+}
+
+impl BatchAction {
+    fn describe(&self, count: usize) -> String {
+        match self {
+            BatchAction::SetFlag(true) => format!("Flag {} issue(s)", count),
+            BatchAction::SetFlag(false) => format!("Unflag {} issue(s)", count),
+            BatchAction::CloseSprint { sprint_name, target, .. } => {
+                format!("Close {} ({} issue(s) to {})", sprint_name, count, target.label())
+            }
+        }
+    }
+}
+
+fn build_client(config: &Config, api_token: String) -> JiraClient {
+    match &config.jira.base_url {
+        Some(base_url) => JiraClient::with_base_url(base_url, config.jira.username.clone(), api_token),
+        None => JiraClient::new(
+            config.jira.username.clone(),
+            api_token,
+            config.jira.domain.clone(),
+        ),
+    }
+    .with_context_path(&config.jira.context_path)
+    .with_api_version(config.jira.api_version())
+}
+
+async fn load_initial_data(
+    client: &JiraClient,
+    board_id: Option<u32>,
+    last_view: Option<LastView>,
+    startup: StartupView,
+    sprint_sort: &str,
+    prefer_active: bool,
+    load_closed: bool,
+) -> Result<InitialData> {
+    let projects = client.get_projects().await.unwrap_or_default();
+    let current_user = client.get_myself().await.ok();
+    let mut boards = client.get_boards().await.unwrap_or_default();
+    let mut notices = Vec::new();
+
+    let startup_board = match startup.board_id {
+        Some(id) if boards.iter().any(|b| b.id == id) => Some(id),
+        Some(id) => match client.get_board(id).await {
+            Ok(board) => {
+                boards.push(board);
+                Some(id)
+            }
+            Err(_) => {
+                notices.push(format!("Board {} was not found; opening the default board", id));
+                None
+            }
+        },
+        None => None,
+    };
+    // Ids from the command line win over the remembered view, and a remembered
+    // board that no longer exists falls back to the configured one
+    let last_view = last_view.filter(|view| {
+        startup_board.is_none() && startup.sprint_id.is_none() && boards.iter().any(|b| b.id == view.board_id)
+    });
+    let requested_board_id = board_id;
+    // Fall back to the first board when none is configured
+    let board_id = startup_board
+        .or(last_view.as_ref().map(|view| view.board_id))
+        .or(board_id)
+        .or_else(|| boards.first().map(|b| b.id));
+
+    // Boards past the first page are looked up to learn their type
+    if let Some(board_id) = board_id
+        && !boards.iter().any(|b| b.id == board_id)
+        && let Ok(board) = client.get_board(board_id).await
+    {
+        boards.push(board);
+    }
+    let kanban = boards
+        .iter()
+        .any(|b| Some(b.id) == board_id && b.board_type == "kanban");
+
+    let mut sprints = Vec::new();
+    let mut closed_sprints = false;
+    let mut sprint = None;
+    let mut backlog = None;
+    let mut columns = Vec::new();
+    if let Some(board_id) = board_id
+        && kanban
+    {
+        // No sprints to load; open on the board's issues instead
+        backlog = Some(client.get_board_issues(board_id, None).await?);
+        if let Some(id) = startup.sprint_id {
+            notices.push(format!("Board {} has no sprints, so sprint {} can't be shown", board_id, id));
+        }
+    } else if let Some(board_id) = board_id {
+        (sprints, closed_sprints) = fetch_board_sprints(client, board_id, load_closed).await?;
+        let wanted_sprint = startup.sprint_id.or(last_view.as_ref().and_then(|view| view.sprint_id));
+        // A requested or remembered sprint that is missing may be a closed one
+        if let Some(id) = wanted_sprint
+            && !closed_sprints
+            && !sprints.iter().any(|s| s.id == id)
+        {
+            (sprints, closed_sprints) = fetch_board_sprints(client, board_id, true).await?;
+        }
+        // WIP limits are a nicety; boards we cannot configure simply show none
+        columns = client.get_board_columns(board_id).await.unwrap_or_default();
+        let wanted = wanted_sprint.and_then(|id| sprints.iter().find(|s| s.id == id));
+        if let Some(id) = startup.sprint_id
+            && wanted.is_none()
+        {
+            notices.push(format!("Sprint {} is not on board {}; opening the latest sprint", id, board_id));
+        }
+        if let Some(latest) = wanted.or_else(|| default_sprint(&sprints, sprint_sort, prefer_active)) {
+            let issues = client.get_sprint_issues(board_id, latest.id, None).await?;
+            sprint = Some((latest.clone(), issues));
+        }
+        if last_view.as_ref().is_some_and(|view| view.mode == "backlog") {
+            backlog = client.get_backlog(board_id, None).await.ok();
+        }
+    }
+
+    Ok(InitialData {
+        projects,
+        boards,
+        current_user,
+        requested_board_id,
+        board_id,
+        sprints,
+        sprint,
+        backlog,
+        columns,
+        closed_sprints,
+        notices,
+    })
+}
+
+/// The startup data as last cached, for the board and sprint that
+/// `load_initial_data` would pick, and when it was saved. `None` when the
+/// board has no unexpired cache entry.
+fn cached_initial_data(
+    cache: &Cache,
+    board_id: Option<u32>,
+    last_view: Option<LastView>,
+    startup: StartupView,
+    sprint_sort: &str,
+    prefer_active: bool,
+) -> Option<(InitialData, chrono::DateTime<chrono::Utc>)> {
+    let last_view = last_view.filter(|view| {
+        startup.board_id.is_none() && startup.sprint_id.is_none() && cache.boards.iter().any(|b| b.id == view.board_id)
+    });
+    let requested_board_id = board_id;
+    let board_id = startup
+        .board_id
+        .or(last_view.as_ref().map(|view| view.board_id))
+        .or(board_id)
+        .or_else(|| cache.boards.first().map(|b| b.id))?;
+    let board = cache.board_data.get(&board_id)?;
+    let kanban = cache.boards.iter().any(|b| b.id == board_id && b.board_type == "kanban");
+
+    let cached = |sprint: &crate::jira::Sprint| Some((sprint.clone(), board.sprint_issues.get(&sprint.id)?.clone()));
+    let wanted = startup
+        .sprint_id
+        .or(last_view.as_ref().and_then(|view| view.sprint_id))
+        .and_then(|id| board.sprints.iter().find(|s| s.id == id));
+    let sprint = if kanban {
+        None
+    } else {
+        wanted
+            .and_then(cached)
+            .or_else(|| default_sprint(&board.sprints, sprint_sort, prefer_active).and_then(cached))
+    };
+    let backlog = if kanban || last_view.as_ref().is_some_and(|view| view.mode == "backlog") {
+        board.backlog.clone()
+    } else {
+        None
+    };
+
+    let data = InitialData {
+        projects: cache.projects.clone(),
+        boards: cache.boards.clone(),
+        current_user: cache.current_user.clone(),
+        requested_board_id,
+        board_id: Some(board_id),
+        sprints: board.sprints.clone(),
+        sprint,
+        backlog,
+        columns: board.columns.clone(),
+        closed_sprints: board.closed_sprints,
+        notices: Vec::new(),
+    };
+    Some((data, board.saved_at))
+}
+
+/// Sprints of a board, leaving out closed ones unless `include_closed`, since
+/// long-lived boards have hundreds. A board without open sprints gets its full
+/// list so there is still one to show. The flag says whether closed sprints
+/// are included.
+async fn fetch_board_sprints(
+    client: &JiraClient,
+    board_id: u32,
+    include_closed: bool,
+) -> Result<(Vec<crate::jira::Sprint>, bool)> {
+    if !include_closed {
+        let open = client.get_open_board_sprints(board_id).await?;
+        if !open.is_empty() {
+            return Ok((open, false));
+        }
+    }
+    Ok((client.get_board_sprints(board_id).await?, true))
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AppMode {
     Sprint,
     SprintSelector,
     BoardSelector,
+    FindBoard,
+    BoardSprintSelector,
     ProjectSelector,
     Backlog,
     IssueDetail,
+    #[allow(dead_code)]
     Help,
     AddComment,
     EditIssue,
+    EditEnvironment,
     EditSprintName,
+    DeleteIssue,
+    ConfirmDeleteSubtasks,
+    FilterLabel,
+    FilterList,
+    QuickFilter,
+    Search,
+    FilterAssignee,
+    SearchResults,
+    Blocked,
+    MyIssues,
+    Board,
+    Settings,
+    EditSetting,
+    AttachmentPreview,
+    TransitionComment,
+    PickResolution,
+    Confirm,
+    ConfirmEdit,
+    EditEstimate,
+    EditDueDate,
+    AddWorklog,
+    EditComment,
+    GoToIssue,
+    ExportCsv,
+    ConfirmBatch,
+    QuickPeek,
+    UserCard,
+    AssignUser,
+    CreateIssue,
+    BatchSummary,
+    CloseSprint,
 }
 
 pub struct App {
@@ -42,25 +443,134 @@ pub struct App {
     pub issue_detail_view: IssueDetailView,
     pub help_view: HelpView,
     pub input_view: InputView,
+    pub settings_view: SettingsView,
+    pub image_preview: ImagePreview,
+    pub toasts: Toasts,
     
     // State
-    pub current_tab: usize,
     pub should_quit: bool,
+    /// Visibility of the comment being written: `None` outside service desk projects
+    pub comment_internal: Option<bool>,
+    /// Role or group the comment being written is restricted to
+    pub comment_visibility: Option<crate::jira::CommentVisibility>,
+    /// Roles and groups offered by Ctrl+R, loaded the first time it is pressed per comment
+    pub comment_visibilities: Vec<crate::jira::CommentVisibility>,
+    /// First key of a two-key sequence such as `zc`
+    pub pending_key: Option<char>,
     pub current_sprint_id: Option<u32>,
+    /// Board shown before the current one, for quick toggling
+    pub previous_board_id: Option<u32>,
+    pub search_view: SearchView,
+    /// Where Esc leaves the issue details for
+    pub detail_return: AppMode,
+    pub blocked_view: BlockedView,
+    pub board_view: BoardView,
+    /// Mode the board view returns to
+    pub board_return: AppMode,
+    /// Scan the open issues of the whole board for blocked ones rather than the current sprint
+    pub blocked_board_wide: bool,
+    /// Where Esc leaves the blocked issues for
+    pub blocked_return: AppMode,
+    /// Everything assigned to me, on any board
+    pub my_issues_view: SprintView,
+    /// Where Esc leaves my issues for
+    pub my_issues_return: AppMode,
+    /// Keys of the issues opened most recently, newest first
+    pub recent_issues: Vec<String>,
+    /// Board quick filter whose JQL narrows the sprint and backlog fetches
+    pub quick_filter: Option<crate::jira::QuickFilter>,
+    /// Quick filters of the current board, loaded when the picker opens
+    pub quick_filters: Vec<crate::jira::QuickFilter>,
+    /// Site resolutions, loaded the first time a transition needs one
+    pub resolutions: Vec<crate::jira::Resolution>,
+    /// Board whose column WIP limits are loaded into the sprint view
+    pub columns_board_id: Option<u32>,
+    /// Comment typed for a transition that is waiting on a resolution
+    pub pending_transition_comment: Option<String>,
+    /// Only show this user's issues in the sprint and backlog views
+    pub assignee_filter: Option<crate::jira::User>,
+    /// Users offered by the assignee filter prompt
+    pub assignee_candidates: Vec<crate::jira::User>,
     pub available_boards: Vec<crate::jira::Board>,
     pub available_sprints: Vec<crate::jira::Sprint>,
+    /// Whether `available_sprints` includes the board's closed sprints
+    pub closed_sprints_loaded: bool,
+    /// Sprints per board, cached while browsing the combined selector, with
+    /// whether closed ones are included
+    pub board_sprints: HashMap<u32, (Vec<crate::jira::Sprint>, bool)>,
+    /// Current sprint of a board peeked at from the board selector, without switching to it
+    pub board_preview: Option<SprintView>,
     pub available_projects: Vec<crate::jira::Project>,
+    pub current_user: Option<crate::jira::User>,
+    pub pending_delete: Option<crate::jira::Issue>,
+    /// Issue whose story points are being entered
+    pub pending_estimate: Option<crate::jira::Issue>,
+    /// Issue whose due date is being entered
+    pub pending_due_date: Option<crate::jira::Issue>,
+    /// Id of the comment being edited
+    pub pending_comment: Option<String>,
+    pub confirm_dialog: ConfirmDialog,
+    /// Action run once the confirm dialog is answered, and the mode to go back to
+    pub pending_confirm: Option<(ConfirmAction, AppMode)>,
+    /// Issue shown in the quick peek popup
+    pub peek_issue: Option<crate::jira::Issue>,
+    /// Role ("Assignee" or "Reporter") and full profile shown in the user card
+    pub user_card: Option<(&'static str, crate::jira::User)>,
+    /// Users the detail issue can be assigned to; the list shows "Unassigned" above them
+    pub assignable_users: Vec<crate::jira::User>,
+    pub assign_state: ListState,
+    /// Bulk action awaiting confirmation, or the failed part of the last one for retry
+    pub pending_batch: Option<(BatchAction, Vec<crate::jira::Issue>)>,
+    pub batch_summary: BatchSummary,
+    pub close_sprint_view: CloseSprintView,
+    pub create_form: CreateIssueForm,
+    pub return_mode: AppMode,
+    pub is_loading: bool,
+    /// File to open in `$EDITOR` once the key is handled
+    pub external_edit: Option<ExternalEdit>,
+    /// Summary or description edit shown as a diff before it is sent
+    pub pending_edit: Option<PendingEdit>,
+    pub edit_preview_scroll: u16,
+    /// The background initial load failed and can be retried
+    pub init_failed: bool,
+    /// Cleared once the initial load has used it
+    pub startup: StartupView,
+    /// Started with `--offline`: only cached data is shown
+    pub offline: bool,
+    /// `--base-url`, which wins over the config file's `base_url`, also after a reload
+    pub base_url_override: Option<String>,
+    /// Fetched data, written to disk on exit
+    cache: Cache,
+    /// When the cached data on screen was fetched, until fresh data replaces it
+    cached_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Board whose cached startup data is shown until the fresh load replaces it
+    init_from_cache: Option<u32>,
+    /// Where background tasks report back into the event loop
+    event_sender: Option<mpsc::UnboundedSender<Event>>,
+    sprint_load: ListLoad,
+    backlog_load: ListLoad,
+    /// Key whose request failed on the network, with the mode it was pressed in
+    failed_key: Option<(AppMode, KeyCode, KeyModifiers)>,
+    /// When the auto-refresh timer last fired
+    pub last_refresh: Instant,
+    /// When sprint or backlog issues last arrived, shown in the status bar
+    pub last_refreshed: Option<chrono::DateTime<chrono::Utc>>,
+    /// Advanced every tick while something loads
+    spinner_frame: usize,
+    pub color_depth: ColorDepth,
+    pub theme: Theme,
 }
 
 impl App {
-    pub fn new(config: Config) -> Self {
-        let jira_client = JiraClient::new(
-            config.jira.username.clone(),
-            config.jira.api_token.clone(),
-            config.jira.domain.clone(),
-        );
+    pub fn new(config: Config, api_token: String) -> Self {
+        let jira_client = build_client(&config, api_token);
 
-        Self {
+        let settings_view = SettingsView::new(config.ui.clone());
+        let cache = match config.ui.cache_ttl_hours {
+            0 => Cache::default(),
+            hours => Cache::load(Some(chrono::Duration::hours(hours as i64))),
+        };
+        let mut app = Self {
             mode: AppMode::Sprint,
             show_help: false,
             jira_client,
@@ -73,13 +583,213 @@ impl App {
             issue_detail_view: IssueDetailView::new(),
             help_view: HelpView::new(),
             input_view: InputView::new("Input".to_string()),
-            current_tab: 0,
+            settings_view,
+            image_preview: ImagePreview::new(),
+            toasts: Toasts::new(),
             should_quit: false,
+            comment_internal: None,
+            comment_visibility: None,
+            comment_visibilities: Vec::new(),
+            pending_key: None,
             current_sprint_id: None,
+            previous_board_id: None,
+            search_view: SearchView::new(),
+            detail_return: AppMode::Sprint,
+            blocked_view: BlockedView::new(),
+            board_view: BoardView::new(),
+            board_return: AppMode::Sprint,
+            blocked_board_wide: false,
+            blocked_return: AppMode::Sprint,
+            my_issues_view: SprintView::new(),
+            my_issues_return: AppMode::Sprint,
+            recent_issues: Vec::new(),
+            quick_filter: None,
+            quick_filters: Vec::new(),
+            resolutions: Vec::new(),
+            columns_board_id: None,
+            pending_transition_comment: None,
+            assignee_filter: None,
+            assignee_candidates: Vec::new(),
             available_boards: Vec::new(),
             available_sprints: Vec::new(),
+            closed_sprints_loaded: false,
+            board_sprints: HashMap::new(),
+            board_preview: None,
             available_projects: Vec::new(),
+            current_user: None,
+            pending_delete: None,
+            pending_estimate: None,
+            pending_due_date: None,
+            pending_comment: None,
+            confirm_dialog: ConfirmDialog::new(),
+            pending_confirm: None,
+            peek_issue: None,
+            user_card: None,
+            assignable_users: Vec::new(),
+            assign_state: ListState::default(),
+            pending_batch: None,
+            batch_summary: BatchSummary::new(String::new()),
+            close_sprint_view: CloseSprintView::new(),
+            create_form: CreateIssueForm::new(),
+            return_mode: AppMode::Sprint,
+            is_loading: false,
+            external_edit: None,
+            pending_edit: None,
+            edit_preview_scroll: 0,
+            init_failed: false,
+            startup: StartupView::default(),
+            offline: false,
+            base_url_override: None,
+            cache,
+            cached_at: None,
+            init_from_cache: None,
+            event_sender: None,
+            sprint_load: ListLoad::default(),
+            backlog_load: ListLoad::default(),
+            failed_key: None,
+            last_refresh: Instant::now(),
+            last_refreshed: None,
+            spinner_frame: 0,
+            color_depth: ColorDepth::TrueColor,
+            theme: Theme::default(),
+        };
+        app.apply_ui_config();
+        app.sprint_view.hide_done = app.config.ui.hide_done_in_sprint;
+        app.apply_field_config();
+        app
+    }
+
+    /// Push the configured field ids and key prefix into the views
+    fn apply_field_config(&mut self) {
+        self.backlog_view.set_key_prefix(self.config.ui.backlog_key_prefix.clone());
+        self.sprint_view.flag_field = self.config.jira.flag_field.clone();
+        self.backlog_view.flag_field = self.config.jira.flag_field.clone();
+        self.search_view.results.flag_field = self.config.jira.flag_field.clone();
+        self.my_issues_view.flag_field = self.config.jira.flag_field.clone();
+        self.sprint_view.story_points_field = self.config.jira.story_points_field.clone();
+        self.search_view.results.story_points_field = self.config.jira.story_points_field.clone();
+        self.my_issues_view.story_points_field = self.config.jira.story_points_field.clone();
+        self.issue_detail_view.story_points_field = self.config.jira.story_points_field.clone();
+        self.issue_detail_view.custom_fields = self
+            .config
+            .jira
+            .custom_fields
+            .iter()
+            .map(|(label, field)| (label.clone(), field.clone()))
+            .collect();
+    }
+
+    /// Re-read the config file after it was edited outside the app. A different
+    /// site, user or token rebuilds the client and reloads the boards; a file
+    /// that no longer parses leaves the running config alone.
+    pub fn reload_config(&mut self) {
+        // Offline there is nothing to authenticate, so a missing token is no reason to keep the old config
+        let offline = self.offline;
+        let loaded = Config::load().and_then(|config| {
+            let token = if offline { String::new() } else { config.jira.resolve_token()? };
+            Ok((token, config))
+        });
+        let (api_token, mut config) = match loaded {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                self.toasts.error(format!("Config not reloaded: {}", e));
+                return;
+            }
+        };
+        if self.base_url_override.is_some() {
+            config.jira.base_url = self.base_url_override.clone();
+        }
+
+        let connection = |config: &Config| {
+            let jira = &config.jira;
+            (
+                jira.domain.clone(),
+                jira.username.clone(),
+                jira.base_url.clone(),
+                jira.context_path.clone(),
+                jira.api_version().to_string(),
+            )
+        };
+        let reconnect = connection(&config) != connection(&self.config)
+            || (!offline && self.config.jira.resolve_token().ok().as_ref() != Some(&api_token));
+        self.config = config;
+        if reconnect {
+            let mut client = build_client(&self.config, api_token);
+            if let Some(log_path) = self.jira_client.dry_run_log() {
+                client.enable_dry_run(log_path.clone());
+            }
+            if offline {
+                client.set_offline();
+            }
+            self.jira_client = client;
+        }
+        // Offline, the cached data on screen stays; there is nothing new to load
+        if reconnect && !offline {
+            self.available_boards.clear();
+            self.available_projects.clear();
+            self.available_sprints.clear();
+            self.current_sprint_id = None;
+            self.columns_board_id = None;
+            self.mode = AppMode::Sprint;
+            if let Some(sender) = self.event_sender.clone() {
+                self.start_initialize(sender);
+            }
         }
+        self.apply_ui_config();
+        self.apply_field_config();
+        self.toasts.success("Config reloaded");
+    }
+
+    /// Send nothing to Jira and show the whole disk cache, however old or
+    /// turned off by `cache_ttl_hours`; the status bar says when it was fetched
+    pub fn set_offline(&mut self) {
+        self.offline = true;
+        self.jira_client.set_offline();
+        self.cache = Cache::load(None);
+    }
+
+    /// How prompts and the board finder match typed text, per `ui.fuzzy_matching`
+    fn matcher(&self) -> Matcher {
+        Matcher::new(self.config.ui.fuzzy_matching)
+    }
+
+    /// Push the UI settings that can change at runtime into the views and client
+    fn apply_ui_config(&mut self) {
+        self.color_depth = ColorDepth::from_setting(&self.config.ui.color_depth);
+        self.sprint_selector.date_format = self.config.ui.date_format.clone();
+        self.sprint_selector.sort = self.config.ui.sprint_sort.clone();
+        self.issue_detail_view.date_format = self.config.ui.date_format.clone();
+        self.issue_detail_view.comments_height = self.config.ui.comments_height;
+        self.issue_detail_view.wrap_description = self.config.ui.wrap_description;
+        self.sprint_view.set_collapsed_groups(&self.config.ui.collapsed_groups);
+        self.theme = Theme::load(&self.config.ui.theme).unwrap_or_else(|e| {
+            self.toasts.error(e.to_string());
+            Theme::default()
+        });
+        let glyphs = Glyphs::new(self.config.ui.use_emoji);
+        self.sprint_view.glyphs = glyphs;
+        self.search_view.results.glyphs = glyphs;
+        self.my_issues_view.glyphs = glyphs;
+        self.backlog_view.glyphs = glyphs;
+        self.board_selector.glyphs = glyphs;
+        self.project_selector.glyphs = glyphs;
+        let type_icons = IssueTypeIcons::from_config(&self.config.ui.issue_type_icons, glyphs);
+        self.sprint_view.type_icons = type_icons.clone();
+        self.search_view.results.type_icons = type_icons.clone();
+        self.my_issues_view.type_icons = type_icons.clone();
+        self.backlog_view.type_icons = type_icons.clone();
+        self.board_view.type_icons = type_icons.clone();
+        self.issue_detail_view.type_icons = type_icons;
+        self.sprint_view.show_status_counts = self.config.ui.show_status_counts;
+        self.search_view.results.show_status_counts = self.config.ui.show_status_counts;
+        self.my_issues_view.show_status_counts = self.config.ui.show_status_counts;
+        self.backlog_view.show_status_counts = self.config.ui.show_status_counts;
+        self.sprint_view.matcher = self.matcher();
+        self.backlog_view.matcher = self.matcher();
+        self.jira_client.set_page_size(self.config.ui.page_size);
+        self.jira_client.set_max_pages(self.config.ui.max_pages);
+        self.jira_client
+            .set_retries(self.config.ui.retries, Duration::from_millis(self.config.ui.retry_delay_ms));
     }
 
     pub async fn handle_event(&mut self, event: Event) -> Result<bool> {
@@ -89,21 +799,57 @@ impl App {
                     return self.handle_help_input(key).await;
                 }
 
-                match self.mode {
-                    AppMode::Sprint => self.handle_sprint_input(key, modifiers).await?,
-                    AppMode::SprintSelector => self.handle_sprint_selector_input(key, modifiers).await?,
-                    AppMode::BoardSelector => self.handle_board_selector_input(key, modifiers).await?,
-                    AppMode::ProjectSelector => self.handle_project_selector_input(key, modifiers).await?,
-                    AppMode::Backlog => self.handle_backlog_input(key, modifiers).await?,
-                    AppMode::IssueDetail => self.handle_issue_detail_input(key, modifiers).await?,
-                    AppMode::AddComment => self.handle_comment_input(key, modifiers).await?,
-                    AppMode::EditIssue => self.handle_edit_input(key, modifiers).await?,
-                    AppMode::EditSprintName => self.handle_edit_sprint_name_input(key, modifiers).await?,
-                    AppMode::Help => { self.handle_help_input(key).await?; }
+                if key == KeyCode::Char('t') && modifiers.contains(KeyModifiers::CONTROL) {
+                    self.config.ui.compact = !self.config.ui.compact;
+                    // The toggle still applies for this session when the config can't be written
+                    if let Err(e) = Config::save_ui(&self.config.ui) {
+                        self.toasts.error(format!("Could not save the compact layout: {}", e));
+                    }
+                    return Ok(false);
+                }
+
+                if key == KeyCode::Char('x') && modifiers.contains(KeyModifiers::CONTROL) {
+                    self.toasts.clear();
+                    return Ok(self.should_quit);
+                }
+
+                let retry = key == KeyCode::Char('r') && self.connection_lost() && !self.input_active();
+                if retry
+                    && self.init_failed
+                    && let Some(sender) = self.event_sender.clone()
+                {
+                    self.toasts.info("Reconnecting…");
+                    self.start_initialize(sender);
+                    return Ok(false);
+                }
+                // While the connection is down, `r` re-runs the key whose request failed
+                let (key, modifiers) = match self.failed_key.take() {
+                    Some((mode, failed, failed_modifiers)) if retry && mode == self.mode => (failed, failed_modifiers),
+                    _ => (key, modifiers),
+                };
+
+                // A failed request shouldn't end the session: report it and stay put
+                let mode = self.mode.clone();
+                if let Err(e) = self.handle_key(key, modifiers).await {
+                    if self.jira_client.network_failures() > 0 {
+                        self.failed_key = Some((mode, key, modifiers));
+                    }
+                    self.toasts.error(e.to_string());
+                }
+            }
+            Event::Initialized(result) => {
+                if let Ok(data) = result.as_ref() {
+                    self.cache_initial_data(data);
                 }
+                self.apply_initial_data(*result);
             }
+            Event::DataLoaded(data) => self.apply_loaded_data(*data),
             Event::Tick => {
-                // Handle periodic updates
+                if self.loading() {
+                    self.spinner_frame = self.spinner_frame.wrapping_add(1);
+                }
+                self.toasts.expire();
+                self.auto_refresh().await;
             }
             Event::Quit => {
                 self.should_quit = true;
@@ -113,19 +859,100 @@ impl App {
         Ok(self.should_quit)
     }
 
-    async fn handle_sprint_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
+    async fn handle_key(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match self.mode {
+            AppMode::Sprint => self.handle_sprint_input(key, modifiers).await?,
+            AppMode::SprintSelector => self.handle_sprint_selector_input(key, modifiers).await?,
+            AppMode::BoardSelector => self.handle_board_selector_input(key, modifiers).await?,
+            AppMode::FindBoard => self.handle_find_board_input(key, modifiers),
+            AppMode::BoardSprintSelector => self.handle_board_sprint_selector_input(key, modifiers).await?,
+            AppMode::ProjectSelector => self.handle_project_selector_input(key, modifiers).await?,
+            AppMode::Backlog => self.handle_backlog_input(key, modifiers).await?,
+            AppMode::IssueDetail => self.handle_issue_detail_input(key, modifiers).await?,
+            AppMode::AddComment => self.handle_comment_input(key, modifiers).await?,
+            AppMode::EditIssue => self.handle_edit_input(key, modifiers).await?,
+            AppMode::EditEnvironment => self.handle_edit_environment_input(key, modifiers).await?,
+            AppMode::EditSprintName => self.handle_edit_sprint_name_input(key, modifiers).await?,
+            AppMode::DeleteIssue => self.handle_delete_issue_input(key, modifiers).await?,
+            AppMode::ConfirmDeleteSubtasks => self.handle_delete_subtasks_input(key).await?,
+            AppMode::FilterLabel => self.handle_filter_label_input(key, modifiers).await?,
+            AppMode::FilterList => self.handle_filter_list_input(key),
+            AppMode::QuickFilter => self.handle_quick_filter_input(key, modifiers).await?,
+            AppMode::Search => self.handle_search_input(key, modifiers).await?,
+            AppMode::FilterAssignee => self.handle_filter_assignee_input(key, modifiers).await?,
+            AppMode::SearchResults => self.handle_search_results_input(key).await?,
+            AppMode::Blocked => self.handle_blocked_input(key).await?,
+            AppMode::MyIssues => self.handle_my_issues_input(key).await?,
+            AppMode::Board => self.handle_board_input(key).await?,
+            AppMode::Settings => self.handle_settings_input(key, modifiers).await?,
+            AppMode::EditSetting => self.handle_edit_setting_input(key, modifiers).await?,
+            AppMode::AttachmentPreview => self.handle_attachment_preview_input(key).await?,
+            AppMode::TransitionComment => self.handle_transition_comment_input(key, modifiers).await?,
+            AppMode::PickResolution => self.handle_resolution_input(key, modifiers).await?,
+            AppMode::Confirm => self.handle_confirm_input(key).await?,
+            AppMode::ConfirmEdit => self.handle_confirm_edit_input(key).await?,
+            AppMode::EditEstimate => self.handle_estimate_input(key, modifiers).await?,
+            AppMode::EditDueDate => self.handle_due_date_input(key, modifiers).await?,
+            AppMode::AddWorklog => self.handle_worklog_input(key, modifiers).await?,
+            AppMode::EditComment => self.handle_edit_comment_input(key, modifiers).await?,
+            AppMode::GoToIssue => self.handle_go_to_issue_input(key, modifiers).await?,
+            AppMode::ExportCsv => self.handle_export_input(key, modifiers),
+            AppMode::ConfirmBatch => self.handle_confirm_batch_input(key).await?,
+            AppMode::QuickPeek => {
+                // Any key closes the peek, leaving the list where it was
+                self.peek_issue = None;
+                self.mode = self.return_mode.clone();
+            }
+            AppMode::UserCard => {
+                if key == KeyCode::Tab {
+                    let other = match self.user_card.as_ref().map(|(role, _)| *role) {
+                        Some("Assignee") => "Reporter",
+                        _ => "Assignee",
+                    };
+                    self.show_user_card(other).await;
+                } else {
+                    self.user_card = None;
+                    self.mode = AppMode::IssueDetail;
+                }
+            }
+            AppMode::AssignUser => self.handle_assign_user_input(key).await?,
+            AppMode::BatchSummary => self.handle_batch_summary_input(key).await?,
+            AppMode::CloseSprint => self.handle_close_sprint_input(key).await?,
+            AppMode::CreateIssue => self.handle_create_issue_input(key, modifiers).await?,
+            AppMode::Help => { self.handle_help_input(key).await?; }
+        }
+        Ok(())
+    }
+
+    async fn handle_sprint_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        if self.pending_key.take() == Some('z') {
+            match key {
+                KeyCode::Char('c') => self.sprint_view.set_all_collapsed(true),
+                KeyCode::Char('o') => self.sprint_view.set_all_collapsed(false),
+                _ => return Ok(()),
+            }
+            return self.save_collapsed_groups();
+        }
         match key {
             KeyCode::Char('q') => self.should_quit = true,
             KeyCode::Char('h') => self.show_help = !self.show_help,
             KeyCode::Char('s') => self.mode = AppMode::Sprint,
             KeyCode::Char('b') => {
                 self.mode = AppMode::Backlog;
-                self.load_backlog().await?;
+                self.load_backlog();
+            }
+            KeyCode::Char('c') => self.open_board(AppMode::Sprint).await?,
+            KeyCode::Char('r') => {
+                // A manual refresh restarts the auto-refresh interval
+                self.last_refresh = Instant::now();
+                self.refresh_sprint().await?;
             }
-            KeyCode::Char('r') => self.refresh_sprint().await?,
+            KeyCode::Char('R') => self.refresh_selected_issue(AppMode::Sprint).await?,
+            KeyCode::Char('n') => self.begin_create_issue(AppMode::Sprint).await?,
             KeyCode::Tab => {
                 // Switch to sprint selector
                 self.sprint_selector.set_sprints(self.available_sprints.clone());
+                self.sprint_selector.closed_hidden = !self.closed_sprints_loaded;
                 self.sprint_selector.activate();
                 self.mode = AppMode::SprintSelector;
             }
@@ -135,20 +962,109 @@ impl App {
                 self.board_selector.activate();
                 self.mode = AppMode::BoardSelector;
             }
+            KeyCode::Char('S') => self.open_board_sprint_selector().await?,
             KeyCode::Char('P') => {
                 // Switch to project selector (capital P for project selector)
                 self.project_selector.set_projects(self.available_projects.clone());
                 self.project_selector.activate();
                 self.mode = AppMode::ProjectSelector;
             }
+            KeyCode::Char('6') if modifiers.contains(KeyModifiers::CONTROL) => self.toggle_previous_board().await?,
+            KeyCode::Char('^') => self.toggle_previous_board().await?,
+            KeyCode::Char(c @ '1'..='9') => {
+                // Quick switch to the Nth board as numbered in the board selector
+                self.board_selector.set_boards(self.available_boards.clone());
+                let index = c.to_digit(10).unwrap_or(1) as usize - 1;
+                if let Some(board_id) = self.board_selector.boards.get(index).map(|b| b.id) {
+                    self.switch_board(board_id).await?;
+                }
+            }
+            KeyCode::Char('0') => {
+                // Boards past the ninth have no digit, so fall back to the selector
+                self.board_selector.set_boards(self.available_boards.clone());
+                self.board_selector.activate();
+                self.mode = AppMode::BoardSelector;
+            }
+            KeyCode::Char('O') => {
+                self.settings_view = SettingsView::new(self.config.ui.clone());
+                self.mode = AppMode::Settings;
+            }
             KeyCode::Down | KeyCode::Char('j') => self.sprint_view.next(),
             KeyCode::Up | KeyCode::Char('k') => self.sprint_view.previous(),
-            KeyCode::Enter => {
+            KeyCode::Char('u') => {
+                let account_id = self.current_account_id().await?;
+                self.sprint_view.select_next_assigned_to(&account_id);
+            }
+            KeyCode::Char('Q') => self.begin_quick_filter(AppMode::Sprint).await?,
+            KeyCode::Char('f') => {
+                self.return_mode = AppMode::Sprint;
+                self.mode = AppMode::FilterList;
+            }
+            KeyCode::Char('d') => self.sprint_view.toggle_hide_done(),
+            KeyCode::Char('C') => self.toggle_status_counts()?,
+            KeyCode::Char('/') => self.begin_search(AppMode::Sprint),
+            KeyCode::Char('m') => {
+                if let Some(issue) = self.sprint_view.selected_issue().cloned() {
+                    self.copy_markdown(&issue)?;
+                }
+            }
+            KeyCode::Char('i') => {
+                if let Some(issue) = self.sprint_view.selected_issue().cloned() {
+                    self.start_work(issue).await?;
+                }
+            }
+            KeyCode::Char('v') => {
+                if let Some(issue) = self.sprint_view.selected_issue().cloned() {
+                    self.peek_issue = Some(issue);
+                    self.return_mode = AppMode::Sprint;
+                    self.mode = AppMode::QuickPeek;
+                }
+            }
+            KeyCode::Char('a') => self.begin_assignee_filter(AppMode::Sprint),
+            KeyCode::Char('l') => {
+                let labels = self.sprint_view.labels();
+                let current = self.sprint_view.label_filter.clone();
+                self.begin_label_filter(labels, current, AppMode::Sprint);
+            }
+            KeyCode::Char('D') => {
                 if let Some(issue) = self.sprint_view.selected_issue() {
-                    let issue_key = issue.key.clone();
-                    self.issue_detail_view.set_issue(issue.clone());
-                    self.load_transitions(&issue_key).await?;
-                    self.mode = AppMode::IssueDetail;
+                    self.begin_delete_issue(issue.clone(), AppMode::Sprint).await?;
+                }
+            }
+            KeyCode::Char('#') => {
+                if let Some(issue) = self.sprint_view.selected_issue() {
+                    self.begin_estimate(issue.clone(), AppMode::Sprint);
+                }
+            }
+            KeyCode::Char('T') => {
+                if let Some(issue) = self.sprint_view.selected_issue() {
+                    self.begin_due_date(issue.clone(), AppMode::Sprint);
+                }
+            }
+            KeyCode::Char(' ') => {
+                self.sprint_view.toggle_selected();
+                self.save_collapsed_groups()?;
+            }
+            KeyCode::Char('g') => self.sprint_view.cycle_group_by(),
+            KeyCode::Char('Y') => self.copy_standup().await?,
+            KeyCode::Char('z') => self.pending_key = Some('z'),
+            KeyCode::Esc => self.sprint_view.marked.clear(),
+            KeyCode::Char('F') if !self.sprint_view.marked.is_empty() => {
+                let issues = self.sprint_view.marked_issues();
+                self.begin_flag_batch(issues, AppMode::Sprint).await?;
+            }
+            KeyCode::Char('F') => {
+                if let Some(issue) = self.sprint_view.selected_issue() {
+                    self.toggle_flag(issue.clone()).await?;
+                }
+            }
+            KeyCode::Char('G') => self.begin_go_to_issue(AppMode::Sprint),
+            KeyCode::Char('X') => self.begin_export(AppMode::Sprint),
+            KeyCode::Char('!') => self.open_blocked(AppMode::Sprint).await?,
+            KeyCode::Char('W') => self.open_my_issues(AppMode::Sprint).await?,
+            KeyCode::Enter => {
+                if let Some(issue) = self.sprint_view.selected_issue().cloned() {
+                    self.open_issue(issue).await?;
                 }
             }
             _ => {}
@@ -156,31 +1072,172 @@ impl App {
         Ok(())
     }
 
-    async fn handle_backlog_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
+    async fn handle_backlog_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
         match key {
             KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('6') if modifiers.contains(KeyModifiers::CONTROL) => self.toggle_previous_board().await?,
+            KeyCode::Char('^') => self.toggle_previous_board().await?,
             KeyCode::Char('h') => self.show_help = !self.show_help,
             KeyCode::Char('s') => {
                 self.mode = AppMode::Sprint;
                 self.refresh_sprint().await?;
             }
             KeyCode::Char('b') => self.mode = AppMode::Backlog,
-            KeyCode::Char('r') => self.load_backlog().await?,
+            KeyCode::Char('c') => self.open_board(AppMode::Backlog).await?,
+            KeyCode::Char('r') => {
+                self.last_refresh = Instant::now();
+                self.load_backlog();
+            }
+            KeyCode::Char('R') => self.refresh_selected_issue(AppMode::Backlog).await?,
+            KeyCode::Char('n') => self.begin_create_issue(AppMode::Backlog).await?,
             KeyCode::Down | KeyCode::Char('j') => self.backlog_view.next(),
             KeyCode::Up | KeyCode::Char('k') => self.backlog_view.previous(),
-            KeyCode::Enter => {
+            KeyCode::Char('u') => {
+                let account_id = self.current_account_id().await?;
+                self.backlog_view.select_next_assigned_to(&account_id);
+            }
+            KeyCode::Char('p') => {
+                // Toggle the key prefix filter, using the configured prefix or else
+                // the project of the selected issue
+                let prefix = if self.backlog_view.key_prefix.is_some() {
+                    None
+                } else {
+                    self.config.ui.backlog_key_prefix.clone().or_else(|| {
+                        self.backlog_view
+                            .selected_issue()
+                            .and_then(|issue| issue.key.split_once('-'))
+                            .map(|(project, _)| project.to_string())
+                    })
+                };
+                self.backlog_view.set_key_prefix(prefix);
+            }
+            KeyCode::Char('Q') => self.begin_quick_filter(AppMode::Backlog).await?,
+            KeyCode::Char('f') => {
+                self.return_mode = AppMode::Backlog;
+                self.mode = AppMode::FilterList;
+            }
+            KeyCode::Char('C') => self.toggle_status_counts()?,
+            KeyCode::Char('/') => self.begin_search(AppMode::Backlog),
+            KeyCode::Char('m') => {
+                if let Some(issue) = self.backlog_view.selected_issue().cloned() {
+                    self.copy_markdown(&issue)?;
+                }
+            }
+            KeyCode::Char('i') => {
+                if let Some(issue) = self.backlog_view.selected_issue().cloned() {
+                    self.start_work(issue).await?;
+                }
+            }
+            KeyCode::Char('v') => {
+                if let Some(issue) = self.backlog_view.selected_issue().cloned() {
+                    self.peek_issue = Some(issue);
+                    self.return_mode = AppMode::Backlog;
+                    self.mode = AppMode::QuickPeek;
+                }
+            }
+            KeyCode::Char('a') => self.begin_assignee_filter(AppMode::Backlog),
+            KeyCode::Char('l') => {
+                let labels = self.backlog_view.labels();
+                let current = self.backlog_view.label_filter.clone();
+                self.begin_label_filter(labels, current, AppMode::Backlog);
+            }
+            KeyCode::Char('D') => {
                 if let Some(issue) = self.backlog_view.selected_issue() {
-                    let issue_key = issue.key.clone();
-                    self.issue_detail_view.set_issue(issue.clone());
-                    self.load_transitions(&issue_key).await?;
-                    self.mode = AppMode::IssueDetail;
+                    self.begin_delete_issue(issue.clone(), AppMode::Backlog).await?;
+                }
+            }
+            KeyCode::Char('#') => {
+                if let Some(issue) = self.backlog_view.selected_issue() {
+                    self.begin_estimate(issue.clone(), AppMode::Backlog);
+                }
+            }
+            KeyCode::Char('T') => {
+                if let Some(issue) = self.backlog_view.selected_issue() {
+                    self.begin_due_date(issue.clone(), AppMode::Backlog);
                 }
             }
+            KeyCode::Char(' ') => self.backlog_view.toggle_mark(),
+            KeyCode::Esc => self.backlog_view.marked.clear(),
+            KeyCode::Char('F') if !self.backlog_view.marked.is_empty() => {
+                let issues = self.backlog_view.marked_issues();
+                self.begin_flag_batch(issues, AppMode::Backlog).await?;
+            }
+            KeyCode::Char('F') => {
+                if let Some(issue) = self.backlog_view.selected_issue() {
+                    self.toggle_flag(issue.clone()).await?;
+                }
+            }
+            KeyCode::Char('M') => self.move_to_sprint().await?,
+            KeyCode::Char('K') => self.move_backlog_issue(true).await?,
+            KeyCode::Char('J') => self.move_backlog_issue(false).await?,
+            KeyCode::Char('G') => self.begin_go_to_issue(AppMode::Backlog),
+            KeyCode::Char('X') => self.begin_export(AppMode::Backlog),
+            KeyCode::Char('!') => self.open_blocked(AppMode::Backlog).await?,
+            KeyCode::Char('W') => self.open_my_issues(AppMode::Backlog).await?,
+            KeyCode::Enter => {
+                if let Some(issue) = self.backlog_view.selected_issue().cloned() {
+                    self.open_issue(issue).await?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn handle_attachment_preview_input(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Esc => self.mode = AppMode::IssueDetail,
+            KeyCode::Right | KeyCode::Char('n') => {
+                self.show_image_attachment(self.image_preview.index + 1).await;
+            }
+            KeyCode::Left | KeyCode::Char('p') => {
+                let index = self.image_preview.index.checked_sub(1).unwrap_or(usize::MAX);
+                self.show_image_attachment(index).await;
+            }
             _ => {}
         }
         Ok(())
     }
 
+    /// Open the preview overlay on the issue's `index`-th image attachment
+    /// (wrapping around); download failures are shown inside the overlay
+    async fn show_image_attachment(&mut self, index: usize) {
+        let images: Vec<crate::jira::Attachment> = self
+            .issue_detail_view
+            .issue
+            .iter()
+            .flat_map(|issue| issue.fields.attachment.iter().flatten())
+            .filter(|a| a.is_image())
+            .cloned()
+            .collect();
+        self.mode = AppMode::AttachmentPreview;
+
+        if images.is_empty() {
+            self.image_preview.show_message(
+                "Attachments".to_string(),
+                "This issue has no image attachments".to_string(),
+            );
+            return;
+        }
+
+        let index = if index == usize::MAX { images.len() - 1 } else { index % images.len() };
+        let attachment = &images[index];
+        let title = format!("{} ({}/{})", attachment.filename, index + 1, images.len());
+        self.image_preview.index = index;
+
+        #[cfg(feature = "image-preview")]
+        if self.image_preview.is_supported() {
+            match self.jira_client.download_attachment(&attachment.content).await {
+                Ok(bytes) => self.image_preview.set_image(title, &bytes),
+                Err(e) => self.image_preview.show_message(title, format!("Download failed: {}", e)),
+            }
+            return;
+        }
+
+        self.image_preview.show_unsupported(title);
+    }
+
     async fn handle_issue_detail_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
         match key {
             KeyCode::Char('q') => self.should_quit = true,
@@ -188,14 +1245,45 @@ impl App {
             KeyCode::Esc => {
                 if self.issue_detail_view.show_transitions {
                     self.issue_detail_view.show_transitions = false;
+                } else if self.issue_detail_view.selected_label.is_some() {
+                    self.issue_detail_view.selected_label = None;
+                } else if self.issue_detail_view.selected_comment.is_some() {
+                    self.issue_detail_view.selected_comment = None;
                 } else {
-                    self.mode = AppMode::Sprint;
+                    self.mode = std::mem::replace(&mut self.detail_return, AppMode::Sprint);
+                }
+            }
+            KeyCode::Char('c') if self.issue_detail_view.show_transitions => {
+                if let Some(transition) = self.issue_detail_view.selected_transition() {
+                    self.input_view = InputView::multiline(format!(
+                        "Comment for '{}' (Ctrl+S applies, empty for none)",
+                        transition.name
+                    ));
+                    self.mode = AppMode::TransitionComment;
                 }
             }
             KeyCode::Char('c') => {
-                self.input_view = InputView::new("Add Comment".to_string());
+                // Service desk comments default to internal notes, so replying
+                // to the customer takes a deliberate Tab
+                self.comment_internal = self
+                    .issue_detail_view
+                    .issue
+                    .as_ref()
+                    .filter(|issue| self.is_service_desk(issue))
+                    .map(|_| true);
+                self.comment_visibility = None;
+                self.comment_visibilities.clear();
+                self.input_view = InputView::multiline(comment_title(self.comment_internal, None));
                 self.mode = AppMode::AddComment;
             }
+            KeyCode::Char(']') if !self.issue_detail_view.show_transitions => self.issue_detail_view.next_comment(),
+            KeyCode::Char('[') if !self.issue_detail_view.show_transitions => {
+                self.issue_detail_view.previous_comment();
+            }
+            KeyCode::Char('e') if self.issue_detail_view.selected_comment.is_some() => self.begin_edit_comment().await?,
+            KeyCode::Char('d') if self.issue_detail_view.selected_comment.is_some() => {
+                self.begin_delete_comment().await?;
+            }
             KeyCode::Char('e') => {
                 self.input_view = InputView::new("Edit Issue Summary".to_string());
                 if let Some(issue) = &self.issue_detail_view.issue {
@@ -207,326 +1295,3167 @@ impl App {
             KeyCode::Char('t') => {
                 self.issue_detail_view.show_transitions = true;
             }
+            KeyCode::Char('v') if !self.issue_detail_view.show_transitions => {
+                self.show_image_attachment(0).await;
+            }
+            KeyCode::Char('F') if !self.issue_detail_view.show_transitions => {
+                if let Some(issue) = &self.issue_detail_view.issue {
+                    self.toggle_flag(issue.clone()).await?;
+                }
+            }
+            KeyCode::Char('a') if !self.issue_detail_view.show_transitions => self.begin_assign().await?,
+            KeyCode::Char('+') | KeyCode::Char('=') if !self.issue_detail_view.show_transitions => {
+                self.issue_detail_view.grow_comments();
+                self.save_detail_layout()?;
+            }
+            KeyCode::Char('-') if !self.issue_detail_view.show_transitions => {
+                self.issue_detail_view.shrink_comments();
+                self.save_detail_layout()?;
+            }
+            KeyCode::Char('m') if !self.issue_detail_view.show_transitions => {
+                if let Some(issue) = self.issue_detail_view.issue.clone() {
+                    self.copy_markdown(&issue)?;
+                }
+            }
+            KeyCode::Char('i') if !self.issue_detail_view.show_transitions => {
+                if let Some(issue) = self.issue_detail_view.issue.clone() {
+                    self.start_work(issue).await?;
+                }
+            }
+            KeyCode::Char('M') if !self.issue_detail_view.show_transitions => {
+                self.issue_detail_view.cycle_maximized();
+            }
+            KeyCode::Char('z') if !self.issue_detail_view.show_transitions => {
+                self.issue_detail_view.wrap_description = !self.issue_detail_view.wrap_description;
+                self.save_detail_layout()?;
+            }
+            KeyCode::Char('w') if !self.issue_detail_view.show_transitions => {
+                if let Some(issue) = &self.issue_detail_view.issue {
+                    self.input_view = InputView::new(format!(
+                        "Log Work on {} (e.g. 1h 30m; add '; comment' to describe it)",
+                        issue.key
+                    ));
+                    self.mode = AppMode::AddWorklog;
+                }
+            }
+            KeyCode::Char('D') if !self.issue_detail_view.show_transitions => {
+                if let Some(issue) = &self.issue_detail_view.issue {
+                    self.begin_delete_issue(issue.clone(), AppMode::IssueDetail).await?;
+                }
+            }
+            KeyCode::Char('#') if !self.issue_detail_view.show_transitions => {
+                if let Some(issue) = &self.issue_detail_view.issue {
+                    self.begin_estimate(issue.clone(), AppMode::IssueDetail);
+                }
+            }
+            KeyCode::Char('T') if !self.issue_detail_view.show_transitions => {
+                if let Some(issue) = &self.issue_detail_view.issue {
+                    self.begin_due_date(issue.clone(), AppMode::IssueDetail);
+                }
+            }
+            KeyCode::Char('p') if !self.issue_detail_view.show_transitions => {
+                let unassigned = self.issue_detail_view.issue.as_ref().is_some_and(|i| i.fields.assignee.is_none());
+                self.show_user_card(if unassigned { "Reporter" } else { "Assignee" }).await;
+            }
+            KeyCode::Char('d') if !self.issue_detail_view.show_transitions => {
+                if let Some(issue) = &self.issue_detail_view.issue {
+                    // The main loop hands the terminal to the editor, then calls `finish_external_edit`
+                    self.external_edit = Some(ExternalEdit::Description {
+                        issue_key: issue.key.clone(),
+                        text: issue.fields.description.clone().unwrap_or_default(),
+                    });
+                }
+            }
+            KeyCode::Char('E') if !self.issue_detail_view.show_transitions => {
+                self.input_view = InputView::new("Edit Environment (empty to clear)".to_string());
+                if let Some(environment) = self
+                    .issue_detail_view
+                    .issue
+                    .as_ref()
+                    .and_then(|issue| issue.fields.environment.as_ref())
+                {
+                    // The prompt is a single line
+                    self.input_view.input = environment.lines().collect::<Vec<_>>().join(" ");
+                    self.input_view.cursor_position = self.input_view.input.len();
+                }
+                self.mode = AppMode::EditEnvironment;
+            }
             KeyCode::Down | KeyCode::Char('j') if self.issue_detail_view.show_transitions => {
                 self.issue_detail_view.next_transition();
             }
             KeyCode::Up | KeyCode::Char('k') if self.issue_detail_view.show_transitions => {
                 self.issue_detail_view.previous_transition();
             }
+            KeyCode::Down | KeyCode::Char('j') => self.issue_detail_view.scroll_description_down(),
+            KeyCode::Up | KeyCode::Char('k') => self.issue_detail_view.scroll_description_up(),
+            KeyCode::Char('>') => self.issue_detail_view.scroll_description_right(),
+            KeyCode::Char('<') => self.issue_detail_view.scroll_description_left(),
             KeyCode::Enter if self.issue_detail_view.show_transitions => {
-                if let Some(transition) = self.issue_detail_view.selected_transition() {
-                    if let Some(issue) = &self.issue_detail_view.issue {
-                        let issue_key = issue.key.clone();
-                        let transition_id = transition.id.clone();
-                        self.jira_client.transition_issue(&issue_key, &transition_id).await?;
-                        // Refresh issue details
-                        let updated_issue = self.jira_client.get_issue(&issue_key).await?;
-                        self.issue_detail_view.set_issue(updated_issue);
-                        self.load_transitions(&issue_key).await?;
-                    }
+                self.request_transition(None).await?;
+            }
+            KeyCode::Tab if !self.issue_detail_view.show_transitions => self.issue_detail_view.next_label(),
+            KeyCode::BackTab if !self.issue_detail_view.show_transitions => {
+                self.issue_detail_view.previous_label();
+            }
+            KeyCode::Enter => {
+                if let Some(label) = self.issue_detail_view.selected_label() {
+                    self.search_label(&label).await?;
                 }
-                self.issue_detail_view.show_transitions = false;
             }
             _ => {}
         }
         Ok(())
     }
 
-    async fn handle_comment_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
+    /// The selected comment, if the signed-in user wrote it; Jira refuses
+    /// edits to anyone else's
+    async fn own_selected_comment(&mut self) -> Result<Option<crate::jira::Comment>> {
+        let account_id = self.current_account_id().await?;
+        self.issue_detail_view.account_id = Some(account_id);
+        let Some(comment) = self.issue_detail_view.selected_comment().cloned() else {
+            return Ok(None);
+        };
+        if !self.issue_detail_view.is_own(&comment) {
+            self.toasts.error(format!("Only your own comments can be changed; this one is {}'s", comment.author.display_name));
+            return Ok(None);
+        }
+        Ok(Some(comment))
+    }
+
+    async fn begin_edit_comment(&mut self) -> Result<()> {
+        let Some(comment) = self.own_selected_comment().await? else {
+            return Ok(());
+        };
+        if comment.rich {
+            // Only plain text can be edited here, and saving it would replace the whole body
+            self.toasts.error("This comment has mentions, links, tables or other formatting that editing here would drop; edit it in Jira");
+            return Ok(());
+        }
+        self.input_view = InputView::multiline("Edit Comment".to_string());
+        self.input_view.input = comment.body.clone();
+        self.input_view.cursor_position = self.input_view.input.len();
+        self.pending_comment = Some(comment.id);
+        self.mode = AppMode::EditComment;
+        Ok(())
+    }
+
+    async fn handle_edit_comment_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
         match key {
             KeyCode::Esc => {
+                self.pending_comment = None;
                 self.input_view.clear();
                 self.mode = AppMode::IssueDetail;
             }
-            KeyCode::Enter => {
-                if let Some(issue) = &self.issue_detail_view.issue {
-                    let comment = self.input_view.get_input();
-                    if !comment.is_empty() {
-                        self.jira_client.add_comment(&issue.key, comment).await?;
-                        // Refresh issue details
-                        let updated_issue = self.jira_client.get_issue(&issue.key).await?;
-                        self.issue_detail_view.set_issue(updated_issue);
-                    }
+            _ if self.input_view.is_submit(key, modifiers) => {
+                let body = self.input_view.get_input().trim().to_string();
+                if body.is_empty() {
+                    self.toasts.error("A comment can't be empty; use d to delete it");
+                    return Ok(());
+                }
+                let (Some(issue), Some(comment_id)) = (self.issue_detail_view.issue.clone(), self.pending_comment.clone()) else {
+                    return Ok(());
+                };
+                if let Err(e) = self.jira_client.update_comment(&issue.key, &comment_id, &body).await {
+                    // Keep the prompt open so nothing typed is lost
+                    self.toasts.error(format!("Could not update the comment: {}", e));
+                    return Ok(());
                 }
+                self.pending_comment = None;
                 self.input_view.clear();
                 self.mode = AppMode::IssueDetail;
+                self.toasts.success(format!("Updated comment on {}", issue.key));
+                self.reload_comments(issue, |comments| {
+                    if let Some(comment) = comments.comments.iter_mut().find(|c| c.id == comment_id) {
+                        comment.body = body;
+                        comment.updated = chrono::Utc::now();
+                    }
+                })
+                .await?;
             }
-            KeyCode::Backspace => self.input_view.pop_char(),
-            KeyCode::Left => self.input_view.move_cursor_left(),
-            KeyCode::Right => self.input_view.move_cursor_right(),
-            KeyCode::Char(c) => self.input_view.push_char(c),
-            _ => {}
+            _ => self.input_view.handle_key(key, modifiers),
         }
         Ok(())
     }
 
-    async fn handle_edit_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
-        match key {
-            KeyCode::Esc => {
-                self.input_view.clear();
-                self.mode = AppMode::IssueDetail;
+    async fn begin_delete_comment(&mut self) -> Result<()> {
+        let Some(comment) = self.own_selected_comment().await? else {
+            return Ok(());
+        };
+        let Some(issue_key) = self.issue_detail_view.issue.as_ref().map(|i| i.key.clone()) else {
+            return Ok(());
+        };
+        let preview: String = comment.body.lines().next().unwrap_or_default().chars().take(60).collect();
+        self.confirm_dialog.open("Delete Comment", format!("Delete your comment \"{}\"?", preview), true);
+        self.ask_confirmation(ConfirmAction::DeleteComment { issue_key, comment_id: comment.id });
+        Ok(())
+    }
+
+    async fn delete_comment(&mut self, issue_key: &str, comment_id: &str) -> Result<()> {
+        let Some(issue) = self.issue_detail_view.issue.clone().filter(|i| i.key == issue_key) else {
+            return Ok(());
+        };
+        if let Err(e) = self.jira_client.delete_comment(issue_key, comment_id).await {
+            self.toasts.error(format!("Could not delete the comment: {}", e));
+            return Ok(());
+        }
+        self.toasts.success(format!("Deleted comment on {}", issue_key));
+        self.reload_comments(issue, |comments| {
+            comments.comments.retain(|c| c.id != comment_id);
+            comments.total = comments.comments.len() as u32;
+        })
+        .await
+    }
+
+    /// Show a comment change: fetched back from Jira, or applied locally with
+    /// `change` in dry-run mode
+    async fn reload_comments(
+        &mut self,
+        mut issue: crate::jira::Issue,
+        change: impl FnOnce(&mut crate::jira::Comments),
+    ) -> Result<()> {
+        if self.jira_client.is_dry_run() {
+            if let Some(comments) = issue.fields.comment.as_mut() {
+                change(comments);
+            }
+            self.apply_local_issue(issue);
+        } else {
+            let updated_issue = self.jira_client.get_issue(&issue.key).await?;
+            self.issue_detail_view.set_issue(updated_issue);
+        }
+        Ok(())
+    }
+
+    async fn handle_transition_comment_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => {
+                self.input_view.clear();
+                self.mode = AppMode::IssueDetail;
+            }
+            _ if self.input_view.is_submit(key, modifiers) => {
+                let comment = self.input_view.get_input().trim().to_string();
+                self.input_view.clear();
+                self.mode = AppMode::IssueDetail;
+                self.request_transition(Some(comment).filter(|c| !c.is_empty())).await?;
+            }
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    /// Apply the selected transition, asking first when `ui.confirm.transition` is on
+    async fn request_transition(&mut self, comment: Option<String>) -> Result<()> {
+        if !self.config.ui.confirm.transition {
+            return self.apply_transition(comment).await;
+        }
+        let key = self.issue_detail_view.issue.as_ref().map_or("", |i| i.key.as_str());
+        let transition = self.issue_detail_view.selected_transition().map_or("", |t| t.name.as_str());
+        let mut message = format!("Apply '{}' to {}?", transition, key);
+        if let Some(comment) = &comment {
+            message.push_str(&format!("\n\nWith comment: {}", comment));
+        }
+        self.confirm_dialog.open("Confirm Transition", message, false);
+        self.ask_confirmation(ConfirmAction::Transition { comment });
+        Ok(())
+    }
+
+    /// Open the confirm dialog, set up by the caller, for `action`
+    fn ask_confirmation(&mut self, action: ConfirmAction) {
+        let return_mode = std::mem::replace(&mut self.mode, AppMode::Confirm);
+        self.pending_confirm = Some((action, return_mode));
+    }
+
+    async fn handle_confirm_input(&mut self, key: KeyCode) -> Result<()> {
+        let confirmed = match key {
+            KeyCode::Char('y') | KeyCode::Enter => true,
+            KeyCode::Char('n') | KeyCode::Esc => false,
+            _ => return Ok(()),
+        };
+        let Some((action, return_mode)) = self.pending_confirm.take() else {
+            return Ok(());
+        };
+        self.mode = return_mode;
+        if confirmed {
+            self.run_confirmed(action).await?;
+        }
+        Ok(())
+    }
+
+    async fn run_confirmed(&mut self, action: ConfirmAction) -> Result<()> {
+        match action {
+            ConfirmAction::Transition { comment } => self.apply_transition(comment).await,
+            ConfirmAction::DeleteComment { issue_key, comment_id } => self.delete_comment(&issue_key, &comment_id).await,
+            ConfirmAction::StartSprint { sprint_id, sprint_name, end_date } => {
+                self.start_sprint(sprint_id, &sprint_name, end_date).await
+            }
+        }
+    }
+
+    async fn handle_resolution_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => {
+                self.input_view.clear();
+                self.pending_transition_comment = None;
+                self.mode = AppMode::IssueDetail;
             }
             KeyCode::Enter => {
-                if let Some(_issue) = &self.issue_detail_view.issue {
-                    let new_summary = self.input_view.get_input();
-                    if !new_summary.is_empty() {
-                        // This is a simplified example - in reality you'd need to construct
-                        // the proper update object for Jira
-                        // For now, we'll just skip the actual update
-                        // let update = IssueUpdate { ... };
-                        // self.jira_client.update_issue(&issue.key, update).await?;
+                let name = self.input_view.chosen_suggestion().cloned().unwrap_or_default();
+                let Some(resolution) = self.resolutions.iter().find(|r| r.name == name).cloned() else {
+                    // Keep the prompt open so the name can be fixed
+                    self.toasts.error(format!("No resolution matches '{}'", self.input_view.get_input().trim()));
+                    return Ok(());
+                };
+                self.input_view.clear();
+                self.mode = AppMode::IssueDetail;
+                let comment = self.pending_transition_comment.take();
+                self.run_transition(comment, Some(resolution)).await?;
+            }
+            KeyCode::Tab => self.input_view.complete(),
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    /// Apply the selected transition, with an optional comment sent in the same
+    /// request. Transitions that require a resolution ask for one first.
+    async fn apply_transition(&mut self, comment: Option<String>) -> Result<()> {
+        let Some(transition) = self.issue_detail_view.selected_transition() else {
+            return Ok(());
+        };
+        if !transition.requires_resolution() {
+            return self.run_transition(comment, None).await;
+        }
+
+        let title = format!("Resolution for '{}' (Tab completes)", transition.name);
+        if self.resolutions.is_empty() {
+            self.resolutions = self.jira_client.get_resolutions().await?;
+        }
+        self.input_view = InputView::new(title);
+        self.input_view
+            .set_suggestions(self.resolutions.iter().map(|r| r.name.clone()).collect(), self.matcher());
+        if let Some(first) = self.resolutions.first() {
+            self.input_view.input = first.name.clone();
+            self.input_view.cursor_position = self.input_view.input.len();
+        }
+        self.pending_transition_comment = comment;
+        self.mode = AppMode::PickResolution;
+        Ok(())
+    }
+
+    async fn run_transition(
+        &mut self,
+        comment: Option<String>,
+        resolution: Option<crate::jira::Resolution>,
+    ) -> Result<()> {
+        if let Some(transition) = self.issue_detail_view.selected_transition()
+            && let Some(issue) = &self.issue_detail_view.issue
+        {
+            let issue_key = issue.key.clone();
+            let transition_id = transition.id.clone();
+            self.jira_client
+                .transition_issue(
+                    &issue_key,
+                    &transition_id,
+                    comment.as_deref(),
+                    resolution.as_ref().map(|r| r.id.as_str()),
+                )
+                .await?;
+            if self.jira_client.is_dry_run() {
+                // Nothing changed server-side, so show the expected result locally
+                let mut updated_issue = issue.clone();
+                updated_issue.fields.status = transition.to.clone();
+                if let Some(resolution) = &resolution {
+                    updated_issue.fields.resolutiondate = Some(chrono::Utc::now());
+                    updated_issue.fields.other.insert("resolution".to_string(), serde_json::json!(resolution));
+                }
+                if let Some(comment) = &comment {
+                    self.push_local_comment(&mut updated_issue, comment);
+                }
+                self.apply_local_issue(updated_issue);
+            } else {
+                // Refresh issue details
+                let updated_issue = self.jira_client.get_issue(&issue_key).await?;
+                self.issue_detail_view.set_issue(updated_issue);
+                self.load_transitions(&issue_key).await?;
+            }
+        }
+        self.issue_detail_view.show_transitions = false;
+        Ok(())
+    }
+
+    /// Append a comment authored by the configured user, for dry-run previews
+    fn push_local_comment(&self, issue: &mut crate::jira::Issue, body: &str) {
+        let now = chrono::Utc::now();
+        let comments = issue.fields.comment.get_or_insert(crate::jira::Comments {
+            comments: Vec::new(),
+            total: 0,
+        });
+        comments.comments.push(crate::jira::Comment {
+            id: String::new(),
+            body: body.to_string(),
+            rich: false,
+            author: crate::jira::User {
+                account_id: String::new(),
+                display_name: self.config.jira.username.clone(),
+                email_address: None,
+                time_zone: None,
+                active: None,
+            },
+            created: now,
+            updated: now,
+        });
+        comments.total += 1;
+    }
+
+    async fn handle_comment_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => {
+                self.input_view.clear();
+                self.mode = AppMode::IssueDetail;
+            }
+            _ if self.input_view.is_submit(key, modifiers) => {
+                if let Some(issue) = &self.issue_detail_view.issue {
+                    let comment = self.input_view.get_input().trim();
+                    if !comment.is_empty() {
+                        let internal = self.comment_internal.unwrap_or(false);
+                        let visibility = self.comment_visibility.clone();
+                        self.jira_client.add_comment(&issue.key, comment, internal, visibility).await?;
+                        let kind = if internal { "internal note" } else { "comment" };
+                        self.toasts.success(format!("Added {} to {}", kind, issue.key));
+                        if self.jira_client.is_dry_run() {
+                            let mut updated_issue = issue.clone();
+                            self.push_local_comment(&mut updated_issue, comment);
+                            self.apply_local_issue(updated_issue);
+                        } else {
+                            // Refresh issue details
+                            let updated_issue = self.jira_client.get_issue(&issue.key).await?;
+                            self.issue_detail_view.set_issue(updated_issue);
+                        }
                     }
                 }
                 self.input_view.clear();
                 self.mode = AppMode::IssueDetail;
             }
-            KeyCode::Backspace => self.input_view.pop_char(),
-            KeyCode::Left => self.input_view.move_cursor_left(),
-            KeyCode::Right => self.input_view.move_cursor_right(),
-            KeyCode::Char(c) => self.input_view.push_char(c),
-            _ => {}
+            KeyCode::Tab if self.comment_internal.is_some() => {
+                self.comment_internal = self.comment_internal.map(|internal| !internal);
+                self.input_view.title = comment_title(self.comment_internal, self.comment_visibility.as_ref());
+            }
+            KeyCode::Char('r') if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.cycle_comment_visibility().await?;
+            }
+            _ => self.input_view.handle_key(key, modifiers),
         }
         Ok(())
     }
 
-    async fn handle_sprint_selector_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
+    /// Step the comment restriction: unrestricted → each role → each group → unrestricted
+    async fn cycle_comment_visibility(&mut self) -> Result<()> {
+        if self.comment_visibilities.is_empty() {
+            let Some(issue) = &self.issue_detail_view.issue else {
+                return Ok(());
+            };
+            let project_key = issue.key.split('-').next().unwrap_or_default().to_string();
+            self.comment_visibilities = self.jira_client.get_comment_visibilities(&project_key).await?;
+            if self.comment_visibilities.is_empty() {
+                self.toasts.info("No roles or groups to restrict the comment to");
+                return Ok(());
+            }
+        }
+        let next = match &self.comment_visibility {
+            None => 0,
+            Some(current) => {
+                self.comment_visibilities.iter().position(|v| v == current).map_or(0, |i| i + 1)
+            }
+        };
+        self.comment_visibility = self.comment_visibilities.get(next).cloned();
+        self.input_view.title = comment_title(self.comment_internal, self.comment_visibility.as_ref());
+        Ok(())
+    }
+
+    /// Service desk projects distinguish internal notes from customer replies
+    fn is_service_desk(&self, issue: &crate::jira::Issue) -> bool {
+        let project_key = issue.key.split('-').next().unwrap_or_default();
+        let project_type = issue.project_type().or_else(|| {
+            self.available_projects
+                .iter()
+                .find(|p| p.key == project_key)
+                .map(|p| p.project_type_key.as_str())
+        });
+        project_type == Some("service_desk")
+    }
+
+    async fn handle_edit_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
         match key {
-            KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Char('h') => self.show_help = !self.show_help,
             KeyCode::Esc => {
-                self.sprint_selector.deactivate();
-                self.mode = AppMode::Sprint;
+                self.input_view.clear();
+                self.mode = AppMode::IssueDetail;
             }
-            KeyCode::Down | KeyCode::Char('j') => self.sprint_selector.next(),
-            KeyCode::Up | KeyCode::Char('k') => self.sprint_selector.previous(),
             KeyCode::Enter => {
-                if let Some(sprint_id) = self.sprint_selector.selected_sprint_id() {
-                    self.current_sprint_id = Some(sprint_id);
-                    self.load_sprint_issues(sprint_id).await?;
-                    self.sprint_selector.deactivate();
-                    self.mode = AppMode::Sprint;
+                let Some(issue) = &self.issue_detail_view.issue else {
+                    return Ok(());
+                };
+                let summary = self.input_view.get_input().trim().to_string();
+                if summary.is_empty() {
+                    // Jira requires a summary; keep the prompt open
+                    self.toasts.error("The summary can't be empty");
+                    return Ok(());
+                }
+                if summary == issue.fields.summary {
+                    self.input_view.clear();
+                    self.mode = AppMode::IssueDetail;
+                    return Ok(());
                 }
+                self.pending_edit = Some(PendingEdit {
+                    issue_key: issue.key.clone(),
+                    field: EditedField::Summary,
+                    before: issue.fields.summary.clone(),
+                    after: summary,
+                });
+                self.edit_preview_scroll = 0;
+                self.mode = AppMode::ConfirmEdit;
             }
-            KeyCode::Char('e') => {
-                if let Some(sprint) = self.sprint_selector.selected_sprint() {
-                    self.input_view = InputView::new(format!("Edit Sprint Name: {}", sprint.name));
-                    self.input_view.input = sprint.name.clone();
-                    self.input_view.cursor_position = self.input_view.input.len();
-                    self.mode = AppMode::EditSprintName;
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    /// Diff preview of a summary or description edit: `y` sends it, `n` goes
+    /// back to editing with the text kept
+    async fn handle_confirm_edit_input(&mut self, key: KeyCode) -> Result<()> {
+        let Some(edit) = self.pending_edit.clone() else {
+            self.mode = AppMode::IssueDetail;
+            return Ok(());
+        };
+        match key {
+            KeyCode::Char('y') | KeyCode::Enter => {
+                let mut issue = self.issue_detail_view.issue.clone().filter(|i| i.key == edit.issue_key);
+                // On failure (e.g. no edit permission) the preview stays open for another try
+                let sent = match edit.field {
+                    EditedField::Summary => self.jira_client.set_summary(&edit.issue_key, &edit.after).await,
+                    EditedField::Description => self.jira_client.set_description(&edit.issue_key, &edit.after).await,
+                };
+                if let Err(e) = sent {
+                    self.toasts.error(format!("Could not update {}: {}", edit.issue_key, e));
+                    return Ok(());
+                }
+                self.pending_edit = None;
+                self.input_view.clear();
+                self.toasts.success(format!("Updated {}", edit.issue_key));
+                self.mode = AppMode::IssueDetail;
+                if !self.jira_client.is_dry_run() {
+                    // Show what Jira stored, along with its new update time
+                    issue = Some(self.jira_client.get_issue(&edit.issue_key).await?);
+                } else if let Some(issue) = issue.as_mut() {
+                    match edit.field {
+                        EditedField::Summary => issue.fields.summary = edit.after.clone(),
+                        EditedField::Description => {
+                            issue.fields.description = (!edit.after.is_empty()).then(|| edit.after.clone())
+                        }
+                    }
+                }
+                if let Some(issue) = issue {
+                    self.apply_local_issue(issue);
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Esc => {
+                self.pending_edit = None;
+                match edit.field {
+                    // The prompt still holds the typed summary
+                    EditedField::Summary => self.mode = AppMode::EditIssue,
+                    EditedField::Description => {
+                        self.mode = AppMode::IssueDetail;
+                        self.external_edit = Some(ExternalEdit::Description {
+                            issue_key: edit.issue_key,
+                            text: edit.after,
+                        });
+                    }
                 }
             }
+            KeyCode::Char('c') => {
+                // Drop the edit altogether
+                self.pending_edit = None;
+                self.input_view.clear();
+                self.mode = AppMode::IssueDetail;
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.edit_preview_scroll = self.edit_preview_scroll.saturating_add(1),
+            KeyCode::Up | KeyCode::Char('k') => self.edit_preview_scroll = self.edit_preview_scroll.saturating_sub(1),
             _ => {}
         }
         Ok(())
     }
 
-    async fn handle_edit_sprint_name_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
+    /// Called by the main loop once the editor exits (or failed to start)
+    pub fn finish_external_edit(&mut self, edit: ExternalEdit, result: Result<PathBuf>) {
+        let path = match result {
+            Ok(path) => path,
+            Err(e) => {
+                self.toasts.error(format!("Could not open the editor: {}", e));
+                return;
+            }
+        };
+        let ExternalEdit::Description { issue_key, .. } = edit else {
+            self.reload_config();
+            return;
+        };
+        let edited = std::fs::read_to_string(&path);
+        let _ = std::fs::remove_file(&path);
+        let after = match edited {
+            Ok(text) => text.trim_end().to_string(),
+            Err(e) => {
+                self.toasts.error(format!("Could not read the edited description: {}", e));
+                return;
+            }
+        };
+        let before = self
+            .issue_detail_view
+            .issue
+            .as_ref()
+            .filter(|issue| issue.key == issue_key)
+            .and_then(|issue| issue.fields.description.clone())
+            .unwrap_or_default();
+        if after == before.trim_end() {
+            self.toasts.info("Description unchanged");
+            return;
+        }
+        self.pending_edit = Some(PendingEdit {
+            issue_key,
+            field: EditedField::Description,
+            before,
+            after,
+        });
+        self.edit_preview_scroll = 0;
+        self.mode = AppMode::ConfirmEdit;
+    }
+
+    async fn handle_edit_environment_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => {
+                self.input_view.clear();
+                self.mode = AppMode::IssueDetail;
+            }
+            KeyCode::Enter => {
+                if let Some(mut issue) = self.issue_detail_view.issue.clone() {
+                    let environment = self.input_view.get_input().trim().to_string();
+                    self.jira_client.set_environment(&issue.key, &environment).await?;
+                    self.toasts.success(format!("Updated environment of {}", issue.key));
+                    issue.fields.environment = (!environment.is_empty()).then_some(environment);
+                    self.apply_local_issue(issue);
+                }
+                self.input_view.clear();
+                self.mode = AppMode::IssueDetail;
+            }
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    fn begin_label_filter(&mut self, labels: Vec<String>, current: Option<String>, return_mode: AppMode) {
+        self.input_view = InputView::new("Filter by Label (empty to clear)".to_string());
+        self.input_view.set_suggestions(labels, self.matcher());
+        if let Some(label) = current {
+            self.input_view.input = label;
+            self.input_view.cursor_position = self.input_view.input.len();
+        }
+        self.return_mode = return_mode;
+        self.mode = AppMode::FilterLabel;
+    }
+
+    async fn handle_filter_label_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
         match key {
             KeyCode::Esc => {
                 self.input_view.clear();
-                self.mode = AppMode::SprintSelector;
+                self.mode = self.return_mode.clone();
             }
             KeyCode::Enter => {
-                if let Some(sprint) = self.sprint_selector.selected_sprint() {
-                    let new_name = self.input_view.get_input();
-                    if !new_name.is_empty() {
-                        let update = crate::jira::SprintUpdate {
-                            name: Some(new_name.to_string()),
-                            ..Default::default()
-                        };
-                        self.jira_client.update_sprint(sprint.id, &update).await?;
-                        self.refresh_sprints().await?;
-                    }
+                let label = self.input_view.get_input().trim();
+                let filter = if label.is_empty() { None } else { Some(label.to_string()) };
+                if self.return_mode == AppMode::Backlog {
+                    self.backlog_view.set_label_filter(filter);
+                } else {
+                    self.sprint_view.set_label_filter(filter);
                 }
                 self.input_view.clear();
-                self.mode = AppMode::SprintSelector;
+                self.mode = self.return_mode.clone();
             }
-            KeyCode::Backspace => self.input_view.pop_char(),
-            KeyCode::Left => self.input_view.move_cursor_left(),
-            KeyCode::Right => self.input_view.move_cursor_right(),
-            KeyCode::Char(c) => self.input_view.push_char(c),
+            KeyCode::Tab => self.input_view.complete(),
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    /// Typing narrows the sprint or backlog list as it goes; Enter keeps the
+    /// filter and Esc clears it
+    fn handle_filter_list_input(&mut self, key: KeyCode) {
+        let backlog = self.return_mode == AppMode::Backlog;
+        let mut filter = if backlog {
+            self.backlog_view.text_filter.clone()
+        } else {
+            self.sprint_view.text_filter.clone()
+        };
+        match key {
+            KeyCode::Esc => {
+                filter.clear();
+                self.mode = self.return_mode.clone();
+            }
+            KeyCode::Enter => self.mode = self.return_mode.clone(),
+            KeyCode::Down if backlog => self.backlog_view.next(),
+            KeyCode::Up if backlog => self.backlog_view.previous(),
+            KeyCode::Down => self.sprint_view.next(),
+            KeyCode::Up => self.sprint_view.previous(),
+            KeyCode::Backspace => {
+                filter.pop();
+            }
+            KeyCode::Char(c) => filter.push(c),
             _ => {}
         }
+        if backlog {
+            self.backlog_view.set_text_filter(filter);
+        } else {
+            self.sprint_view.set_text_filter(filter);
+        }
+    }
+
+    async fn begin_quick_filter(&mut self, return_mode: AppMode) -> Result<()> {
+        let Some(board_id) = self.config.jira.default_board_id else {
+            return Ok(());
+        };
+        self.quick_filters = self.jira_client.get_board_quick_filters(board_id).await?;
+        if self.quick_filters.is_empty() {
+            self.toasts.info("This board has no quick filters");
+            return Ok(());
+        }
+        self.input_view = InputView::new("Quick Filter (Tab completes, empty to clear)".to_string());
+        self.input_view
+            .set_suggestions(self.quick_filters.iter().map(|f| f.name.clone()).collect(), self.matcher());
+        if let Some(filter) = &self.quick_filter {
+            self.input_view.input = filter.name.clone();
+            self.input_view.cursor_position = self.input_view.input.len();
+        }
+        self.return_mode = return_mode;
+        self.mode = AppMode::QuickFilter;
         Ok(())
     }
 
-    async fn handle_board_selector_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
+    async fn handle_quick_filter_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
         match key {
-            KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Char('h') => self.show_help = !self.show_help,
             KeyCode::Esc => {
-                self.board_selector.deactivate();
-                self.mode = AppMode::Sprint;
+                self.input_view.clear();
+                self.mode = self.return_mode.clone();
             }
-            KeyCode::Down | KeyCode::Char('j') => self.board_selector.next(),
-            KeyCode::Up | KeyCode::Char('k') => self.board_selector.previous(),
             KeyCode::Enter => {
-                if let Some(board_id) = self.board_selector.selected_board_id() {
-                    self.config.jira.default_board_id = Some(board_id);
-                    // Clear sprint data to force reload for new board
-                    self.available_sprints.clear();
-                    self.current_sprint_id = None;
-                    // Load new board's sprint data
+                let name = self.input_view.chosen_suggestion().cloned();
+                let filter = self.quick_filters.iter().find(|f| Some(&f.name) == name.as_ref()).cloned();
+                let typed = self.input_view.get_input().trim();
+                if filter.is_none() && !typed.is_empty() {
+                    // Keep the prompt open so the name can be fixed
+                    self.toasts.error(format!("No quick filter matches '{}'", typed));
+                    return Ok(());
+                }
+                self.quick_filter = filter;
+                self.input_view.clear();
+                self.mode = self.return_mode.clone();
+                if self.mode == AppMode::Backlog {
+                    self.load_backlog();
+                } else {
                     self.refresh_sprint().await?;
-                    self.board_selector.deactivate();
-                    self.mode = AppMode::Sprint;
                 }
             }
-            _ => {}
+            KeyCode::Tab => self.input_view.complete(),
+            _ => self.input_view.handle_key(key, modifiers),
         }
         Ok(())
     }
 
-    async fn handle_project_selector_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
+    /// Offer the users seen in loaded issues; other names are looked up in the directory
+    fn begin_assignee_filter(&mut self, return_mode: AppMode) {
+        self.assignee_candidates = self.jira_client.cached_users("", self.matcher());
+        self.input_view = InputView::new("Filter by Assignee (Tab completes, empty to clear)".to_string());
+        let names = self.assignee_candidates.iter().map(|u| u.display_name.clone()).collect();
+        self.input_view.set_suggestions(names, self.matcher());
+        if let Some(user) = &self.assignee_filter {
+            self.input_view.input = user.display_name.clone();
+            self.input_view.cursor_position = self.input_view.input.len();
+        }
+        self.return_mode = return_mode;
+        self.mode = AppMode::FilterAssignee;
+    }
+
+    async fn handle_filter_assignee_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
         match key {
-            KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Char('h') => self.show_help = !self.show_help,
             KeyCode::Esc => {
-                self.project_selector.deactivate();
-                self.mode = AppMode::Sprint;
+                self.input_view.clear();
+                self.mode = self.return_mode.clone();
             }
-            KeyCode::Down | KeyCode::Char('j') => self.project_selector.next(),
-            KeyCode::Up | KeyCode::Char('k') => self.project_selector.previous(),
             KeyCode::Enter => {
-            if let Some(project) = self.project_selector.selected_project() {
-                let project_key = &project.key;
-                    // Load boards for the selected project
-                    let project_boards = self.jira_client.get_boards().await?
-                        .into_iter()
-                        .filter(|board| {
-                            // Filter boards that belong to this project
-                            // This is a simplified check - in reality you might need to check board location or other attributes
-                            board.name.contains(&*project_key) || 
-                            board.location.as_ref().map_or(false, |loc| loc.project_key.as_deref() == Some(&*project_key))
-                        })
-                        .collect::<Vec<_>>();
-                    
-                    if !project_boards.is_empty() {
-                        // Update available boards and set the first one as default
-                        self.available_boards = project_boards;
-                        self.config.jira.default_board_id = Some(self.available_boards[0].id);
-                        
-                        // Clear sprint data to force reload for new board
-                        self.available_sprints.clear();
-                        self.current_sprint_id = None;
-                        
-                        // Load new board's sprint data
-                        self.refresh_sprint().await?;
+                let name = self.input_view.get_input().trim().to_lowercase();
+                let known = self
+                    .assignee_candidates
+                    .iter()
+                    .find(|u| u.display_name.to_lowercase() == name)
+                    .cloned();
+                let user = match known {
+                    Some(user) => Some(user),
+                    None if name.is_empty() => None,
+                    None => {
+                        let users = self.jira_client.search_users(&name).await?;
+                        if users.len() != 1 {
+                            // Keep the prompt open with the matches to pick from
+                            if users.is_empty() {
+                                self.toasts.error(format!("No users match '{}'", name));
+                            } else {
+                                self.toasts.info(format!("{} users match, Tab completes", users.len()));
+                            }
+                            let names = users.iter().map(|u| u.display_name.clone()).collect();
+                            self.input_view.set_suggestions(names, self.matcher());
+                            self.assignee_candidates = users;
+                            return Ok(());
+                        }
+                        users.into_iter().next()
                     }
-                    
-                    self.project_selector.deactivate();
-                    self.mode = AppMode::Sprint;
+                };
+                self.assignee_filter = user;
+                self.input_view.clear();
+                self.mode = self.return_mode.clone();
+                if self.mode == AppMode::Backlog {
+                    self.load_backlog();
+                } else {
+                    self.refresh_sprint().await?;
+                }
+            }
+            KeyCode::Tab => self.input_view.complete(),
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    /// Remember the comments pane height and description wrapping across sessions
+    fn save_detail_layout(&mut self) -> Result<()> {
+        let view = &self.issue_detail_view;
+        if self.config.ui.comments_height == view.comments_height
+            && self.config.ui.wrap_description == view.wrap_description
+        {
+            return Ok(());
+        }
+        self.config.ui.comments_height = view.comments_height;
+        self.config.ui.wrap_description = view.wrap_description;
+        Config::save_ui(&self.config.ui)
+    }
+
+    /// Remember the folded sprint view groups across sessions
+    fn save_collapsed_groups(&mut self) -> Result<()> {
+        let groups = self.sprint_view.collapsed_groups();
+        if self.config.ui.collapsed_groups == groups {
+            return Ok(());
+        }
+        self.config.ui.collapsed_groups = groups;
+        Config::save_ui(&self.config.ui)
+    }
+
+    fn begin_search(&mut self, return_mode: AppMode) {
+        self.search_view.begin(return_mode);
+        self.mode = AppMode::Search;
+        self.update_search_prompt();
+    }
+
+    /// JQL clause for the current search scope, `None` when unscoped or unknown
+    fn search_scope_clause(&self) -> Option<String> {
+        match self.search_view.scope {
+            SearchScope::Board => self.current_sprint_id.map(|id| format!("sprint = {}", id)),
+            SearchScope::Project => self
+                .available_boards
+                .iter()
+                .find(|b| Some(b.id) == self.config.jira.default_board_id)
+                .and_then(|b| b.location.as_ref()?.project_key.clone())
+                .map(|key| format!("project = \"{}\"", key)),
+            SearchScope::All => None,
+        }
+    }
+
+    fn update_search_prompt(&mut self) {
+        let clause = self.search_scope_clause();
+        self.search_view.update_prompt(clause.as_deref());
+    }
+
+    async fn handle_search_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => {
+                self.search_view.input.clear();
+                self.mode = self.search_view.return_mode.clone();
+            }
+            KeyCode::Tab => {
+                self.search_view.next_scope();
+                self.update_search_prompt();
+            }
+            KeyCode::Enter => {
+                let jql = self.search_view.jql(self.search_scope_clause().as_deref());
+                if jql.is_empty() {
+                    self.toasts.info("Type a JQL query, or Tab to a scope");
+                    return Ok(());
                 }
+                let issues = self.jira_client.search_issues(&jql).await?;
+                self.search_view.set_results(issues, format!("Search: {}", jql));
+                self.mode = AppMode::SearchResults;
+            }
+            _ => {
+                self.search_view.handle_key(key, modifiers);
+                self.update_search_prompt();
+            }
+        }
+        Ok(())
+    }
+
+    /// Ask for an issue key; recent keys and `KEY-` prefixes of the known projects
+    /// are offered for Tab
+    fn begin_go_to_issue(&mut self, return_mode: AppMode) {
+        self.input_view = InputView::new("Go to Issue (Tab completes)".to_string());
+        let suggestions = self
+            .recent_issues
+            .iter()
+            .cloned()
+            .chain(self.available_projects.iter().map(|p| format!("{}-", p.key)))
+            .collect();
+        self.input_view.set_suggestions(suggestions, self.matcher());
+        self.return_mode = return_mode;
+        self.mode = AppMode::GoToIssue;
+        self.update_go_to_issue_hint();
+    }
+
+    /// Say what Enter will open, or why the key can't be right
+    fn update_go_to_issue_hint(&mut self) {
+        let input = self.input_view.get_input().trim().to_uppercase();
+        self.input_view.hint = if input.is_empty() {
+            None
+        } else if let Some(project) = issue_key_project(&input) {
+            let known = self.available_projects.is_empty() || self.available_projects.iter().any(|p| p.key == project);
+            Some(if known {
+                format!("Opens {}", input)
+            } else {
+                format!("Opens {} (no project {} is loaded)", input, project)
+            })
+        } else {
+            Some("Issue keys look like ABC-123".to_string())
+        };
+    }
+
+    async fn handle_go_to_issue_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => {
+                self.input_view.clear();
+                self.mode = self.return_mode.clone();
+            }
+            KeyCode::Enter => {
+                let issue_key = self.input_view.get_input().trim().to_uppercase();
+                if issue_key_project(&issue_key).is_none() {
+                    // Keep the prompt open so the key can be fixed
+                    self.toasts.error(format!("'{}' is not an issue key like ABC-123", issue_key));
+                    return Ok(());
+                }
+                let issue = self.jira_client.get_issue(&issue_key).await?;
+                self.input_view.clear();
+                self.detail_return = self.return_mode.clone();
+                self.open_issue(issue).await?;
+            }
+            KeyCode::Tab => {
+                self.input_view.complete();
+                self.update_go_to_issue_hint();
+            }
+            _ => {
+                self.input_view.handle_key(key, modifiers);
+                self.update_go_to_issue_hint();
+            }
+        }
+        Ok(())
+    }
+
+    /// Show or hide the status category counts under every list, and remember it
+    fn toggle_status_counts(&mut self) -> Result<()> {
+        self.config.ui.show_status_counts = !self.config.ui.show_status_counts;
+        self.apply_ui_config();
+        Config::save_ui(&self.config.ui)
+    }
+
+    /// List the board's issues carrying `label`, in the search results view
+    async fn search_label(&mut self, label: &str) -> Result<()> {
+        let clause = format!("labels = \"{}\"", label.replace('"', "\\\""));
+        let issues = match self.config.jira.default_board_id {
+            Some(board_id) => self.jira_client.get_board_issues(board_id, Some(&clause)).await?,
+            None => self.jira_client.search_issues(&clause).await?,
+        };
+        self.search_view.results.set_issues(issues, format!("Label: {}", label), None);
+        self.search_view.return_mode = AppMode::IssueDetail;
+        self.mode = AppMode::SearchResults;
+        Ok(())
+    }
+
+    async fn handle_search_results_input(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Esc => self.mode = self.search_view.return_mode.clone(),
+            KeyCode::Char('C') => self.toggle_status_counts()?,
+            KeyCode::Down | KeyCode::Char('j') => self.search_view.results.next(),
+            KeyCode::Up | KeyCode::Char('k') => self.search_view.results.previous(),
+            KeyCode::Char('/') => {
+                let return_mode = self.search_view.return_mode.clone();
+                self.begin_search(return_mode);
+            }
+            KeyCode::Char('G') => self.begin_go_to_issue(AppMode::SearchResults),
+            KeyCode::Char('X') => self.begin_export(AppMode::SearchResults),
+            KeyCode::Char('R') => self.refresh_selected_issue(AppMode::SearchResults).await?,
+            KeyCode::Enter => {
+                if let Some(issue) = self.search_view.results.selected_issue().cloned() {
+                    self.open_issue(issue).await?;
+                    self.detail_return = AppMode::SearchResults;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Show the blocked issues of the sprint, or of the board when coming from
+    /// the backlog, returning to `return_mode` on Esc
+    async fn open_blocked(&mut self, return_mode: AppMode) -> Result<()> {
+        self.blocked_board_wide = return_mode == AppMode::Backlog;
+        self.blocked_return = return_mode;
+        self.load_blocked().await?;
+        self.mode = AppMode::Blocked;
+        Ok(())
+    }
+
+    /// Scan the current sprint, or the open issues of the whole board, for
+    /// issues blocked by another open issue
+    async fn load_blocked(&mut self) -> Result<()> {
+        let Some(board_id) = self.config.jira.default_board_id else {
+            self.toasts.info("Select a board first");
+            return Ok(());
+        };
+        let sprint_id = self.current_sprint_id.filter(|_| !self.blocked_board_wide);
+        let (issues, scope) = match sprint_id {
+            Some(sprint_id) => {
+                let name = self
+                    .available_sprints
+                    .iter()
+                    .find(|s| s.id == sprint_id)
+                    .map_or_else(|| format!("Sprint {}", sprint_id), |s| s.name.clone());
+                (self.jira_client.get_sprint_issues(board_id, sprint_id, None).await?, name)
+            }
+            None => (
+                self.jira_client.get_board_issues(board_id, Some("statusCategory != Done")).await?,
+                "Board".to_string(),
+            ),
+        };
+        self.blocked_view.set_issues(&issues, scope);
+        Ok(())
+    }
+
+    async fn handle_blocked_input(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Esc => self.mode = self.blocked_return.clone(),
+            KeyCode::Down | KeyCode::Char('j') => self.blocked_view.next(),
+            KeyCode::Up | KeyCode::Char('k') => self.blocked_view.previous(),
+            KeyCode::Char('r') => self.load_blocked().await?,
+            KeyCode::Tab => {
+                self.blocked_board_wide = !self.blocked_board_wide;
+                self.load_blocked().await?;
+            }
+            KeyCode::Enter => {
+                if let Some(issue) = self.blocked_view.selected_issue().cloned() {
+                    self.open_issue(issue).await?;
+                    self.detail_return = AppMode::Blocked;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Show every issue assigned to me across boards, returning to `return_mode` on Esc
+    async fn open_my_issues(&mut self, return_mode: AppMode) -> Result<()> {
+        self.my_issues_return = return_mode;
+        self.load_my_issues().await?;
+        self.mode = AppMode::MyIssues;
+        Ok(())
+    }
+
+    async fn load_my_issues(&mut self) -> Result<()> {
+        let issues = self.jira_client.get_my_issues().await?;
+        // Resolved once, for the title
+        self.current_account_id().await?;
+        let name = self.current_user.as_ref().map_or("me", |user| user.display_name.as_str());
+        let title = format!("Assigned to {}", name);
+        self.my_issues_view.set_issues(issues, title, None);
+        Ok(())
+    }
+
+    async fn handle_my_issues_input(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Esc => self.mode = self.my_issues_return.clone(),
+            KeyCode::Char('C') => self.toggle_status_counts()?,
+            KeyCode::Down | KeyCode::Char('j') => self.my_issues_view.next(),
+            KeyCode::Up | KeyCode::Char('k') => self.my_issues_view.previous(),
+            KeyCode::Char('r') => self.load_my_issues().await?,
+            KeyCode::Char('G') => self.begin_go_to_issue(AppMode::MyIssues),
+            KeyCode::Char('X') => self.begin_export(AppMode::MyIssues),
+            KeyCode::Char('R') => self.refresh_selected_issue(AppMode::MyIssues).await?,
+            KeyCode::Enter => {
+                if let Some(issue) = self.my_issues_view.selected_issue().cloned() {
+                    self.open_issue(issue).await?;
+                    self.detail_return = AppMode::MyIssues;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Show the sprint's issues in the board's columns, or the whole board's on
+    /// kanban boards, returning to `return_mode` on Esc
+    async fn open_board(&mut self, return_mode: AppMode) -> Result<()> {
+        self.board_return = return_mode;
+        self.load_board().await?;
+        self.mode = AppMode::Board;
+        Ok(())
+    }
+
+    async fn load_board(&mut self) -> Result<()> {
+        let Some(board_id) = self.config.jira.default_board_id else {
+            self.toasts.info("Select a board first");
+            return Ok(());
+        };
+        if self.columns_board_id != Some(board_id) {
+            self.columns_board_id = Some(board_id);
+            self.sprint_view.columns = self.jira_client.get_board_columns(board_id).await.unwrap_or_default();
+        }
+        let jql = self.view_jql();
+        let sprint_id = self.current_sprint_id.filter(|_| !self.board_is_kanban());
+        let (issues, scope) = match sprint_id {
+            Some(sprint_id) => (
+                self.jira_client.get_sprint_issues(board_id, sprint_id, jql.as_deref()).await?,
+                self.sprint_view.sprint_name.clone(),
+            ),
+            None => (
+                self.jira_client.get_board_issues(board_id, jql.as_deref()).await?,
+                "Board".to_string(),
+            ),
+        };
+        self.board_view.set_issues(issues, &self.sprint_view.columns, scope);
+        self.mark_refreshed();
+        Ok(())
+    }
+
+    async fn handle_board_input(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            // `h` moves between columns here, so help is on `?`
+            KeyCode::Char('?') => self.show_help = !self.show_help,
+            KeyCode::Esc | KeyCode::Char('c') => self.mode = self.board_return.clone(),
+            KeyCode::Left | KeyCode::Char('h') => self.board_view.previous_column(),
+            KeyCode::Right | KeyCode::Char('l') => self.board_view.next_column(),
+            KeyCode::Down | KeyCode::Char('j') => self.board_view.next(),
+            KeyCode::Up | KeyCode::Char('k') => self.board_view.previous(),
+            KeyCode::Char('r') => self.load_board().await?,
+            KeyCode::Enter => {
+                if let Some(issue) = self.board_view.selected_issue().cloned() {
+                    self.open_issue(issue).await?;
+                    self.detail_return = AppMode::Board;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Prompt for where to write the issues listed in `return_mode` as CSV
+    fn begin_export(&mut self, return_mode: AppMode) {
+        let view = match return_mode {
+            AppMode::Backlog => "backlog".to_string(),
+            AppMode::SearchResults => "search".to_string(),
+            AppMode::MyIssues => "my-issues".to_string(),
+            _ => self.sprint_view.sprint_name.clone(),
+        };
+        self.input_view = InputView::new("Export CSV to".to_string());
+        self.input_view.input = csv_export::default_file_name(&view);
+        self.input_view.cursor_position = self.input_view.input.len();
+        self.return_mode = return_mode;
+        self.mode = AppMode::ExportCsv;
+    }
+
+    fn handle_export_input(&mut self, key: KeyCode, modifiers: KeyModifiers) {
+        match key {
+            KeyCode::Esc => {
+                self.input_view.clear();
+                self.mode = self.return_mode.clone();
+            }
+            KeyCode::Enter => {
+                let input = self.input_view.get_input().trim().to_string();
+                if input.is_empty() {
+                    return;
+                }
+                let path = csv_export::expand_home(&input);
+                let issues = match self.return_mode {
+                    AppMode::Backlog => self.backlog_view.visible_issues(),
+                    AppMode::SearchResults => self.search_view.results.visible_issues(),
+                    AppMode::MyIssues => self.my_issues_view.visible_issues(),
+                    _ => self.sprint_view.visible_issues(),
+                };
+                let count = issues.len();
+                match csv_export::write(&path, &issues, &self.config.jira.story_points_field) {
+                    Ok(()) => {
+                        self.toasts.success(format!("Exported {} issues to {}", count, path.display()));
+                        self.input_view.clear();
+                        self.mode = self.return_mode.clone();
+                    }
+                    // Keep the prompt open so the path can be fixed
+                    Err(e) => self.toasts.error(format!("Failed to write {}: {}", path.display(), e)),
+                }
+            }
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+    }
+
+    fn begin_estimate(&mut self, issue: crate::jira::Issue, return_mode: AppMode) {
+        self.input_view = InputView::new(format!("Story Points for {} (empty to clear)", issue.key));
+        if let Some(points) = issue.field_text(&self.config.jira.story_points_field) {
+            self.input_view.input = points;
+            self.input_view.cursor_position = self.input_view.input.len();
+        }
+        self.pending_estimate = Some(issue);
+        self.return_mode = return_mode;
+        self.mode = AppMode::EditEstimate;
+    }
+
+    async fn handle_estimate_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => {
+                self.pending_estimate = None;
+                self.input_view.clear();
+                self.mode = self.return_mode.clone();
+            }
+            KeyCode::Enter => {
+                let input = self.input_view.get_input().trim().to_string();
+                let points = match input.parse::<f64>() {
+                    _ if input.is_empty() => None,
+                    Ok(points) if points >= 0.0 && points.is_finite() => Some(points),
+                    _ => {
+                        // Keep the prompt open so the number can be fixed
+                        self.toasts.error(format!("'{}' is not a non-negative number", input));
+                        return Ok(());
+                    }
+                };
+                self.input_view.clear();
+                self.mode = self.return_mode.clone();
+                if let Some(mut issue) = self.pending_estimate.take() {
+                    let field = self.config.jira.story_points_field.clone();
+                    self.jira_client.set_story_points(&issue.key, &field, points).await?;
+                    self.toasts.success(match points {
+                        Some(_) => format!("Estimated {} at {} points", issue.key, input),
+                        None => format!("Cleared the estimate of {}", issue.key),
+                    });
+                    issue.fields.other.insert(field, serde_json::json!(points));
+                    self.apply_local_issue(issue);
+                }
+            }
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    fn begin_due_date(&mut self, issue: crate::jira::Issue, return_mode: AppMode) {
+        self.input_view = InputView::new(format!(
+            "Due Date for {} (YYYY-MM-DD, today, +3, +2w; empty to clear)",
+            issue.key
+        ));
+        if let Some(due) = issue.fields.duedate {
+            self.input_view.input = due.format("%Y-%m-%d").to_string();
+            self.input_view.cursor_position = self.input_view.input.len();
+        }
+        self.pending_due_date = Some(issue);
+        self.return_mode = return_mode;
+        self.mode = AppMode::EditDueDate;
+    }
+
+    async fn handle_due_date_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => {
+                self.pending_due_date = None;
+                self.input_view.clear();
+                self.mode = self.return_mode.clone();
+            }
+            KeyCode::Enter => {
+                let due = match due_date::parse(self.input_view.get_input(), due_date::today()) {
+                    Ok(due) => due,
+                    Err(e) => {
+                        // Keep the prompt open so the date can be fixed
+                        self.toasts.error(e);
+                        return Ok(());
+                    }
+                };
+                self.input_view.clear();
+                self.mode = self.return_mode.clone();
+                if let Some(mut issue) = self.pending_due_date.take() {
+                    self.jira_client.set_due_date(&issue.key, due).await?;
+                    self.toasts.success(match due {
+                        Some(due) => format!("{} is due {}", issue.key, due.format("%a %Y-%m-%d")),
+                        None => format!("Cleared the due date of {}", issue.key),
+                    });
+                    issue.fields.duedate = due;
+                    self.apply_local_issue(issue);
+                }
+            }
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    async fn handle_worklog_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => {
+                self.input_view.clear();
+                self.mode = AppMode::IssueDetail;
+            }
+            KeyCode::Enter => {
+                let Some(issue_key) = self.issue_detail_view.issue.as_ref().map(|issue| issue.key.clone()) else {
+                    return Ok(());
+                };
+                let input = self.input_view.get_input().to_string();
+                let (duration, comment) = match input.split_once(';') {
+                    Some((duration, comment)) => (duration, Some(comment.trim()).filter(|c| !c.is_empty())),
+                    None => (input.as_str(), None),
+                };
+                let time_spent = match time_spent::parse(duration) {
+                    Ok(time_spent) => time_spent,
+                    Err(e) => {
+                        // Keep the prompt open so the duration can be fixed
+                        self.toasts.error(e);
+                        return Ok(());
+                    }
+                };
+                if let Err(e) = self.jira_client.add_worklog(&issue_key, &time_spent, comment).await {
+                    self.toasts.error(format!("Could not log work on {}: {}", issue_key, e));
+                    return Ok(());
+                }
+                self.input_view.clear();
+                self.mode = AppMode::IssueDetail;
+                self.toasts.success(format!("Logged {} on {}", time_spent, issue_key));
+                self.load_worklogs(&issue_key).await;
+            }
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    async fn begin_delete_issue(&mut self, issue: crate::jira::Issue, return_mode: AppMode) -> Result<()> {
+        let has_subtasks = issue.fields.subtasks.as_ref().is_some_and(|s| !s.is_empty());
+        self.input_view = InputView::new(format!("Delete {}? Type the issue key to confirm", issue.key));
+        self.pending_delete = Some(issue);
+        self.return_mode = return_mode;
+        if self.config.ui.confirm.delete {
+            self.mode = AppMode::DeleteIssue;
+        } else if has_subtasks {
+            // Not a confirmation but a choice, so it is still asked
+            self.mode = AppMode::ConfirmDeleteSubtasks;
+        } else {
+            self.delete_pending_issue(false).await?;
+        }
+        Ok(())
+    }
+
+    fn cancel_delete_issue(&mut self) {
+        self.pending_delete = None;
+        self.input_view.clear();
+        self.mode = self.return_mode.clone();
+    }
+
+    async fn handle_delete_issue_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => self.cancel_delete_issue(),
+            KeyCode::Enter => {
+                let Some(issue) = &self.pending_delete else {
+                    self.cancel_delete_issue();
+                    return Ok(());
+                };
+
+                if self.input_view.get_input() != issue.key {
+                    // Require an exact match before anything irreversible happens
+                    self.input_view = InputView::new(format!("Key mismatch - type {} to confirm deletion", issue.key));
+                } else if issue.fields.subtasks.as_ref().is_some_and(|s| !s.is_empty()) {
+                    self.mode = AppMode::ConfirmDeleteSubtasks;
+                } else {
+                    self.delete_pending_issue(false).await?;
+                }
+            }
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    async fn handle_delete_subtasks_input(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Char('y') => self.delete_pending_issue(true).await?,
+            KeyCode::Char('n') | KeyCode::Esc => self.cancel_delete_issue(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn delete_pending_issue(&mut self, delete_subtasks: bool) -> Result<()> {
+        let deleted_key = self.pending_delete.as_ref().map(|issue| issue.key.clone());
+        if let Some(issue) = self.pending_delete.take() {
+            self.jira_client.delete_issue(&issue.key, delete_subtasks).await?;
+            if self.issue_detail_view.issue.as_ref().is_some_and(|i| i.key == issue.key) {
+                self.issue_detail_view.issue = None;
+            }
+        }
+        self.input_view.clear();
+
+        // The deleted issue can't be shown anymore, so return to the list it
+        // came from, or the one the issue details were opened from
+        let origin = match &self.return_mode {
+            AppMode::IssueDetail => self.detail_return.clone(),
+            mode => mode.clone(),
+        };
+        self.mode = match origin {
+            AppMode::Backlog | AppMode::SearchResults | AppMode::MyIssues | AppMode::Blocked | AppMode::Board => origin,
+            _ => AppMode::Sprint,
+        };
+        if self.jira_client.is_dry_run() {
+            // The issue still exists server-side, so drop it from the list
+            // locally rather than re-fetching it
+            if let Some(key) = &deleted_key {
+                match self.mode {
+                    AppMode::Backlog => self.backlog_view.remove_issue(key),
+                    AppMode::SearchResults => self.search_view.remove_issue(key),
+                    AppMode::MyIssues => self.my_issues_view.remove_issue(key),
+                    AppMode::Blocked => self.blocked_view.remove_issue(key),
+                    AppMode::Board => self.board_view.remove_issue(key),
+                    _ => self.sprint_view.remove_issue(key),
+                }
+            }
+            return Ok(());
+        }
+        match self.mode {
+            AppMode::Backlog => self.load_backlog(),
+            AppMode::SearchResults => {
+                if let Some(key) = &deleted_key {
+                    self.search_view.remove_issue(key);
+                }
+            }
+            AppMode::MyIssues => self.load_my_issues().await?,
+            AppMode::Blocked => self.load_blocked().await?,
+            AppMode::Board => self.load_board().await?,
+            _ => self.refresh_sprint().await?,
+        }
+        Ok(())
+    }
+
+    async fn handle_sprint_selector_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('h') => self.show_help = !self.show_help,
+            KeyCode::Esc => {
+                self.sprint_selector.deactivate();
+                self.mode = AppMode::Sprint;
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.sprint_selector.next(),
+            KeyCode::Up | KeyCode::Char('k') => self.sprint_selector.previous(),
+            KeyCode::Enter => {
+                if let Some(sprint_id) = self.sprint_selector.selected_sprint_id() {
+                    self.current_sprint_id = Some(sprint_id);
+                    self.load_sprint_issues(sprint_id);
+                    self.sprint_selector.deactivate();
+                    self.mode = AppMode::Sprint;
+                }
+            }
+            KeyCode::Char('e') => {
+                if let Some(sprint) = self.sprint_selector.selected_sprint() {
+                    self.input_view = InputView::new(format!("Edit Sprint Name: {}", sprint.name));
+                    self.input_view.input = sprint.name.clone();
+                    self.input_view.cursor_position = self.input_view.input.len();
+                    self.mode = AppMode::EditSprintName;
+                }
+            }
+            KeyCode::Char('s') => self.begin_start_sprint(),
+            KeyCode::Char('C') => self.open_close_sprint().await?,
+            KeyCode::Char('o') => self.load_closed_sprints().await?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Add the closed sprints left out of the selector, keeping the highlighted sprint
+    async fn load_closed_sprints(&mut self) -> Result<()> {
+        let Some(board_id) = self.config.jira.default_board_id else {
+            return Ok(());
+        };
+        if self.closed_sprints_loaded {
+            self.toasts.info("Closed sprints are already listed");
+            return Ok(());
+        }
+        let open = self.available_sprints.len();
+        self.available_sprints = self.jira_client.get_board_sprints(board_id).await?;
+        self.closed_sprints_loaded = true;
+        let selected = self.sprint_selector.selected_sprint_id();
+        self.sprint_selector.set_sprints(self.available_sprints.clone());
+        self.sprint_selector.closed_hidden = false;
+        if let Some(id) = selected {
+            self.sprint_selector.select_sprint(id);
+        }
+        self.toasts.info(format!("Loaded {} closed sprint(s)", self.available_sprints.len().saturating_sub(open)));
+        Ok(())
+    }
+
+    async fn handle_edit_sprint_name_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => {
+                self.input_view.clear();
+                self.mode = AppMode::SprintSelector;
+            }
+            KeyCode::Enter => {
+                if let Some(sprint) = self.sprint_selector.selected_sprint() {
+                    let new_name = self.input_view.get_input();
+                    if !new_name.is_empty() {
+                        let update = crate::jira::SprintUpdate {
+                            name: Some(new_name.to_string()),
+                            ..Default::default()
+                        };
+                        self.jira_client.update_sprint(sprint.id, &update).await?;
+                        if self.jira_client.is_dry_run() {
+                            let sprint_id = sprint.id;
+                            if let Some(s) = self.available_sprints.iter_mut().find(|s| s.id == sprint_id) {
+                                s.name = new_name.to_string();
+                            }
+                            self.sprint_selector.set_sprints(self.available_sprints.clone());
+                        } else {
+                            self.refresh_sprints().await?;
+                        }
+                    }
+                }
+                self.input_view.clear();
+                self.mode = AppMode::SprintSelector;
+            }
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    async fn handle_board_selector_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('h') => self.show_help = !self.show_help,
+            KeyCode::Esc => {
+                // Nothing was switched, so the current board is still loaded
+                self.board_preview = None;
+                self.board_selector.deactivate();
+                self.mode = AppMode::Sprint;
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.board_selector.next(),
+            KeyCode::Up | KeyCode::Char('k') => self.board_selector.previous(),
+            KeyCode::Char(' ') => self.preview_board().await?,
+            KeyCode::Char('/') => {
+                self.input_view = InputView::new("Find Board".to_string());
+                self.mode = AppMode::FindBoard;
+            }
+            KeyCode::Enter => {
+                if let Some(board_id) = self.board_selector.selected_board_id() {
+                    self.board_preview = None;
+                    self.board_selector.deactivate();
+                    // Set first: kanban boards switch on to the backlog view
+                    self.mode = AppMode::Sprint;
+                    self.switch_board(board_id).await?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Select the best matching board as the name is typed; closing the prompt keeps it selected
+    fn handle_find_board_input(&mut self, key: KeyCode, modifiers: KeyModifiers) {
+        match key {
+            KeyCode::Esc | KeyCode::Enter => {
+                self.board_selector.found.clear();
+                self.input_view.clear();
+                self.mode = AppMode::BoardSelector;
+            }
+            _ => {
+                self.input_view.handle_key(key, modifiers);
+                let found = self.board_selector.find(self.input_view.get_input(), self.matcher());
+                self.input_view.hint = Some(format!("{} of {} boards match", found, self.board_selector.boards.len()));
+            }
+        }
+    }
+
+    /// Load the highlighted board's current sprint beside the selector, leaving
+    /// the default board and its loaded sprint untouched
+    async fn preview_board(&mut self) -> Result<()> {
+        let Some(board) = self.board_selector.selected_board().cloned() else {
+            return Ok(());
+        };
+        let mut preview = SprintView::new();
+        preview.flag_field = self.config.jira.flag_field.clone();
+        preview.story_points_field = self.config.jira.story_points_field.clone();
+        preview.type_icons = self.sprint_view.type_icons.clone();
+        preview.glyphs = self.sprint_view.glyphs;
+
+        // Kanban boards have no sprints and reject the request
+        let sprints = fetch_board_sprints(&self.jira_client, board.id, self.config.ui.load_closed_sprints)
+            .await
+            .map(|(sprints, _)| sprints)
+            .unwrap_or_default();
+        if let Some(sprint) = default_sprint(&sprints, &self.config.ui.sprint_sort, self.config.ui.prefer_active_sprint) {
+            let issues = self.jira_client.get_sprint_issues(board.id, sprint.id, None).await?;
+            let title = format!("{} (preview: {})", sprint.name, board.name);
+            preview.set_issues(issues, title, sprint.goal.clone());
+            preview.set_sprint_end(sprint.end_date, &sprint.state);
+        } else {
+            preview.set_issues(Vec::new(), format!("No Sprints Available (preview: {})", board.name), None);
+        }
+        self.board_preview = Some(preview);
+        Ok(())
+    }
+
+    async fn open_board_sprint_selector(&mut self) -> Result<()> {
+        // Sprints are only cached while browsing, so reopening picks up new ones
+        self.board_sprints.clear();
+        self.board_selector.set_boards(self.available_boards.clone());
+        // Start on the board that is currently shown
+        if let Some(index) = self
+            .board_selector
+            .boards
+            .iter()
+            .position(|b| Some(b.id) == self.config.jira.default_board_id)
+        {
+            self.board_selector.state.select(Some(index));
+        }
+        self.board_selector.activate();
+        self.sprint_selector.deactivate();
+        self.mode = AppMode::BoardSprintSelector;
+        self.load_selector_sprints().await
+    }
+
+    /// Show the sprints of the highlighted board in the right-hand column
+    async fn load_selector_sprints(&mut self) -> Result<()> {
+        let Some(board_id) = self.board_selector.selected_board_id() else {
+            self.sprint_selector.set_sprints(Vec::new());
+            return Ok(());
+        };
+        if !self.board_sprints.contains_key(&board_id) {
+            // Kanban boards have no sprints and reject the request
+            let sprints = fetch_board_sprints(&self.jira_client, board_id, self.config.ui.load_closed_sprints)
+                .await
+                .unwrap_or_default();
+            self.board_sprints.insert(board_id, sprints);
+        }
+        let (sprints, closed) = self.board_sprints.get(&board_id).cloned().unwrap_or_default();
+        self.sprint_selector.set_sprints(sprints);
+        self.sprint_selector.closed_hidden = !closed;
+        Ok(())
+    }
+
+    async fn handle_board_sprint_selector_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
+        let boards_focused = self.board_selector.is_active;
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('h') => self.show_help = !self.show_help,
+            KeyCode::Esc => {
+                self.board_selector.deactivate();
+                self.sprint_selector.deactivate();
+                self.mode = AppMode::Sprint;
+            }
+            KeyCode::Down | KeyCode::Char('j') if boards_focused => {
+                self.board_selector.next();
+                self.load_selector_sprints().await?;
+            }
+            KeyCode::Up | KeyCode::Char('k') if boards_focused => {
+                self.board_selector.previous();
+                self.load_selector_sprints().await?;
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.sprint_selector.next(),
+            KeyCode::Up | KeyCode::Char('k') => self.sprint_selector.previous(),
+            KeyCode::Char('o') => {
+                if let Some(board_id) = self.board_selector.selected_board_id()
+                    && self.sprint_selector.closed_hidden
+                {
+                    let sprints = self.jira_client.get_board_sprints(board_id).await?;
+                    self.board_sprints.insert(board_id, (sprints, true));
+                    self.load_selector_sprints().await?;
+                }
+            }
+            KeyCode::Enter | KeyCode::Right | KeyCode::Tab
+                if boards_focused && !self.sprint_selector.sprints.is_empty() =>
+            {
+                self.board_selector.deactivate();
+                self.sprint_selector.activate();
+            }
+            KeyCode::Left | KeyCode::Tab => {
+                self.sprint_selector.deactivate();
+                self.board_selector.activate();
+            }
+            KeyCode::Enter => {
+                if let Some(board_id) = self.board_selector.selected_board_id()
+                    && let Some(sprint_id) = self.sprint_selector.selected_sprint_id()
+                {
+                    self.set_board(board_id);
+                    (self.available_sprints, self.closed_sprints_loaded) =
+                        self.board_sprints.get(&board_id).cloned().unwrap_or_default();
+                    self.current_sprint_id = Some(sprint_id);
+                    self.refresh_sprint().await?;
+                    self.sprint_selector.deactivate();
+                    self.mode = AppMode::Sprint;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Make `board_id` current, remembering the board it replaces for `Ctrl+^`
+    fn set_board(&mut self, board_id: u32) {
+        if self.config.jira.default_board_id != Some(board_id) {
+            self.previous_board_id = self.config.jira.default_board_id;
+            // Quick filters belong to a board
+            self.quick_filter = None;
+        }
+        self.config.jira.default_board_id = Some(board_id);
+    }
+
+    /// Swap back to the previously shown board, like `Ctrl+^` in editors
+    async fn toggle_previous_board(&mut self) -> Result<()> {
+        let Some(board_id) = self.previous_board_id else {
+            self.toasts.info("No previous board yet");
+            return Ok(());
+        };
+        self.switch_board(board_id).await?;
+        if self.mode == AppMode::Backlog {
+            self.load_backlog();
+        }
+        Ok(())
+    }
+
+    async fn switch_board(&mut self, board_id: u32) -> Result<()> {
+        self.set_board(board_id);
+        // Clear sprint data to force reload for new board
+        self.available_sprints.clear();
+        self.current_sprint_id = None;
+        // Load new board's sprint data
+        self.refresh_sprint().await
+    }
+
+    async fn handle_project_selector_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('h') => self.show_help = !self.show_help,
+            KeyCode::Esc => {
+                self.project_selector.deactivate();
+                self.mode = AppMode::Sprint;
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.project_selector.next(),
+            KeyCode::Up | KeyCode::Char('k') => self.project_selector.previous(),
+            KeyCode::Enter => {
+            if let Some(project) = self.project_selector.selected_project() {
+                let project_key = &project.key;
+                    // Load boards for the selected project
+                    let project_boards = self.jira_client.get_boards().await?
+                        .into_iter()
+                        .filter(|board| {
+                            // Filter boards that belong to this project
+                            // This is a simplified check - in reality you might need to check board location or other attributes
+                            board.name.contains(&*project_key) || 
+                            board.location.as_ref().is_some_and(|loc| loc.project_key.as_deref() == Some(&*project_key))
+                        })
+                        .collect::<Vec<_>>();
+                    
+                    self.project_selector.deactivate();
+                    self.mode = AppMode::Sprint;
+
+                    if !project_boards.is_empty() {
+                        // Update available boards and set the first one as default
+                        self.available_boards = project_boards;
+                        self.set_board(self.available_boards[0].id);
+                        
+                        // Clear sprint data to force reload for new board
+                        self.available_sprints.clear();
+                        self.current_sprint_id = None;
+                        
+                        // Load new board's sprint data
+                        self.refresh_sprint().await?;
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn handle_settings_input(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Esc => self.mode = AppMode::Sprint,
+            KeyCode::Down | KeyCode::Char('j') => self.settings_view.next(),
+            KeyCode::Up | KeyCode::Char('k') => self.settings_view.previous(),
+            KeyCode::Left | KeyCode::Right if self.settings_view.selected_field().is_cycled() => {
+                let field = self.settings_view.selected_field();
+                self.settings_view.cycle(field, key == KeyCode::Right);
+            }
+            KeyCode::Enter => {
+                let field = self.settings_view.selected_field();
+                if field.is_cycled() {
+                    self.settings_view.cycle(field, true);
+                } else {
+                    self.input_view = InputView::new(format!("Edit {}", field.label()));
+                    self.input_view.input = self.settings_view.value(field);
+                    self.input_view.cursor_position = self.input_view.input.len();
+                    self.mode = AppMode::EditSetting;
+                }
+            }
+            KeyCode::Char('s') => {
+                self.config.ui = self.settings_view.draft.clone();
+                self.apply_ui_config();
+                Config::save_ui(&self.config.ui)?;
+                self.mode = AppMode::Sprint;
+            }
+            KeyCode::Char('e') => {
+                // The main loop hands the terminal to the editor, then calls `finish_external_edit`
+                self.external_edit = Some(ExternalEdit::Config);
+                self.mode = AppMode::Sprint;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn handle_edit_setting_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => {
+                self.input_view.clear();
+                self.mode = AppMode::Settings;
+            }
+            KeyCode::Enter => {
+                let field = self.settings_view.selected_field();
+                match self.settings_view.set_value(field, self.input_view.get_input()) {
+                    Ok(()) => {
+                        self.input_view.clear();
+                        self.mode = AppMode::Settings;
+                    }
+                    Err(message) => {
+                        let input = self.input_view.get_input().to_string();
+                        self.input_view = InputView::new(message);
+                        self.input_view.input = input;
+                        self.input_view.cursor_position = self.input_view.input.len();
+                    }
+                }
+            }
+            _ => self.input_view.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    async fn handle_help_input(&mut self, key: KeyCode) -> Result<bool> {
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('h') | KeyCode::Char('?') | KeyCode::Esc => self.show_help = false,
+            _ => {}
+        }
+        Ok(self.should_quit)
+    }
+
+    /// Account id of the authenticated user, fetched from `/myself` on first use
+    async fn current_account_id(&mut self) -> Result<String> {
+        if self.current_user.is_none() {
+            self.current_user = Some(self.jira_client.get_myself().await?);
+        }
+        Ok(self.current_user.as_ref().map(|u| u.account_id.clone()).unwrap_or_default())
+    }
+
+    /// Copy my in-progress and done-today sprint issues as a standup update
+    async fn copy_standup(&mut self) -> Result<()> {
+        let account_id = self.current_account_id().await?;
+        let text = standup::summary(
+            &self.sprint_view.issues,
+            &account_id,
+            &self.config.ui.standup_group,
+            &self.config.ui.standup_item,
+            chrono::Local::now(),
+        );
+        if text.is_empty() {
+            self.toasts.info("Nothing in progress or done today");
+            return Ok(());
+        }
+        clipboard::copy(&text)?;
+        self.toasts.success("Copied standup update to the clipboard");
+        Ok(())
+    }
+
+    fn copy_markdown(&mut self, issue: &crate::jira::Issue) -> Result<()> {
+        let url = self.jira_client.browse_url(&issue.key);
+        let text = markdown::issue(issue, &self.config.ui.markdown_sections, &url);
+        clipboard::copy(&text)?;
+        self.toasts.success(format!("Copied {} as Markdown", issue.key));
+        Ok(())
+    }
+
+    /// Rank the selected backlog issue above the first (or below the last) issue,
+    /// show the move right away, then reload to pick up the server's order
+    async fn move_backlog_issue(&mut self, to_top: bool) -> Result<()> {
+        let Some(key) = self.backlog_view.selected_issue().map(|i| i.key.clone()) else {
+            return Ok(());
+        };
+        let anchor = if to_top {
+            self.backlog_view.issues.first()
+        } else {
+            self.backlog_view.issues.last()
+        };
+        let Some(anchor) = anchor.map(|i| i.key.clone()).filter(|anchor| *anchor != key) else {
+            return Ok(());
+        };
+
+        self.jira_client.rank_issues(&[&key], &anchor, to_top).await?;
+        self.backlog_view.move_issue(&key, to_top);
+        if !self.jira_client.is_dry_run() {
+            self.fetch_backlog(Some(key));
+        }
+        Ok(())
+    }
+
+    /// Pull the marked backlog issues, or else the selected one, into the sprint
+    /// shown in the sprint view, or the active sprint when that one is closed
+    async fn move_to_sprint(&mut self) -> Result<()> {
+        let keys: Vec<String> = if self.backlog_view.marked.is_empty() {
+            self.backlog_view.selected_issue().map(|i| i.key.clone()).into_iter().collect()
+        } else {
+            self.backlog_view.marked_issues().into_iter().map(|i| i.key).collect()
+        };
+        let Some(board_id) = self.config.jira.default_board_id.filter(|_| !keys.is_empty()) else {
+            return Ok(());
+        };
+        if self.board_is_kanban() {
+            self.toasts.error("Kanban boards have no sprints to move issues into");
+            return Ok(());
+        }
+        if self.available_sprints.is_empty() {
+            (self.available_sprints, self.closed_sprints_loaded) =
+                fetch_board_sprints(&self.jira_client, board_id, self.config.ui.load_closed_sprints).await?;
+        }
+        let target = self
+            .available_sprints
+            .iter()
+            .find(|s| Some(s.id) == self.current_sprint_id && s.state != "closed")
+            .or_else(|| self.available_sprints.iter().find(|s| s.state == "active"))
+            .map(|s| (s.id, s.name.clone()));
+        let Some((sprint_id, sprint_name)) = target else {
+            self.toasts.error("No active sprint to move issues into; start one from the sprint selector");
+            return Ok(());
+        };
+
+        let key_refs: Vec<&str> = keys.iter().map(String::as_str).collect();
+        if let Err(e) = self.jira_client.move_issues_to_sprint(sprint_id, &key_refs).await {
+            self.toasts.error(format!("Could not move {} to {}: {}", keys.join(", "), sprint_name, e));
+            return Ok(());
+        }
+        self.toasts.success(match keys.as_slice() {
+            [key] => format!("Moved {} to {}", key, sprint_name),
+            _ => format!("Moved {} issues to {}", keys.len(), sprint_name),
+        });
+        self.backlog_view.marked.clear();
+        if self.jira_client.is_dry_run() {
+            // Nothing moved server-side, so a reload would bring the issues back
+            for key in &keys {
+                self.backlog_view.remove_issue(key);
+            }
+            return Ok(());
+        }
+        self.load_backlog();
+        if self.current_sprint_id == Some(sprint_id) {
+            self.load_sprint_issues(sprint_id);
+        }
+        Ok(())
+    }
+
+    /// Flag or unflag an issue. The update returns no body, so the new value is
+    /// applied locally and reconciled by the next refresh.
+    async fn toggle_flag(&mut self, issue: crate::jira::Issue) -> Result<()> {
+        let flagged = !issue.is_flagged(&self.config.jira.flag_field);
+        let key = issue.key.clone();
+        self.set_flag(issue, flagged).await?;
+        self.toasts.success(format!("{} {}", if flagged { "Flagged" } else { "Unflagged" }, key));
+        Ok(())
+    }
+
+    async fn set_flag(&mut self, mut issue: crate::jira::Issue, flagged: bool) -> Result<()> {
+        let field = self.config.jira.flag_field.clone();
+        self.jira_client.set_flagged(&issue.key, &field, flagged).await?;
+        let value = if flagged {
+            serde_json::json!([{ "value": "Impediment" }])
+        } else {
+            serde_json::Value::Null
+        };
+        issue.fields.other.insert(field, value);
+        self.apply_local_issue(issue);
+        Ok(())
+    }
+
+    /// Pick up an issue: assign it to me, then apply the first transition into an
+    /// "In Progress" category status if the workflow has one
+    async fn start_work(&mut self, mut issue: crate::jira::Issue) -> Result<()> {
+        let me = match &self.current_user {
+            Some(user) => user.clone(),
+            None => {
+                let user = self.jira_client.get_myself().await?;
+                self.current_user = Some(user.clone());
+                user
+            }
+        };
+        self.jira_client.assign_issue(&issue.key, Some(&me.account_id)).await?;
+        issue.fields.assignee = Some(me);
+
+        let transitions = self.jira_client.get_transitions(&issue.key).await?;
+        let start = transitions
+            .into_iter()
+            .find(|t| t.to.status_category.key == "indeterminate");
+        match start {
+            Some(transition) if issue.fields.status.status_category.key != "indeterminate" => {
+                self.jira_client.transition_issue(&issue.key, &transition.id, None, None).await?;
+                self.toasts.success(format!("{} assigned to you and moved to {}", issue.key, transition.to.name));
+                issue.fields.status = transition.to;
+            }
+            Some(_) => self.toasts.success(format!("{} assigned to you (already in progress)", issue.key)),
+            None => self.toasts.info(format!("{} assigned to you; no In Progress transition available", issue.key)),
+        }
+        if self.issue_detail_view.issue.as_ref().is_some_and(|i| i.key == issue.key) {
+            self.load_transitions(&issue.key).await?;
+        }
+        self.apply_local_issue(issue);
+        Ok(())
+    }
+
+    /// Flag all marked issues, or unflag them if every one is already flagged
+    async fn begin_flag_batch(&mut self, issues: Vec<crate::jira::Issue>, return_mode: AppMode) -> Result<()> {
+        let flag = !issues.iter().all(|i| i.is_flagged(&self.config.jira.flag_field));
+        self.begin_batch(BatchAction::SetFlag(flag), issues, return_mode).await
+    }
+
+    /// Run a batch action, asking first when `ui.confirm` says so for it
+    async fn begin_batch(
+        &mut self,
+        action: BatchAction,
+        issues: Vec<crate::jira::Issue>,
+        return_mode: AppMode,
+    ) -> Result<()> {
+        let confirm = match action {
+            BatchAction::SetFlag(_) => self.config.ui.confirm.bulk,
+            BatchAction::CloseSprint { .. } => self.config.ui.confirm.close_sprint,
+        };
+        self.return_mode = return_mode;
+        if !confirm {
+            return self.run_batch(action, issues).await;
+        }
+        self.pending_batch = Some((action, issues));
+        self.mode = AppMode::ConfirmBatch;
+        Ok(())
+    }
+
+    async fn handle_confirm_batch_input(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Char('y') | KeyCode::Enter => {
+                if let Some((action, issues)) = self.pending_batch.take() {
+                    self.run_batch(action, issues).await?;
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Esc => {
+                self.pending_batch = None;
+                self.mode = self.return_mode.clone();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn handle_batch_summary_input(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Char('r') => {
+                if let Some((action, issues)) = self.pending_batch.take() {
+                    self.run_batch(action, issues).await?;
+                }
+            }
+            KeyCode::Enter | KeyCode::Esc => {
+                self.pending_batch = None;
+                self.mode = self.return_mode.clone();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Apply `action` to every issue without stopping at the first failure, then
+    /// show which ones succeeded. Failed issues stay marked and can be retried.
+    async fn run_batch(&mut self, action: BatchAction, issues: Vec<crate::jira::Issue>) -> Result<()> {
+        let mut summary = BatchSummary::new(action.describe(issues.len()));
+        let mut failed_issues = Vec::new();
+        let mut close_failed = false;
+        match &action {
+            BatchAction::SetFlag(flagged) => {
+                for issue in issues {
+                    let key = issue.key.clone();
+                    match self.set_flag(issue.clone(), *flagged).await {
+                        Ok(()) => {
+                            self.sprint_view.marked.remove(&key);
+                            self.backlog_view.marked.remove(&key);
+                            summary.succeeded.push(key);
+                        }
+                        Err(e) => {
+                            summary.failed.push((key, e.to_string()));
+                            failed_issues.push(issue);
+                        }
+                    }
+                }
+            }
+            BatchAction::CloseSprint { sprint_id, sprint_name, target } => {
+                let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+                let moved = match target {
+                    _ if keys.is_empty() => Ok(()),
+                    MoveTarget::Sprint { id, .. } => self.jira_client.move_issues_to_sprint(*id, &keys).await,
+                    MoveTarget::Backlog => self.jira_client.move_issues_to_backlog(&keys).await,
+                };
+                match moved {
+                    Ok(()) => summary.succeeded.extend(keys.iter().map(|k| k.to_string())),
+                    Err(e) => {
+                        summary.failed.extend(keys.iter().map(|k| (k.to_string(), e.to_string())));
+                        failed_issues = issues.clone();
+                    }
+                }
+
+                // Only close once nothing would be left behind in the sprint
+                if failed_issues.is_empty() {
+                    let update = crate::jira::SprintUpdate {
+                        state: Some("closed".to_string()),
+                        ..Default::default()
+                    };
+                    match self.jira_client.update_sprint(*sprint_id, &update).await {
+                        Ok(()) => self.after_sprint_closed(*sprint_id).await?,
+                        Err(e) => {
+                            summary.failed.push((sprint_name.clone(), e.to_string()));
+                            close_failed = true;
+                        }
+                    }
+                }
+            }
+        }
+        self.pending_batch = (!failed_issues.is_empty() || close_failed).then_some((action, failed_issues));
+        self.batch_summary = summary;
+        self.mode = AppMode::BatchSummary;
+        Ok(())
+    }
+
+    /// Ask before starting the future sprint highlighted in the selector. It
+    /// starts now and keeps its planned end date, or runs for two weeks when
+    /// it has none (Jira requires both dates).
+    fn begin_start_sprint(&mut self) {
+        let Some(sprint) = self.sprint_selector.selected_sprint().cloned() else {
+            return;
+        };
+        if sprint.state != "future" {
+            self.toasts.info("Only future sprints can be started");
+            return;
+        }
+        // Whole seconds, which every Jira version parses
+        let now = chrono::SubsecRound::trunc_subsecs(chrono::Utc::now(), 0);
+        let end_date = sprint
+            .end_date
+            .filter(|end| *end > now)
+            .unwrap_or(now + chrono::Duration::days(DEFAULT_SPRINT_DAYS));
+        let ends = end_date.with_timezone(&chrono::Local).format("%a %Y-%m-%d %H:%M");
+        self.confirm_dialog.open("Start Sprint", format!("Start {} now, ending {}?", sprint.name, ends), false);
+        self.ask_confirmation(ConfirmAction::StartSprint {
+            sprint_id: sprint.id,
+            sprint_name: sprint.name,
+            end_date,
+        });
+    }
+
+    async fn start_sprint(&mut self, sprint_id: u32, sprint_name: &str, end_date: chrono::DateTime<chrono::Utc>) -> Result<()> {
+        let start_date = chrono::SubsecRound::trunc_subsecs(chrono::Utc::now(), 0);
+        let update = crate::jira::SprintUpdate {
+            state: Some("active".to_string()),
+            start_date: Some(start_date),
+            end_date: Some(end_date),
+            ..Default::default()
+        };
+        // Jira refuses e.g. a second active sprint on boards without parallel sprints
+        if let Err(e) = self.jira_client.update_sprint(sprint_id, &update).await {
+            anyhow::bail!("Couldn't start {}: {}", sprint_name, e);
+        }
+        if self.jira_client.is_dry_run() {
+            if let Some(s) = self.available_sprints.iter_mut().find(|s| s.id == sprint_id) {
+                s.state = "active".to_string();
+                s.start_date = Some(start_date);
+                s.end_date = Some(end_date);
+            }
+            self.sprint_selector.set_sprints(self.available_sprints.clone());
+        } else {
+            self.refresh_sprints().await?;
+        }
+        self.sprint_selector.select_sprint(sprint_id);
+        self.toasts.success(format!("Started {}", sprint_name));
+        Ok(())
+    }
+
+    /// Open the close-sprint dialog for the sprint highlighted in the selector
+    async fn open_close_sprint(&mut self) -> Result<()> {
+        let (Some(board_id), Some(sprint)) = (
+            self.config.jira.default_board_id,
+            self.sprint_selector.selected_sprint().cloned(),
+        ) else {
+            return Ok(());
+        };
+        if sprint.state != "active" {
+            return Ok(());
+        }
+        // Every incomplete issue has to move, whatever quick filter is applied
+        let issues = self.jira_client.get_sprint_issues(board_id, sprint.id, None).await?;
+        self.close_sprint_view.open(sprint, issues, &self.available_sprints);
+        self.mode = AppMode::CloseSprint;
+        Ok(())
+    }
+
+    async fn handle_close_sprint_input(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Esc => self.mode = AppMode::SprintSelector,
+            KeyCode::Right | KeyCode::Tab | KeyCode::Char('l') => self.close_sprint_view.next_target(),
+            KeyCode::Left | KeyCode::BackTab | KeyCode::Char('h') => self.close_sprint_view.previous_target(),
+            KeyCode::Enter => {
+                if let Some(sprint) = self.close_sprint_view.sprint.clone() {
+                    let action = BatchAction::CloseSprint {
+                        sprint_id: sprint.id,
+                        sprint_name: sprint.name,
+                        target: self.close_sprint_view.target(),
+                    };
+                    let issues = std::mem::take(&mut self.close_sprint_view.incomplete);
+                    self.begin_batch(action, issues, AppMode::SprintSelector).await?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn after_sprint_closed(&mut self, sprint_id: u32) -> Result<()> {
+        if self.jira_client.is_dry_run() {
+            if let Some(s) = self.available_sprints.iter_mut().find(|s| s.id == sprint_id) {
+                s.state = "closed".to_string();
+            }
+            self.sprint_selector.set_sprints(self.available_sprints.clone());
+            return Ok(());
+        }
+        self.refresh_sprints().await?;
+        if self.current_sprint_id == Some(sprint_id) {
+            self.load_sprint_issues(sprint_id);
+        }
+        Ok(())
+    }
+
+    fn render_confirm_batch_overlay(&mut self, f: &mut Frame) {
+        self.render_main_layout(f);
+
+        let (description, keys) = self
+            .pending_batch
+            .as_ref()
+            .map(|(action, issues)| {
+                let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+                (action.describe(issues.len()), keys.join(", "))
+            })
+            .unwrap_or_default();
+        let title = match self.pending_batch {
+            Some((BatchAction::CloseSprint { .. }, _)) => "Confirm Close Sprint",
+            _ => "Confirm Bulk Action",
+        };
+
+        let area = centered_rect(60, 30, f.size());
+        let prompt = Paragraph::new(format!("{}?\n\n{}\n\n(y/n)", description, keys))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .border_style(Style::default().fg(self.theme.accent)),
+            )
+            .style(Style::default().fg(self.theme.text).bg(self.theme.popup))
+            .wrap(Wrap { trim: true });
+        f.render_widget(Clear, area);
+        f.render_widget(prompt, area);
+    }
+
+    fn render_confirm_edit_overlay(&mut self, f: &mut Frame) {
+        self.render_main_layout(f);
+        let Some(edit) = &self.pending_edit else {
+            return;
+        };
+        let field = match edit.field {
+            EditedField::Summary => "Summary",
+            EditedField::Description => "Description",
+        };
+
+        let area = centered_rect(80, 70, f.size());
+        let diff = Paragraph::new(diff::lines(&edit.before, &edit.after, &self.theme))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(
+                        "Save {} of {}? (y: save, n: keep editing, c: discard)",
+                        field, edit.issue_key
+                    ))
+                    .border_style(Style::default().fg(self.theme.accent)),
+            )
+            .style(Style::default().bg(self.theme.popup))
+            .wrap(Wrap { trim: false })
+            .scroll((self.edit_preview_scroll, 0));
+        f.render_widget(Clear, area);
+        f.render_widget(diff, area);
+    }
+
+    fn render_quick_peek_overlay(&mut self, f: &mut Frame) {
+        self.render_main_layout(f);
+        let Some(issue) = &self.peek_issue else {
+            return;
+        };
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let assignee = issue.fields.assignee.as_ref().map_or("Unassigned", |u| u.display_name.as_str());
+        let mut lines = vec![
+            Line::from(Span::styled(issue.fields.summary.as_str(), bold.fg(self.theme.border))),
+            Line::from(""),
+            Line::from(vec![Span::styled("Status: ", bold), Span::raw(issue.fields.status.name.as_str())]),
+            Line::from(vec![Span::styled("Assignee: ", bold), Span::raw(assignee)]),
+            Line::from(""),
+        ];
+        match issue.fields.description.as_deref().filter(|d| !d.trim().is_empty()) {
+            Some(description) => {
+                let shown: Vec<&str> = description.lines().take(PEEK_DESCRIPTION_LINES).collect();
+                lines.extend(shown.iter().map(|line| Line::from(*line)));
+                if description.lines().count() > PEEK_DESCRIPTION_LINES {
+                    lines.push(Line::from(Span::styled("…", Style::default().fg(self.theme.hint))));
+                }
+            }
+            None => lines.push(Line::from(Span::styled("No description", Style::default().fg(self.theme.hint)))),
+        }
+
+        let area = centered_rect(60, 50, f.size());
+        let peek = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("{} (any key closes)", issue.key))
+                    .border_style(Style::default().fg(self.theme.border)),
+            )
+            .style(Style::default().fg(self.theme.text).bg(self.theme.popup))
+            .wrap(Wrap { trim: false });
+        f.render_widget(Clear, area);
+        f.render_widget(peek, area);
+    }
+
+    /// Fetch the full profile of the detail issue's assignee or reporter and
+    /// show it in the user card
+    async fn show_user_card(&mut self, role: &'static str) {
+        let Some(issue) = &self.issue_detail_view.issue else {
+            return;
+        };
+        let user = if role == "Assignee" { &issue.fields.assignee } else { &issue.fields.reporter };
+        let Some(user) = user.clone() else {
+            self.toasts.info(format!("{} has no {}", issue.key, role.to_lowercase()));
+            return;
+        };
+        // Accounts without an id (e.g. Server users) only have what the issue carries
+        let profile = if user.account_id.is_empty() {
+            Ok(user.clone())
+        } else {
+            self.jira_client.get_user(&user.account_id).await
+        };
+        match profile {
+            Ok(profile) => {
+                self.user_card = Some((role, profile));
+                self.mode = AppMode::UserCard;
+            }
+            Err(e) => self.toasts.error(format!("Failed to load {}: {}", user.display_name, e)),
+        }
+    }
+
+    fn render_user_card_overlay(&mut self, f: &mut Frame) {
+        self.render_main_layout(f);
+        let Some((role, user)) = &self.user_card else {
+            return;
+        };
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let dim = Style::default().fg(self.theme.hint);
+        let time_zone = match &user.time_zone {
+            Some(name) => match name.parse::<chrono_tz::Tz>() {
+                Ok(tz) => {
+                    let now = chrono::Utc::now().with_timezone(&tz);
+                    vec![Span::raw(format!("{} ", name)), Span::styled(now.format("(%H:%M %a local time)").to_string(), dim)]
+                }
+                Err(_) => vec![Span::raw(name.clone())],
+            },
+            None => vec![Span::styled("hidden", dim)],
+        };
+        let status = match user.active {
+            Some(true) => Span::styled("Active", Style::default().fg(self.theme.success)),
+            Some(false) => Span::styled("Deactivated", Style::default().fg(self.theme.error)),
+            None => Span::styled("unknown", dim),
+        };
+        let email = match &user.email_address {
+            Some(email) => Span::raw(email.as_str()),
+            None => Span::styled("hidden", dim),
+        };
+        let lines = vec![
+            Line::from(Span::styled(user.display_name.as_str(), bold.fg(self.theme.border))),
+            Line::from(""),
+            Line::from(vec![Span::styled("Email: ", bold), email]),
+            Line::from([vec![Span::styled("Time zone: ", bold)], time_zone].concat()),
+            Line::from(vec![Span::styled("Status: ", bold), status]),
+        ];
+
+        let area = centered_rect(50, 30, f.size());
+        let card = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("{} (Tab switches, any key closes)", role))
+                    .border_style(Style::default().fg(self.theme.border)),
+            )
+            .style(Style::default().fg(self.theme.text).bg(self.theme.popup))
+            .wrap(Wrap { trim: false });
+        f.render_widget(Clear, area);
+        f.render_widget(card, area);
+    }
+
+    /// Open the create-issue form on the board's project
+    async fn begin_create_issue(&mut self, return_mode: AppMode) -> Result<()> {
+        let board_project = self
+            .available_boards
+            .iter()
+            .find(|b| Some(b.id) == self.config.jira.default_board_id)
+            .and_then(|b| b.location.as_ref()?.project_key.clone());
+        let keys = self.available_projects.iter().map(|p| p.key.clone()).collect();
+        self.create_form.open(keys, board_project, self.matcher());
+        self.return_mode = return_mode;
+        self.mode = AppMode::CreateIssue;
+        self.load_create_issue_types().await
+    }
+
+    /// Offer the issue types of the form's project, once per project
+    async fn load_create_issue_types(&mut self) -> Result<()> {
+        let Some(project) = self.create_form.project_key() else {
+            return Ok(());
+        };
+        if self.create_form.types_project.as_ref() == Some(&project) {
+            return Ok(());
+        }
+        let types = self.jira_client.get_issue_types(&project).await?;
+        let names = types.into_iter().map(|t| t.name).collect();
+        self.create_form.set_issue_types(project, names, self.matcher());
+        Ok(())
+    }
+
+    async fn handle_create_issue_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        match key {
+            KeyCode::Esc => self.mode = self.return_mode.clone(),
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.create_form.move_focus(if key == KeyCode::Tab { 1 } else { -1 });
+                self.load_create_issue_types().await?;
+            }
+            KeyCode::Enter => {
+                self.load_create_issue_types().await?;
+                let form = &self.create_form;
+                let summary = form.summary.get_input().trim().to_string();
+                let description = form.description.get_input().trim().to_string();
+                let (Some(project), Some(issue_type)) = (form.project_key(), form.issue_type_name()) else {
+                    // Keep the form open so the field can be fixed
+                    self.toasts.error("Pick a project and an issue type from the suggestions");
+                    return Ok(());
+                };
+                if summary.is_empty() {
+                    self.toasts.error("The summary can't be empty");
+                    return Ok(());
+                }
+                let created = self
+                    .jira_client
+                    .create_issue(&project, &issue_type, &summary, (!description.is_empty()).then_some(description.as_str()))
+                    .await?;
+                self.mode = self.return_mode.clone();
+                let Some(issue) = created else {
+                    self.toasts.info("Dry run: the issue was not created");
+                    return Ok(());
+                };
+                self.toasts.success(format!("Created {}: {}", issue.key, issue.fields.summary));
+                if self.mode == AppMode::Backlog {
+                    self.fetch_backlog(Some(issue.key));
+                } else {
+                    self.refresh_sprint().await?;
+                }
+            }
+            _ => self.create_form.handle_key(key, modifiers),
+        }
+        Ok(())
+    }
+
+    /// List the users the detail issue can be assigned to, starting on the current assignee
+    async fn begin_assign(&mut self) -> Result<()> {
+        let Some(issue) = &self.issue_detail_view.issue else {
+            return Ok(());
+        };
+        let current = issue.fields.assignee.as_ref().map(|user| user.account_id.clone());
+        self.assignable_users = self.jira_client.get_assignable_users(&issue.key).await?;
+        let index = current
+            .and_then(|id| self.assignable_users.iter().position(|user| user.account_id == id))
+            .map_or(0, |i| i + 1);
+        self.assign_state.select(Some(index));
+        self.mode = AppMode::AssignUser;
+        Ok(())
+    }
+
+    async fn handle_assign_user_input(&mut self, key: KeyCode) -> Result<()> {
+        // Row 0 is "Unassigned"
+        let len = self.assignable_users.len() + 1;
+        let selected = self.assign_state.selected().unwrap_or(0);
+        match key {
+            KeyCode::Esc => self.mode = AppMode::IssueDetail,
+            KeyCode::Down | KeyCode::Char('j') => self.assign_state.select(Some((selected + 1) % len)),
+            KeyCode::Up | KeyCode::Char('k') => self.assign_state.select(Some((selected + len - 1) % len)),
+            KeyCode::Enter => {
+                self.mode = AppMode::IssueDetail;
+                let Some(issue_key) = self.issue_detail_view.issue.as_ref().map(|i| i.key.clone()) else {
+                    return Ok(());
+                };
+                let assignee = selected.checked_sub(1).and_then(|i| self.assignable_users.get(i)).cloned();
+                self.jira_client
+                    .assign_issue(&issue_key, assignee.as_ref().map(|user| user.account_id.as_str()))
+                    .await?;
+                self.toasts.success(match &assignee {
+                    Some(user) => format!("{} assigned to {}", issue_key, user.display_name),
+                    None => format!("{} unassigned", issue_key),
+                });
+                let issue = if self.jira_client.is_dry_run() {
+                    // Nothing changed server-side, so show the expected result locally
+                    let Some(mut issue) = self.issue_detail_view.issue.clone() else {
+                        return Ok(());
+                    };
+                    issue.fields.assignee = assignee;
+                    issue
+                } else {
+                    // Fetched again so the detail view shows what Jira stored
+                    self.jira_client.get_issue(&issue_key).await?
+                };
+                self.apply_local_issue(issue);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn render_assign_user_overlay(&mut self, f: &mut Frame) {
+        self.render_main_layout(f);
+        let dim = Style::default().fg(self.theme.hint);
+        let items: Vec<ListItem> = std::iter::once(ListItem::new(Span::styled("Unassigned", dim)))
+            .chain(self.assignable_users.iter().map(|user| {
+                let mut spans = vec![Span::raw(user.display_name.clone())];
+                if let Some(email) = &user.email_address {
+                    spans.push(Span::styled(format!("  {}", email), dim));
+                }
+                ListItem::new(Line::from(spans))
+            }))
+            .collect();
+        let title = match &self.issue_detail_view.issue {
+            Some(issue) => format!("Assign {}", issue.key),
+            None => "Assign".to_string(),
+        };
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .border_style(Style::default().fg(self.theme.border)),
+            )
+            .style(Style::default().fg(self.theme.text).bg(self.theme.popup))
+            .highlight_style(Style::default().bg(self.theme.highlight).add_modifier(Modifier::BOLD))
+            .highlight_symbol(">> ");
+        let area = centered_rect(50, 50, f.size());
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(list, area, &mut self.assign_state);
+    }
+
+    /// Fetch the issue selected in `mode`'s list again and update that one row,
+    /// keeping it selected, instead of reloading the whole list
+    async fn refresh_selected_issue(&mut self, mode: AppMode) -> Result<()> {
+        let selected = match mode {
+            AppMode::Backlog => self.backlog_view.selected_issue(),
+            AppMode::SearchResults => self.search_view.results.selected_issue(),
+            AppMode::MyIssues => self.my_issues_view.selected_issue(),
+            _ => self.sprint_view.selected_issue(),
+        };
+        let Some(key) = selected.map(|issue| issue.key.clone()) else {
+            return Ok(());
+        };
+        let issue = self.jira_client.get_issue(&key).await?;
+        self.apply_local_issue(issue);
+        // Grouping by status or assignee may have moved the row
+        match mode {
+            AppMode::Backlog => self.backlog_view.select_key(&key),
+            AppMode::SearchResults => self.search_view.results.select_key(&key),
+            AppMode::MyIssues => self.my_issues_view.select_key(&key),
+            _ => self.sprint_view.select_key(&key),
+        }
+        self.toasts.info(format!("Refreshed {}", key));
+        Ok(())
+    }
+
+    /// Replace an issue wherever it is currently shown, without re-fetching it
+    fn apply_local_issue(&mut self, issue: crate::jira::Issue) {
+        for list in [
+            &mut self.sprint_view.issues,
+            &mut self.backlog_view.issues,
+            &mut self.search_view.results.issues,
+            &mut self.my_issues_view.issues,
+        ] {
+            if let Some(existing) = list.iter_mut().find(|i| i.key == issue.key) {
+                *existing = issue.clone();
+            }
+        }
+        if self.issue_detail_view.issue.as_ref().is_some_and(|i| i.key == issue.key) {
+            self.issue_detail_view.set_issue(issue);
+        }
+    }
+
+    async fn refresh_sprints(&mut self) -> Result<()> {
+        if let Some(board_id) = self.config.jira.default_board_id {
+            (self.available_sprints, self.closed_sprints_loaded) =
+                fetch_board_sprints(&self.jira_client, board_id, self.config.ui.load_closed_sprints || self.closed_sprints_loaded)
+                    .await?;
+            self.sprint_selector.set_sprints(self.available_sprints.clone());
+            self.sprint_selector.closed_hidden = !self.closed_sprints_loaded;
+        }
+        Ok(())
+    }
+
+    /// Kanban boards have no sprints, and the sprint endpoints reject them
+    fn board_is_kanban(&self) -> bool {
+        self.available_boards
+            .iter()
+            .find(|b| Some(b.id) == self.config.jira.default_board_id)
+            .is_some_and(|b| b.board_type == "kanban")
+    }
+
+    async fn refresh_sprint(&mut self) -> Result<()> {
+        self.refresh_sprint_selecting(None).await
+    }
+
+    /// `refresh_sprint`, then select `select_key` again if it is still listed
+    async fn refresh_sprint_selecting(&mut self, select_key: Option<String>) -> Result<()> {
+        if self.board_is_kanban() {
+            self.sprint_view.set_issues(Vec::new(), "No sprints on a kanban board".to_string(), None);
+            if self.mode == AppMode::Sprint {
+                self.mode = AppMode::Backlog;
+                self.toasts.info("Kanban board: showing the board's issues");
+            }
+            self.fetch_backlog(select_key);
+            return Ok(());
+        }
+        if let Some(board_id) = self.config.jira.default_board_id {
+            // Load available sprints if not already loaded
+            if self.offline {
+                let Some(board) = self.cache.board_data.get(&board_id) else {
+                    anyhow::bail!("Board {} isn't cached", board_id);
+                };
+                if self.available_sprints.is_empty() {
+                    (self.available_sprints, self.closed_sprints_loaded) = (board.sprints.clone(), board.closed_sprints);
+                }
+                self.columns_board_id = Some(board_id);
+                self.sprint_view.columns = board.columns.clone();
+            }
+            if self.available_sprints.is_empty() {
+                (self.available_sprints, self.closed_sprints_loaded) =
+                    fetch_board_sprints(&self.jira_client, board_id, self.config.ui.load_closed_sprints).await?;
+            }
+            
+            if self.columns_board_id != Some(board_id) {
+                self.columns_board_id = Some(board_id);
+                self.sprint_view.columns = self.jira_client.get_board_columns(board_id).await.unwrap_or_default();
+            }
+
+            // If we have a current sprint ID, use it; otherwise find the last (most recent) sprint
+            let target_sprint = if let Some(current_id) = self.current_sprint_id {
+                self.available_sprints.iter().find(|s| s.id == current_id)
+            } else {
+                default_sprint(
+                    &self.available_sprints,
+                    &self.config.ui.sprint_sort,
+                    self.config.ui.prefer_active_sprint,
+                )
+            };
+                
+            if let Some(sprint_id) = target_sprint.map(|sprint| sprint.id) {
+                self.current_sprint_id = Some(sprint_id);
+                self.fetch_sprint_issues(sprint_id, select_key);
+            } else {
+                // No sprints available, show empty sprint
+                self.sprint_view.set_issues(Vec::new(), "No Sprints Available".to_string(), None);
+            }
+        }
+        Ok(())
+    }
+
+    /// Refresh the visible list every `ui.refresh_interval` seconds (0 disables),
+    /// backing off while Jira reports the rate-limit quota running low
+    async fn auto_refresh(&mut self) {
+        let interval = self.config.ui.refresh_interval;
+        if interval == 0 || self.offline || self.loading() {
+            return;
+        }
+        let rate_limit = self.jira_client.rate_limit().unwrap_or_default();
+        let mut wait = Duration::from_secs(interval);
+        if rate_limit.is_low() {
+            wait = (wait * RATE_LIMIT_BACKOFF as u32)
+                .max(Duration::from_secs(rate_limit.retry_after.unwrap_or(0)));
+        }
+        if self.last_refresh.elapsed() < wait {
+            return;
+        }
+        self.last_refresh = Instant::now();
+
+        // A failed background refresh is simply retried on the next interval,
+        // and the cursor stays on the issue it was on
+        let _ = match self.mode {
+            AppMode::Sprint => {
+                let key = self.sprint_view.selected_issue().map(|issue| issue.key.clone());
+                self.refresh_sprint_selecting(key).await
+            }
+            AppMode::Backlog => {
+                let key = self.backlog_view.selected_issue().map(|issue| issue.key.clone());
+                self.fetch_backlog(key);
+                Ok(())
+            }
+            _ => Ok(()),
+        };
+    }
+
+    /// JQL narrowing the sprint and backlog fetches: the quick filter and the assignee filter
+    fn view_jql(&self) -> Option<String> {
+        let clauses: Vec<String> = self
+            .quick_filter
+            .iter()
+            .map(|f| format!("({})", f.jql))
+            .chain(self.assignee_filter.iter().map(|u| format!("assignee = \"{}\"", u.account_id)))
+            .collect();
+        (!clauses.is_empty()).then(|| clauses.join(" AND "))
+    }
+
+    /// Whether anything is being fetched in the background
+    fn loading(&self) -> bool {
+        self.is_loading || self.sprint_load.in_flight || self.backlog_load.in_flight
+    }
+
+    /// Fetch the backlog (the board's issues on kanban boards) in the background
+    fn load_backlog(&mut self) {
+        self.fetch_backlog(None);
+    }
+
+    fn fetch_backlog(&mut self, select_key: Option<String>) {
+        let (Some(board_id), Some(sender)) = (self.config.jira.default_board_id, self.event_sender.clone()) else {
+            return;
+        };
+        let generation = self.backlog_load.start();
+        let kanban = self.board_is_kanban();
+        if self.offline {
+            let issues = self
+                .cache
+                .board_data
+                .get(&board_id)
+                .and_then(|board| board.backlog.clone())
+                .ok_or_else(|| "The backlog isn't cached".to_string());
+            return self.apply_loaded_data(AppData::Backlog { generation, kanban, select_key, issues });
+        }
+        let jql = self.view_jql();
+        let client = self.jira_client.clone();
+        tokio::spawn(async move {
+            let issues = if kanban {
+                client.get_board_issues(board_id, jql.as_deref()).await
+            } else {
+                client.get_backlog(board_id, jql.as_deref()).await
+            };
+            let data = AppData::Backlog { generation, kanban, select_key, issues: issues.map_err(|e| e.to_string()) };
+            let _ = sender.send(Event::DataLoaded(Box::new(data)));
+        });
+    }
+
+    /// Show `issue` in the details view and remember it for the go-to prompt
+    async fn open_issue(&mut self, issue: crate::jira::Issue) -> Result<()> {
+        // Offline, the cached issue is shown without its transitions
+        if !self.offline {
+            self.load_transitions(&issue.key).await?;
+        }
+        self.recent_issues.retain(|key| *key != issue.key);
+        self.recent_issues.insert(0, issue.key.clone());
+        self.recent_issues.truncate(RECENT_ISSUES);
+        let issue_key = issue.key.clone();
+        self.issue_detail_view.account_id = self.current_user.as_ref().map(|u| u.account_id.clone());
+        self.issue_detail_view.set_issue(issue);
+        self.load_worklogs(&issue_key).await;
+        self.mode = AppMode::IssueDetail;
+        Ok(())
+    }
+
+    /// Worklogs are extra detail, so an instance with time tracking turned
+    /// off just shows none
+    async fn load_worklogs(&mut self, issue_key: &str) {
+        let worklogs = self.jira_client.get_worklogs(issue_key).await.unwrap_or_default();
+        self.issue_detail_view.set_worklogs(worklogs);
+    }
+
+    async fn load_transitions(&mut self, issue_key: &str) -> Result<()> {
+        let transitions = self.jira_client.get_transitions(issue_key).await?;
+        self.issue_detail_view.set_transitions(transitions);
+        Ok(())
+    }
+
+    /// Fetch a sprint's issues in the background; the list keeps showing the
+    /// previous issues until they arrive
+    fn load_sprint_issues(&mut self, sprint_id: u32) {
+        self.fetch_sprint_issues(sprint_id, None);
+    }
+
+    fn fetch_sprint_issues(&mut self, sprint_id: u32, select_key: Option<String>) {
+        let (Some(board_id), Some(sender)) = (self.config.jira.default_board_id, self.event_sender.clone()) else {
+            return;
+        };
+        let generation = self.sprint_load.start();
+        if self.offline {
+            let issues = self
+                .cache
+                .board_data
+                .get(&board_id)
+                .and_then(|board| board.sprint_issues.get(&sprint_id).cloned())
+                .ok_or_else(|| format!("Sprint {} isn't cached", sprint_id));
+            return self.apply_loaded_data(AppData::SprintIssues { generation, sprint_id, select_key, issues });
+        }
+        let jql = self.view_jql();
+        let client = self.jira_client.clone();
+        tokio::spawn(async move {
+            let issues = client.get_sprint_issues(board_id, sprint_id, jql.as_deref()).await;
+            let data = AppData::SprintIssues { generation, sprint_id, select_key, issues: issues.map_err(|e| e.to_string()) };
+            let _ = sender.send(Event::DataLoaded(Box::new(data)));
+        });
+    }
+
+    /// Show a background fetch, unless a newer one for the same view replaced it
+    fn apply_loaded_data(&mut self, data: AppData) {
+        match data {
+            AppData::SprintIssues { generation, sprint_id, select_key, issues } => {
+                if !self.sprint_load.finish(generation) {
+                    return;
+                }
+                let issues = match issues {
+                    Ok(issues) => issues,
+                    Err(e) => return self.toasts.error(e),
+                };
+                let sprint = self.available_sprints.iter().find(|s| s.id == sprint_id);
+                let (sprint_name, sprint_goal) = sprint
+                    .map(|s| (s.name.clone(), s.goal.clone()))
+                    .unwrap_or_else(|| (format!("Sprint {}", sprint_id), None));
+                let end = sprint.map(|s| (s.end_date, s.state.clone()));
+                self.cache_issues(Some(sprint_id), &issues);
+                self.sprint_view.set_issues(issues, sprint_name, sprint_goal);
+                self.mark_refreshed();
+                self.sprint_view.quick_filter = self.quick_filter.as_ref().map(|f| f.name.clone());
+                self.sprint_view.assignee_filter = self.assignee_filter.as_ref().map(|u| u.display_name.clone());
+                if let Some((end_date, state)) = end {
+                    self.sprint_view.set_sprint_end(end_date, &state);
+                }
+                if let Some(key) = select_key {
+                    self.sprint_view.select_key(&key);
+                }
+            }
+            AppData::Backlog { generation, kanban, select_key, issues } => {
+                if !self.backlog_load.finish(generation) {
+                    return;
+                }
+                let issues = match issues {
+                    Ok(issues) => issues,
+                    Err(e) => return self.toasts.error(e),
+                };
+                self.cache_issues(None, &issues);
+                self.backlog_view.set_issues(issues);
+                self.mark_refreshed();
+                self.backlog_view.kanban = kanban;
+                self.backlog_view.quick_filter = self.quick_filter.as_ref().map(|f| f.name.clone());
+                self.backlog_view.assignee_filter = self.assignee_filter.as_ref().map(|u| u.display_name.clone());
+                if let Some(key) = select_key {
+                    self.backlog_view.select_key(&key);
+                }
+            }
+        }
+    }
+    
+    /// Load projects, boards, the current user and the default sprint on a
+    /// background task; the result arrives as `Event::Initialized`.
+    pub fn start_initialize(&mut self, sender: mpsc::UnboundedSender<Event>) {
+        self.event_sender = Some(sender.clone());
+        let client = self.jira_client.clone();
+        let board_id = self.config.jira.default_board_id;
+        let sprint_sort = self.config.ui.sprint_sort.clone();
+        let prefer_active = self.config.ui.prefer_active_sprint;
+        let load_closed = self.config.ui.load_closed_sprints;
+        let last_view = if self.config.ui.restore_last_view {
+            LastView::load()
+        } else {
+            None
+        };
+        let startup = self.startup;
+
+        // Show the cached lists right away, until the fresh ones arrive
+        if self.available_boards.is_empty()
+            && let Some((data, saved_at)) =
+                cached_initial_data(&self.cache, board_id, last_view.clone(), startup, &sprint_sort, prefer_active)
+        {
+            let cached_board = data.board_id;
+            self.apply_initial_data(Ok(data));
+            self.init_from_cache = cached_board;
+            self.cached_at = Some(saved_at);
+            self.last_refreshed = None;
+        }
+        if self.offline {
+            if self.cached_at.is_none() {
+                self.sprint_view.set_issues(
+                    Vec::new(),
+                    "Nothing cached yet: start once without --offline".to_string(),
+                    None,
+                );
             }
-            _ => {}
+            return;
         }
-        Ok(())
+
+        self.is_loading = true;
+        tokio::spawn(async move {
+            let result = load_initial_data(&client, board_id, last_view, startup, &sprint_sort, prefer_active, load_closed)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = sender.send(Event::Initialized(Box::new(result)));
+        });
     }
 
-    async fn handle_help_input(&mut self, key: KeyCode) -> Result<bool> {
-        match key {
-            KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Char('h') | KeyCode::Esc => self.show_help = false,
-            _ => {}
+    /// Merge the background load into the views, keeping anything the user
+    /// already loaded (e.g. by switching boards) while it was in flight.
+    fn apply_initial_data(&mut self, result: Result<InitialData, String>) {
+        self.is_loading = false;
+        let data = match result {
+            Ok(data) => data,
+            Err(e) if self.init_from_cache.is_some() => {
+                // Keep showing the cached lists
+                self.init_failed = true;
+                self.toasts.error(format!("Failed to load: {}", e));
+                return;
+            }
+            Err(e) => {
+                self.init_failed = true;
+                self.sprint_view.set_issues(Vec::new(), format!("Failed to load: {}", e), None);
+                return;
+            }
+        };
+        self.init_failed = false;
+        if let Some(cached_board) = self.init_from_cache.take() {
+            // Fresh data replaces what the cache showed, and picks the board
+            // again unless another one was opened meanwhile
+            self.available_projects.clear();
+            self.available_boards.clear();
+            self.current_user = None;
+            if self.config.jira.default_board_id == Some(cached_board) {
+                self.config.jira.default_board_id = data.requested_board_id;
+                self.available_sprints.clear();
+                self.cached_at = None;
+            }
+        }
+        self.last_refreshed = Some(chrono::Utc::now());
+        // A retry after a failed load still honors the flags
+        self.startup = StartupView::default();
+        for notice in &data.notices {
+            self.toasts.error(notice.clone());
         }
-        Ok(self.should_quit)
-    }
 
-    async fn refresh_sprints(&mut self) -> Result<()> {
-        if let Some(board_id) = self.config.jira.default_board_id {
-            self.available_sprints = self.jira_client.get_board_sprints(board_id).await?;
-            self.sprint_selector.set_sprints(self.available_sprints.clone());
+        if self.available_projects.is_empty() {
+            self.available_projects = data.projects;
         }
-        Ok(())
-    }
+        if self.current_user.is_none() {
+            self.current_user = data.current_user;
+        }
+        if self.available_boards.is_empty() {
+            self.available_boards = data.boards;
+        }
+        if self.config.jira.default_board_id != data.requested_board_id || !self.available_sprints.is_empty() {
+            return;
+        }
+        self.config.jira.default_board_id = data.board_id;
 
-    async fn refresh_sprint(&mut self) -> Result<()> {
-        if let Some(board_id) = self.config.jira.default_board_id {
-            // Load available sprints if not already loaded
-            if self.available_sprints.is_empty() {
-                self.available_sprints = self.jira_client.get_board_sprints(board_id).await?;
-            }
-            
-            // If we have a current sprint ID, use it; otherwise find the last (most recent) sprint
-            let target_sprint = if let Some(current_id) = self.current_sprint_id {
-                self.available_sprints.iter().find(|s| s.id == current_id)
-            } else {
-                // Start with the last sprint (most recent)
-                self.available_sprints.last()
-            };
-                
-            if let Some(sprint) = target_sprint {
+        self.available_sprints = data.sprints;
+        self.closed_sprints_loaded = data.closed_sprints;
+        self.columns_board_id = data.board_id;
+        self.sprint_view.columns = data.columns;
+        match data.sprint {
+            Some((sprint, issues)) => {
                 self.current_sprint_id = Some(sprint.id);
-                let issues = self.jira_client.get_sprint_issues(board_id, sprint.id).await?;
-                self.sprint_view.set_issues(issues, sprint.name.clone(), sprint.goal.clone());
-            } else {
-                // No sprints available, show empty sprint
+                self.sprint_view.set_issues(issues, sprint.name, sprint.goal);
+                self.sprint_view.set_sprint_end(sprint.end_date, &sprint.state);
+            }
+            None if data.board_id.is_some() => {
                 self.sprint_view.set_issues(Vec::new(), "No Sprints Available".to_string(), None);
             }
+            None => {}
         }
-        Ok(())
-    }
-
-    async fn load_backlog(&mut self) -> Result<()> {
-        if let Some(board_id) = self.config.jira.default_board_id {
-            let issues = self.jira_client.get_backlog(board_id).await?;
+        if let Some(issues) = data.backlog
+            && self.mode == AppMode::Sprint
+        {
             self.backlog_view.set_issues(issues);
+            self.backlog_view.kanban = self.board_is_kanban();
+            self.mode = AppMode::Backlog;
         }
-        Ok(())
     }
 
-    async fn load_transitions(&mut self, issue_key: &str) -> Result<()> {
-        let transitions = self.jira_client.get_transitions(issue_key).await?;
-        self.issue_detail_view.set_transitions(transitions);
-        Ok(())
+    /// Whether fetched data goes into the disk cache
+    fn caching(&self) -> bool {
+        self.config.ui.cache_ttl_hours > 0 && !self.offline
     }
 
-    async fn load_sprint_issues(&mut self, sprint_id: u32) -> Result<()> {
-        if let Some(board_id) = self.config.jira.default_board_id {
-            let issues = self.jira_client.get_sprint_issues(board_id, sprint_id).await?;
-            
-            // Find the sprint name
-            let (sprint_name, sprint_goal) = self.available_sprints
-                .iter()
-                .find(|s| s.id == sprint_id)
-                .map(|s| (s.name.clone(), s.goal.clone()))
-                .unwrap_or_else(|| (format!("Sprint {}", sprint_id), None));
-            
-            self.sprint_view.set_issues(issues, sprint_name, sprint_goal);
+    fn cache_initial_data(&mut self, data: &InitialData) {
+        if !self.caching() {
+            return;
+        }
+        self.cache.saved_at = Some(chrono::Utc::now());
+        self.cache.projects = data.projects.clone();
+        self.cache.boards = data.boards.clone();
+        self.cache.current_user = data.current_user.clone();
+        let Some(board_id) = data.board_id else {
+            return;
+        };
+        let board = self.cache.board(board_id);
+        board.sprints = data.sprints.clone();
+        board.closed_sprints = data.closed_sprints;
+        board.columns = data.columns.clone();
+        if let Some((sprint, issues)) = &data.sprint {
+            board.sprint_issues.insert(sprint.id, issues.clone());
+        }
+        if let Some(backlog) = &data.backlog {
+            board.backlog = Some(backlog.clone());
         }
-        Ok(())
     }
-    
-    pub async fn initialize(&mut self) -> Result<()> {
-        // Load projects if none are available
-        if self.available_projects.is_empty() {
-            self.available_projects = self.jira_client.get_projects().await.unwrap_or_default();
+
+    /// Remember a sprint's issues, or the backlog's when `sprint_id` is `None`
+    fn cache_issues(&mut self, sprint_id: Option<u32>, issues: &[crate::jira::Issue]) {
+        // A filtered list would later pass for the whole sprint
+        if !self.caching() || self.view_jql().is_some() {
+            return;
         }
-        
-        // Load boards if none are available
-        if self.available_boards.is_empty() {
-            self.available_boards = self.jira_client.get_boards().await.unwrap_or_default();
+        let Some(board_id) = self.config.jira.default_board_id else {
+            return;
+        };
+        self.cache.saved_at = Some(chrono::Utc::now());
+        let (sprints, closed_sprints) = (self.available_sprints.clone(), self.closed_sprints_loaded);
+        let board = self.cache.board(board_id);
+        match sprint_id {
+            Some(id) => {
+                board.sprints = sprints;
+                board.closed_sprints = closed_sprints;
+                board.sprint_issues.insert(id, issues.to_vec());
+            }
+            None => board.backlog = Some(issues.to_vec()),
         }
-        
-        // Set default board if not configured but boards are available
-        if self.config.jira.default_board_id.is_none() && !self.available_boards.is_empty() {
-            self.config.jira.default_board_id = Some(self.available_boards[0].id);
+    }
+
+    /// Fresh issues arrived: show the time and drop the cached-data notice
+    fn mark_refreshed(&mut self) {
+        if self.offline {
+            return;
         }
-        
-        // Load initial sprint data
-        self.refresh_sprint().await?;
-        Ok(())
+        self.last_refreshed = Some(chrono::Utc::now());
+        self.cached_at = None;
+    }
+
+    /// Write the fetched data to disk for the next launch
+    pub fn save_cache(&self) {
+        if !self.caching() || self.cache.saved_at.is_none() {
+            return;
+        }
+        // Like the remembered view, not worth failing the exit over
+        let _ = self.cache.save();
+    }
+
+    /// Save the board, sprint and view for the next launch when `ui.restore_last_view` is on
+    pub fn remember_last_view(&self) {
+        if !self.config.ui.restore_last_view {
+            return;
+        }
+        let Some(board_id) = self.config.jira.default_board_id else {
+            return;
+        };
+        let mode = if self.mode == AppMode::Backlog { "backlog" } else { "sprint" };
+        let view = LastView {
+            mode: mode.to_string(),
+            board_id,
+            sprint_id: self.current_sprint_id,
+        };
+        // Losing the remembered view is not worth failing the exit over
+        let _ = view.save();
     }
 
     pub fn render(&mut self, f: &mut Frame) {
         if self.show_help {
-            self.help_view.render(f, f.size());
-            return;
+            self.help_view.render(f, f.size(), &self.theme);
+        } else {
+            self.render_mode(f);
         }
+        // Above the status bar
+        let size = f.size();
+        let (_, status_height) = self.bar_heights();
+        let toast_area = Rect::new(size.x, size.y, size.width, size.height.saturating_sub(status_height));
+        self.toasts.render(f, toast_area, &self.theme);
+        self.theme.apply(f.buffer_mut());
+        self.color_depth.adapt(f.buffer_mut());
+    }
 
+    fn render_mode(&mut self, f: &mut Frame) {
         match self.mode {
-            AppMode::AddComment | AppMode::EditIssue | AppMode::EditSprintName => {
-                self.render_input_overlay(f);
+            // The list itself shows the filter, so there's no prompt to draw
+            AppMode::FilterList => self.render_main_layout(f),
+            _ if self.input_active() => self.render_input_overlay(f),
+            AppMode::ConfirmDeleteSubtasks => {
+                self.render_delete_subtasks_overlay(f);
+            }
+            AppMode::ConfirmBatch => {
+                self.render_confirm_batch_overlay(f);
+            }
+            AppMode::Confirm => {
+                self.render_main_layout(f);
+                let area = centered_rect(50, 25, f.size());
+                self.confirm_dialog.render(f, area, &self.theme);
+            }
+            AppMode::ConfirmEdit => self.render_confirm_edit_overlay(f),
+            AppMode::QuickPeek => self.render_quick_peek_overlay(f),
+            AppMode::UserCard => self.render_user_card_overlay(f),
+            AppMode::AssignUser => self.render_assign_user_overlay(f),
+            AppMode::CreateIssue => {
+                self.render_main_layout(f);
+                let area = centered_rect(60, 70, f.size());
+                self.create_form.render(f, area, &self.theme);
+            }
+            AppMode::CloseSprint => {
+                self.render_main_layout(f);
+                let area = centered_rect(70, 60, f.size());
+                self.close_sprint_view.render(f, area, &self.theme);
+            }
+            AppMode::BatchSummary => {
+                self.render_main_layout(f);
+                let area = centered_rect(70, 50, f.size());
+                self.batch_summary.render(f, area, &self.theme);
+            }
+            AppMode::AttachmentPreview => {
+                self.render_main_layout(f);
+                let area = centered_rect(80, 80, f.size());
+                self.image_preview.render(f, area, &self.theme);
             }
             _ => {
                 self.render_main_layout(f);
@@ -534,53 +4463,179 @@ impl App {
         }
     }
 
+    /// Whether a text prompt is open over the current view
+    fn input_active(&self) -> bool {
+        matches!(
+            self.mode,
+            AppMode::AddComment
+                | AppMode::EditIssue
+                | AppMode::EditEnvironment
+                | AppMode::EditSprintName
+                | AppMode::DeleteIssue
+                | AppMode::FilterLabel
+                | AppMode::FilterList
+                | AppMode::QuickFilter
+                | AppMode::Search
+                | AppMode::FilterAssignee
+                | AppMode::EditSetting
+                | AppMode::TransitionComment
+                | AppMode::PickResolution
+                | AppMode::EditEstimate
+                | AppMode::EditDueDate
+                | AppMode::AddWorklog
+                | AppMode::EditComment
+                | AppMode::GoToIssue
+                | AppMode::ExportCsv
+                | AppMode::FindBoard
+        )
+    }
+
+    /// Rows of the tab bar and the status bar; compact mode drops the borders
+    /// and only shows the status bar while a prompt is open
+    fn bar_heights(&self) -> (u16, u16) {
+        match (self.config.ui.compact, self.input_active() || self.connection_lost()) {
+            (false, _) => (3, 3),
+            (true, true) => (1, 1),
+            (true, false) => (1, 0),
+        }
+    }
+
+    /// Requests keep failing to reach Jira, e.g. after a VPN drop
+    fn connection_lost(&self) -> bool {
+        self.jira_client.network_failures() >= CONNECTION_LOST_AFTER
+    }
+
     fn render_main_layout(&mut self, f: &mut Frame) {
+        let (tab_height, status_height) = self.bar_heights();
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3),  // Tab bar
-                Constraint::Min(0),     // Main content
-                Constraint::Length(3),  // Status bar
+                Constraint::Length(tab_height),    // Tab bar
+                Constraint::Min(0),                // Main content
+                Constraint::Length(status_height), // Status bar
             ])
             .split(f.size());
 
         // Tab bar
         let titles = vec!["Sprint", "Backlog", "Issue Detail"];
-        let tabs = Tabs::new(titles)
-            .block(Block::default().borders(Borders::ALL).title("Jira TUI"))
-            .style(Style::default().fg(Color::White))
-            .highlight_style(Style::default().fg(Color::Yellow))
+        let board_name = self
+            .available_boards
+            .iter()
+            .find(|b| Some(b.id) == self.config.jira.default_board_id)
+            .map(|b| b.name.as_str());
+        let tabs_title = match board_name {
+            Some(name) => format!("Jira TUI - {}", name),
+            None => "Jira TUI".to_string(),
+        };
+        let mut tab_area = chunks[0];
+        let tabs = Tabs::new(titles);
+        let tabs = if self.config.ui.compact {
+            // The title moves in front of the tabs on the same line
+            let title = format!(" {} │", tabs_title);
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(title.chars().count() as u16), Constraint::Min(0)])
+                .split(tab_area);
+            f.render_widget(Paragraph::new(title).style(Style::default().fg(self.theme.border)), columns[0]);
+            tab_area = columns[1];
+            tabs
+        } else {
+            tabs.block(Block::default().borders(Borders::ALL).title(tabs_title))
+        };
+        let tabs = tabs
+            .style(Style::default().fg(self.theme.text))
+            .highlight_style(Style::default().fg(self.theme.accent))
             .select(match self.mode {
                 AppMode::Sprint => 0,
                 AppMode::Backlog => 1,
-                AppMode::IssueDetail => 2,
+                AppMode::FilterList if self.return_mode == AppMode::Backlog => 1,
+                AppMode::IssueDetail | AppMode::AttachmentPreview => 2,
                 _ => 0,
             });
-        f.render_widget(tabs, chunks[0]);
+        f.render_widget(tabs, tab_area);
 
         // Main content
         match self.mode {
-            AppMode::Sprint => self.sprint_view.render(f, chunks[1]),
-            AppMode::SprintSelector => self.sprint_selector.render(f, chunks[1]),
-            AppMode::BoardSelector => self.board_selector.render(f, chunks[1]),
-            AppMode::ProjectSelector => self.project_selector.render(f, chunks[1]),
-            AppMode::Backlog => self.backlog_view.render(f, chunks[1]),
-            AppMode::IssueDetail => self.issue_detail_view.render(f, chunks[1]),
+            AppMode::Sprint => self.sprint_view.render(f, chunks[1], &self.theme),
+            AppMode::SearchResults => self.search_view.render(f, chunks[1], &self.theme),
+            AppMode::MyIssues => self.my_issues_view.render(f, chunks[1], &self.theme),
+            AppMode::Blocked => self.blocked_view.render(f, chunks[1], &self.theme),
+            AppMode::Board => self.board_view.render(f, chunks[1], &self.theme),
+            AppMode::SprintSelector => self.sprint_selector.render(f, chunks[1], &self.theme),
+            AppMode::BoardSelector | AppMode::FindBoard => {
+                if let Some(preview) = self.board_preview.as_mut() {
+                    let columns = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+                        .split(chunks[1]);
+                    self.board_selector.render(f, columns[0], &self.theme);
+                    preview.render(f, columns[1], &self.theme);
+                } else {
+                    self.board_selector.render(f, chunks[1], &self.theme);
+                }
+            }
+            AppMode::BoardSprintSelector => {
+                let columns = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+                    .split(chunks[1]);
+                self.board_selector.render(f, columns[0], &self.theme);
+                self.sprint_selector.render(f, columns[1], &self.theme);
+            }
+            AppMode::ProjectSelector => self.project_selector.render(f, chunks[1], &self.theme),
+            AppMode::Backlog => self.backlog_view.render(f, chunks[1], &self.theme),
+            AppMode::FilterList if self.return_mode == AppMode::Backlog => self.backlog_view.render(f, chunks[1], &self.theme),
+            AppMode::FilterList => self.sprint_view.render(f, chunks[1], &self.theme),
+            AppMode::IssueDetail | AppMode::AttachmentPreview => self.issue_detail_view.render(f, chunks[1], &self.theme),
+            AppMode::Settings | AppMode::EditSetting => self.settings_view.render(f, chunks[1], &self.theme),
             _ => {}
         }
 
         // Status bar with contextual keybindings
-        self.render_status_bar(f, chunks[2]);
+        if status_height > 0 {
+            self.render_status_bar(f, chunks[2]);
+        }
     }
 
     fn render_input_overlay(&mut self, f: &mut Frame) {
         // Render the main content first
         self.render_main_layout(f);
 
-        // Render input overlay
+        // Render input overlay, taller when it takes several lines
+        let height = if self.input_view.multiline { 40 } else { 20 };
+        let area = centered_rect(60, height, f.size());
+        f.render_widget(Block::default().style(Style::default().bg(self.theme.popup)), area);
+        if self.mode == AppMode::Search {
+            self.search_view.render_prompt(f, area, &self.theme);
+        } else {
+            self.input_view.render(f, area, &self.theme);
+        }
+    }
+
+    fn render_delete_subtasks_overlay(&mut self, f: &mut Frame) {
+        self.render_main_layout(f);
+
+        let (issue_key, subtask_count) = self
+            .pending_delete
+            .as_ref()
+            .map(|i| (i.key.as_str(), i.fields.subtasks.as_ref().map_or(0, |s| s.len())))
+            .unwrap_or(("", 0));
+
         let area = centered_rect(60, 20, f.size());
-        f.render_widget(Block::default().style(Style::default().bg(Color::Black)), area);
-        self.input_view.render(f, area);
+        let prompt = Paragraph::new(format!(
+            "{} has {} sub-task(s). Delete them as well? (y/n)",
+            issue_key, subtask_count
+        ))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Delete Sub-tasks")
+                .border_style(Style::default().fg(self.theme.error)),
+        )
+        .style(Style::default().fg(self.theme.text).bg(self.theme.popup))
+        .wrap(Wrap { trim: true });
+        f.render_widget(Clear, area);
+        f.render_widget(prompt, area);
     }
 
     fn render_status_bar(&self, f: &mut Frame, area: Rect) {
@@ -594,27 +4649,85 @@ impl App {
                 let mut spans = vec![
                     Span::styled(
                         format!(" {}", key),
-                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                        Style::default().fg(self.theme.accent).add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(
                         format!(" {}", desc),
-                        Style::default().fg(Color::Gray),
+                        Style::default().fg(self.theme.muted),
                     ),
                 ];
                 
                 // Add separator between keybindings (except for the last one)
                 if i < keybinding_count - 1 {
-                    spans.push(Span::styled(" │", Style::default().fg(Color::DarkGray)));
+                    spans.push(Span::styled(" │", Style::default().fg(self.theme.hint)));
                 }
                 
                 spans
             })
             .collect();
 
-        let status_line = Line::from(keybinding_spans);
-        let status_bar = Paragraph::new(status_line)
-            .block(Block::default().borders(Borders::ALL))
-            .style(Style::default().bg(Color::Black));
+        let mut status_spans = Vec::new();
+        if self.offline {
+            status_spans.push(Span::styled(
+                " OFFLINE ",
+                Style::default().fg(self.theme.on_accent).bg(self.theme.accent).add_modifier(Modifier::BOLD),
+            ));
+        }
+        if let Some(saved_at) = self.cached_at {
+            status_spans.push(Span::styled(
+                format!(" Cached data from {} │", saved_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M")),
+                Style::default().fg(self.theme.accent),
+            ));
+        }
+        if self.connection_lost() {
+            status_spans.push(Span::styled(
+                " Connection lost — press r to retry ",
+                Style::default().fg(self.theme.on_accent).bg(self.theme.error).add_modifier(Modifier::BOLD),
+            ));
+            status_spans.push(Span::raw(" │"));
+        }
+        if let Some(wait) = self.jira_client.rate_limit_wait() {
+            status_spans.push(Span::styled(
+                format!(" Rate limited — retrying in {}s │", wait.as_millis().div_ceil(1000)),
+                Style::default().fg(self.theme.accent),
+            ));
+        }
+        if self.loading() {
+            let spinner = Glyphs::new(self.config.ui.use_emoji).spinner(self.spinner_frame);
+            status_spans.push(Span::styled(format!(" {} Loading… │", spinner), Style::default().fg(self.theme.border)));
+        } else if let Some(refreshed) = self.last_refreshed {
+            status_spans.push(Span::styled(
+                format!(" Last refreshed: {} │", refreshed.with_timezone(&chrono::Local).format("%H:%M:%S")),
+                Style::default().fg(self.theme.hint),
+            ));
+        }
+        if let Some(rate_limit) = self.jira_client.rate_limit()
+            && let Some(remaining) = rate_limit.remaining
+        {
+            let color = if rate_limit.is_low() { self.theme.error } else { self.theme.hint };
+            status_spans.push(Span::styled(
+                format!(" API: {} left │", remaining),
+                Style::default().fg(color),
+            ));
+        }
+        if self.jira_client.is_dry_run() {
+            status_spans.push(Span::styled(
+                " DRY RUN ",
+                Style::default().fg(self.theme.on_accent).bg(self.theme.error).add_modifier(Modifier::BOLD),
+            ));
+            if let Some(last) = self.jira_client.last_dry_run() {
+                status_spans.push(Span::styled(format!(" {} │", last), Style::default().fg(self.theme.error)));
+            }
+        }
+        status_spans.extend(keybinding_spans);
+
+        let status_line = Line::from(status_spans);
+        let status_bar = Paragraph::new(status_line).style(Style::default().bg(self.theme.status_bar));
+        let status_bar = if self.config.ui.compact {
+            status_bar
+        } else {
+            status_bar.block(Block::default().borders(Borders::ALL))
+        };
 
         f.render_widget(status_bar, area);
     }
@@ -624,6 +4737,9 @@ impl App {
             ("q", "Quit"),
             ("h", "Help"),
         ];
+        if !self.toasts.is_empty() {
+            bindings.push(("Ctrl+X", "Dismiss"));
+        }
 
         match self.mode {
             AppMode::Sprint => {
@@ -633,7 +4749,37 @@ impl App {
                     ("r", "Refresh"),
                     ("Tab", "Sprint Selector"),
                     ("B", "Board Selector"),
+                    ("S", "Board + Sprint"),
                     ("P", "Project Selector"),
+                    ("1-9", "Switch Board"),
+                    ("^", "Previous Board"),
+                    ("O", "Settings"),
+                    ("u", "Next Mine"),
+                    ("l", "Filter Label"),
+                    ("f", "Type to Filter"),
+                    ("Q", "Quick Filter"),
+                    ("d", "Show/Hide Done"),
+                    ("a", "Filter Assignee"),
+                    ("C", "Status Counts"),
+                    ("m", "Copy Markdown"),
+                    ("i", "Assign Me + Start"),
+                    ("v", "Peek"),
+                    ("/", "Search"),
+                    ("G", "Go to Issue"),
+                    ("!", "Blocked Issues"),
+                    ("W", "My Issues"),
+                    ("c", "Board Columns"),
+                    ("X", "Export CSV"),
+                    ("R", "Refresh Issue"),
+                    ("n", "New Issue"),
+                    ("#", "Story Points"),
+                    ("T", "Due Date"),
+                    ("D", "Delete Issue"),
+                    ("Space", "Mark/Fold"),
+                    ("g", "Group By"),
+                    ("zc/zo", "Fold/Unfold All"),
+                    ("Y", "Copy Standup"),
+                    ("F", "Flag"),
                     ("s", "Sprint"),
                     ("b", "Backlog"),
                 ]);
@@ -643,16 +4789,41 @@ impl App {
                     ("j/k", "Navigate"),
                     ("Enter", "Select Sprint"),
                     ("e", "Edit Sprint"),
+                    ("s", "Start Sprint"),
+                    ("C", "Close Sprint"),
+                    ("o", "Load Closed"),
                     ("Esc", "Back"),
                 ]);
             }
             AppMode::BoardSelector => {
                 bindings.extend_from_slice(&[
                     ("j/k", "Navigate"),
+                    ("Space", "Preview"),
+                    ("/", "Find"),
                     ("Enter", "Select Board"),
                     ("Esc", "Back"),
                 ]);
             }
+            AppMode::FindBoard => {
+                bindings.extend_from_slice(&[
+                    ("Enter/Esc", "Done"),
+                ]);
+            }
+            AppMode::FilterList => {
+                bindings.extend_from_slice(&[
+                    ("↑/↓", "Navigate"),
+                    ("Enter", "Keep Filter"),
+                    ("Esc", "Clear Filter"),
+                ]);
+            }
+            AppMode::BoardSprintSelector => {
+                bindings.extend_from_slice(&[
+                    ("j/k", "Navigate"),
+                    ("Tab/←/→", "Switch Column"),
+                    ("Enter", "Select Sprint"),
+                    ("Esc", "Back"),
+                ]);
+            }
             AppMode::ProjectSelector => {
                 bindings.extend_from_slice(&[
                     ("j/k", "Navigate"),
@@ -665,6 +4836,31 @@ impl App {
                     ("j/k", "Navigate"),
                     ("Enter", "View Issue"),
                     ("r", "Refresh"),
+                    ("u", "Next Mine"),
+                    ("l", "Filter Label"),
+                    ("f", "Type to Filter"),
+                    ("Q", "Quick Filter"),
+                    ("a", "Filter Assignee"),
+                    ("C", "Status Counts"),
+                    ("m", "Copy Markdown"),
+                    ("i", "Assign Me + Start"),
+                    ("v", "Peek"),
+                    ("/", "Search"),
+                    ("G", "Go to Issue"),
+                    ("!", "Blocked Issues"),
+                    ("W", "My Issues"),
+                    ("c", "Board Columns"),
+                    ("X", "Export CSV"),
+                    ("R", "Refresh Issue"),
+                    ("n", "New Issue"),
+                    ("p", "Key Prefix"),
+                    ("K/J", "Move Top/Bottom"),
+                    ("M", "Move to Sprint"),
+                    ("#", "Story Points"),
+                    ("T", "Due Date"),
+                    ("D", "Delete Issue"),
+                    ("Space", "Mark"),
+                    ("F", "Flag"),
                     ("s", "Sprint"),
                     ("b", "Backlog"),
                 ]);
@@ -674,27 +4870,93 @@ impl App {
                     bindings.extend_from_slice(&[
                         ("j/k", "Navigate"),
                         ("Enter", "Apply Transition"),
+                        ("c", "Apply with Comment"),
                         ("Esc", "Back"),
                     ]);
                 } else {
                     bindings.extend_from_slice(&[
+                        ("j/k", "Scroll"),
+                        ("</>", "Scroll Sideways"),
+                        ("+/-", "Resize Comments"),
+                        ("M", "Maximize"),
+                        ("z", "Wrap/Truncate"),
+                        ("m", "Copy Markdown"),
+                        ("i", "Assign Me + Start"),
                         ("c", "Comment"),
-                        ("e", "Edit"),
+                        ("e", "Edit Summary"),
+                        ("d", "Edit Description"),
+                        ("p", "Profile Card"),
+                        ("a", "Assign"),
+                        ("E", "Environment"),
                         ("t", "Transitions"),
+                        ("v", "Attachments"),
+                        ("F", "Flag"),
+                        ("#", "Story Points"),
+                        ("T", "Due Date"),
+                        ("w", "Log Work"),
+                        ("[/]", "Select Comment"),
+                        ("Tab", "Select Label"),
+                        ("Enter", "Search Label"),
+                        ("D", "Delete"),
                         ("Esc", "Back"),
                     ]);
                 }
             }
+            AppMode::TransitionComment => {
+                bindings.extend_from_slice(&[
+                    ("Ctrl+S", "Transition"),
+                    ("Enter", "New Line"),
+                    ("Esc", "Back to Transitions"),
+                ]);
+            }
+            AppMode::EditEstimate => {
+                bindings.extend_from_slice(&[
+                    ("Enter", "Save Estimate"),
+                    ("Esc", "Cancel"),
+                ]);
+            }
+            AppMode::EditDueDate => {
+                bindings.extend_from_slice(&[
+                    ("Enter", "Save Due Date"),
+                    ("Esc", "Cancel"),
+                ]);
+            }
+            AppMode::AddWorklog => {
+                bindings.extend_from_slice(&[
+                    ("Enter", "Log Work"),
+                    ("Esc", "Cancel"),
+                ]);
+            }
+            AppMode::EditComment => {
+                bindings.extend_from_slice(&[
+                    ("Ctrl+S", "Save Comment"),
+                    ("Enter", "New Line"),
+                    ("Esc", "Cancel"),
+                ]);
+            }
+            AppMode::PickResolution => {
+                bindings.extend_from_slice(&[
+                    ("Tab", "Complete"),
+                    ("Enter", "Resolve"),
+                    ("Esc", "Back to Transitions"),
+                ]);
+            }
             AppMode::AddComment => {
                 bindings.extend_from_slice(&[
-                    ("Enter", "Submit"),
+                    ("Ctrl+S", "Submit"),
+                    ("Enter", "New Line"),
                     ("Esc", "Cancel"),
-                    ("←/→", "Move Cursor"),
+                    ("←/→/↑/↓", "Move Cursor"),
                 ]);
+                if self.comment_internal.is_some() {
+                    bindings.push(("Tab", "Internal/Public"));
+                }
+                bindings.push(("Ctrl+R", "Restrict"));
             }
-            AppMode::EditIssue => {
+            AppMode::EditIssue | AppMode::EditEnvironment => {
+                let enter = if self.mode == AppMode::EditIssue { "Preview" } else { "Save" };
                 bindings.extend_from_slice(&[
-                    ("Enter", "Save"),
+                    ("Enter", enter),
                     ("Esc", "Cancel"),
                     ("←/→", "Move Cursor"),
                 ]);
@@ -706,6 +4968,167 @@ impl App {
                     ("←/→", "Move Cursor"),
                 ]);
             }
+            AppMode::DeleteIssue => {
+                bindings.extend_from_slice(&[
+                    ("Enter", "Confirm Delete"),
+                    ("Esc", "Cancel"),
+                ]);
+            }
+            AppMode::ConfirmDeleteSubtasks => {
+                bindings.extend_from_slice(&[
+                    ("y", "Delete With Sub-tasks"),
+                    ("n", "Cancel"),
+                ]);
+            }
+            AppMode::Search => {
+                bindings.extend_from_slice(&[
+                    ("Enter", "Search"),
+                    ("Tab", "Scope"),
+                    ("Esc", "Cancel"),
+                ]);
+            }
+            AppMode::SearchResults => {
+                bindings.extend_from_slice(&[
+                    ("j/k", "Navigate"),
+                    ("Enter", "View Issue"),
+                    ("/", "New Search"),
+                    ("G", "Go to Issue"),
+                    ("X", "Export CSV"),
+                    ("R", "Refresh Issue"),
+                    ("C", "Status Counts"),
+                    ("Esc", "Back"),
+                ]);
+            }
+            AppMode::Board => {
+                bindings.extend_from_slice(&[
+                    ("h/l", "Column"),
+                    ("j/k", "Navigate"),
+                    ("Enter", "View Issue"),
+                    ("r", "Refresh"),
+                    ("?", "Help"),
+                    ("Esc", "Back"),
+                ]);
+            }
+            AppMode::MyIssues => {
+                bindings.extend_from_slice(&[
+                    ("j/k", "Navigate"),
+                    ("Enter", "View Issue"),
+                    ("r", "Refresh"),
+                    ("G", "Go to Issue"),
+                    ("X", "Export CSV"),
+                    ("R", "Refresh Issue"),
+                    ("C", "Status Counts"),
+                    ("Esc", "Back"),
+                ]);
+            }
+            AppMode::Blocked => {
+                bindings.extend_from_slice(&[
+                    ("j/k", "Navigate"),
+                    ("Enter", "View Issue"),
+                    ("Tab", "Sprint/Board"),
+                    ("r", "Refresh"),
+                    ("Esc", "Back"),
+                ]);
+            }
+            AppMode::GoToIssue => {
+                bindings.extend_from_slice(&[
+                    ("Enter", "Open"),
+                    ("Tab", "Complete"),
+                    ("Esc", "Cancel"),
+                ]);
+            }
+            AppMode::ExportCsv => {
+                bindings.extend_from_slice(&[
+                    ("Enter", "Export"),
+                    ("Esc", "Cancel"),
+                ]);
+            }
+            AppMode::FilterLabel | AppMode::QuickFilter | AppMode::FilterAssignee => {
+                bindings.extend_from_slice(&[
+                    ("Enter", "Apply Filter"),
+                    ("Tab", "Complete"),
+                    ("Esc", "Cancel"),
+                ]);
+            }
+            AppMode::Settings => {
+                bindings.extend_from_slice(&[
+                    ("j/k", "Navigate"),
+                    ("Enter", "Edit"),
+                    ("←/→", "Change Theme"),
+                    ("s", "Save"),
+                    ("e", "Edit Config File"),
+                    ("Esc", "Discard"),
+                ]);
+            }
+            AppMode::EditSetting => {
+                bindings.extend_from_slice(&[
+                    ("Enter", "Apply"),
+                    ("Esc", "Cancel"),
+                    ("←/→", "Move Cursor"),
+                ]);
+            }
+            AppMode::QuickPeek => {
+                bindings.push(("any", "Close"));
+            }
+            AppMode::ConfirmBatch => {
+                bindings.extend_from_slice(&[
+                    ("y", "Apply"),
+                    ("n", "Cancel"),
+                ]);
+            }
+            AppMode::Confirm => {
+                bindings.extend_from_slice(&[
+                    ("y/Enter", "Confirm"),
+                    ("n/Esc", "Cancel"),
+                ]);
+            }
+            AppMode::UserCard => {
+                bindings.extend_from_slice(&[
+                    ("Tab", "Assignee/Reporter"),
+                    ("Any Key", "Close"),
+                ]);
+            }
+            AppMode::AssignUser => {
+                bindings.extend_from_slice(&[
+                    ("j/k", "Select"),
+                    ("Enter", "Assign"),
+                    ("Esc", "Cancel"),
+                ]);
+            }
+            AppMode::ConfirmEdit => {
+                bindings.extend_from_slice(&[
+                    ("y", "Save"),
+                    ("n", "Keep Editing"),
+                    ("c", "Discard"),
+                    ("j/k", "Scroll"),
+                ]);
+            }
+            AppMode::CreateIssue => {
+                bindings.extend_from_slice(&[
+                    ("Tab/Shift+Tab", "Next/Previous Field"),
+                    ("Enter", "Create"),
+                    ("Esc", "Cancel"),
+                ]);
+            }
+            AppMode::CloseSprint => {
+                bindings.extend_from_slice(&[
+                    ("←/→", "Destination"),
+                    ("Enter", "Move and Close"),
+                    ("Esc", "Cancel"),
+                ]);
+            }
+            AppMode::BatchSummary => {
+                bindings.extend_from_slice(&[
+                    ("r", "Retry Failed"),
+                    ("Enter/Esc", "Close"),
+                ]);
+            }
+            AppMode::AttachmentPreview => {
+                bindings.extend_from_slice(&[
+                    ("n/p", "Next/Prev Image"),
+                    ("Esc", "Back"),
+                ]);
+            }
             AppMode::Help => {
                 bindings.extend_from_slice(&[
                     ("Esc", "Close Help"),
@@ -717,6 +5140,18 @@ impl App {
     }
 }
 
+fn comment_title(internal: Option<bool>, visibility: Option<&crate::jira::CommentVisibility>) -> String {
+    let title = match internal {
+        Some(true) => "Add Comment [INTERNAL note] (Tab: make public)".to_string(),
+        Some(false) => "Add Comment [PUBLIC reply to customer] (Tab: make internal)".to_string(),
+        None => "Add Comment".to_string(),
+    };
+    match visibility {
+        Some(visibility) => format!("{} [only {}] (Ctrl+R: next)", title, visibility.label()),
+        None => format!("{} (Ctrl+R: restrict)", title),
+    }
+}
+
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -736,3 +5171,14 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         ])
         .split(popup_layout[1])[1]
 }
+
+/// Prefix a query with the scope clause; ORDER BY stays at the end, outside the parentheses
+/// Project key of an issue key shaped like `ABC-123`, `None` for anything else
+fn issue_key_project(key: &str) -> Option<&str> {
+    let (project, number) = key.rsplit_once('-')?;
+    let valid = project.starts_with(|c: char| c.is_ascii_alphabetic())
+        && project.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit());
+    valid.then_some(project)
+}
@@ -0,0 +1,13 @@
+use anyhow::Result;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::io::Write;
+
+/// Copy `text` to the system clipboard with the OSC 52 escape sequence. The
+/// terminal does the copying, so it also works over SSH; tmux needs
+/// `set -g set-clipboard on`.
+pub fn copy(text: &str) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+    stdout.flush()?;
+    Ok(())
+}
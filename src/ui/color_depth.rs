@@ -0,0 +1,124 @@
+use ratatui::{buffer::Buffer, style::Color};
+
+/// Values accepted by `ui.color_depth`
+pub const COLOR_DEPTHS: [&str; 4] = ["auto", "truecolor", "256", "16"];
+
+/// Standard xterm RGB values of the 16 ANSI colors, in ANSI index order
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// How many colors the terminal can show; frames are mapped down after drawing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorDepth {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+impl ColorDepth {
+    /// Parse a `ui.color_depth` value, detecting from the environment for "auto"
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "truecolor" => ColorDepth::TrueColor,
+            "256" => ColorDepth::Ansi256,
+            "16" => ColorDepth::Ansi16,
+            _ => Self::detect(),
+        }
+    }
+
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            ColorDepth::TrueColor
+        } else if std::env::var("TERM").is_ok_and(|term| term.contains("256color")) {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+
+    /// Rewrite every cell of a rendered frame to colors this depth can show
+    pub fn adapt(self, buffer: &mut Buffer) {
+        if self == ColorDepth::TrueColor {
+            return;
+        }
+        for cell in buffer.content.iter_mut() {
+            cell.fg = self.map(cell.fg, false);
+            cell.bg = self.map(cell.bg, true);
+        }
+    }
+
+    fn map(self, color: Color, background: bool) -> Color {
+        match (self, color) {
+            (ColorDepth::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_256(r, g, b)),
+            (ColorDepth::Ansi16, Color::Rgb(r, g, b)) => nearest_ansi16((r, g, b), background),
+            (ColorDepth::Ansi16, Color::Indexed(i)) => nearest_ansi16(indexed_to_rgb(i), background),
+            // Basic terminals (and the Linux console) only have 8 background colors
+            (ColorDepth::Ansi16, named) if background => dim(named),
+            (_, other) => other,
+        }
+    }
+}
+
+fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| ((c as u16 * 5 + 127) / 255) as u8;
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16[index as usize].1,
+        16..=231 => {
+            let i = index - 16;
+            let level = |c: u8| if c == 0 { 0 } else { 55 + c * 40 };
+            (level(i / 36), level((i / 6) % 6), level(i % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+fn nearest_ansi16((r, g, b): (u8, u8, u8), background: bool) -> Color {
+    // Backgrounds are limited to the 8 non-bright colors
+    let candidates = if background { &ANSI16[..8] } else { &ANSI16[..] };
+    candidates
+        .iter()
+        .min_by_key(|(_, (cr, cg, cb))| {
+            let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+            d(r, *cr) + d(g, *cg) + d(b, *cb)
+        })
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+fn dim(color: Color) -> Color {
+    match color {
+        Color::DarkGray => Color::Black,
+        Color::LightRed => Color::Red,
+        Color::LightGreen => Color::Green,
+        Color::LightYellow => Color::Yellow,
+        Color::LightBlue => Color::Blue,
+        Color::LightMagenta => Color::Magenta,
+        Color::LightCyan => Color::Cyan,
+        Color::White => Color::Gray,
+        other => other,
+    }
+}
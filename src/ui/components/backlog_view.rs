@@ -1,14 +1,51 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
-    widgets::{Block, Borders, List, ListItem, ListState},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{block::{Position, Title}, Block, Borders, List, ListItem, ListState},
     Frame,
 };
 use crate::jira::Issue;
+use crate::ui::fuzzy::Matcher;
+use crate::ui::glyphs::Glyphs;
+use crate::ui::icons::IssueTypeIcons;
+use crate::ui::theme::Theme;
+use crate::ui::{due_date, status_counts};
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// How long issues that changed since the last look stay highlighted
+const CHANGE_HIGHLIGHT: Duration = Duration::from_secs(5);
 
 pub struct BacklogView {
     pub issues: Vec<Issue>,
     pub state: ListState,
+    pub label_filter: Option<String>,
+    /// Name of the board quick filter the issues were fetched with
+    pub quick_filter: Option<String>,
+    /// Display name of the user the issues were narrowed to
+    pub assignee_filter: Option<String>,
+    /// Text typed with `t`, matched against key, summary and assignee
+    pub text_filter: String,
+    pub matcher: Matcher,
+    /// Showing all issues of a kanban board rather than a backlog
+    pub kanban: bool,
+    /// Custom field checked for the flagged marker
+    pub flag_field: String,
+    pub type_icons: IssueTypeIcons,
+    pub glyphs: Glyphs,
+    /// Keys marked with Space for bulk actions
+    pub marked: HashSet<String>,
+    /// Only show issues whose key starts with this prefix, e.g. "ABC-"
+    pub key_prefix: Option<String>,
+    /// Issues updated since the list was last loaded, highlighted until `highlight_until`
+    pub changed_keys: HashSet<String>,
+    pub highlight_until: Option<Instant>,
+    /// When the backlog was last loaded
+    pub last_seen: Option<DateTime<Utc>>,
+    /// Count the loaded issues per status category along the bottom border
+    pub show_status_counts: bool,
 }
 
 impl BacklogView {
@@ -16,21 +53,116 @@ impl BacklogView {
         Self {
             issues: Vec::new(),
             state: ListState::default(),
+            label_filter: None,
+            quick_filter: None,
+            assignee_filter: None,
+            text_filter: String::new(),
+            matcher: Matcher::default(),
+            kanban: false,
+            flag_field: String::new(),
+            type_icons: IssueTypeIcons::default(),
+            glyphs: Glyphs::default(),
+            marked: HashSet::new(),
+            key_prefix: None,
+            changed_keys: HashSet::new(),
+            highlight_until: None,
+            last_seen: None,
+            show_status_counts: false,
         }
     }
 
-    pub fn set_issues(&mut self, mut issues: Vec<Issue>) {
-        issues.sort_by(|a, b| b.key.cmp(&a.key));
+    /// Issues arrive in rank order, which is kept so ranking changes are visible
+    pub fn set_issues(&mut self, issues: Vec<Issue>) {
+        if let Some(seen) = self.last_seen.replace(Utc::now()) {
+            self.changed_keys = issues
+                .iter()
+                .filter(|i| i.fields.updated.is_some_and(|updated| updated > seen))
+                .map(|i| i.key.clone())
+                .collect();
+            self.highlight_until = Some(Instant::now() + CHANGE_HIGHLIGHT);
+        } else {
+            self.changed_keys.clear();
+        }
+
+        // Marks survive a refresh as long as the issue is still listed
+        self.marked.retain(|key| issues.iter().any(|i| &i.key == key));
         self.issues = issues;
-        if !self.issues.is_empty() {
-            self.state.select(Some(0));
+        let visible = self.visible_issues().len();
+        self.state.select(if visible == 0 { None } else { Some(0) });
+    }
+
+    fn is_highlighted(&self, key: &str) -> bool {
+        self.highlight_until.is_some_and(|until| Instant::now() < until) && self.changed_keys.contains(key)
+    }
+
+    pub fn visible_issues(&self) -> Vec<&Issue> {
+        self.issues
+            .iter()
+            .filter(|issue| match &self.label_filter {
+                Some(label) => issue.fields.labels.as_ref().is_some_and(|l| l.contains(label)),
+                None => true,
+            })
+            .filter(|issue| match &self.key_prefix {
+                Some(prefix) => issue.key.starts_with(prefix.as_str()),
+                None => true,
+            })
+            .filter(|issue| self.matches_text_filter(issue))
+            .collect()
+    }
+
+    fn matches_text_filter(&self, issue: &Issue) -> bool {
+        let assignee = issue.fields.assignee.as_ref().map_or("", |u| u.display_name.as_str());
+        let text = format!("{} {} {}", issue.key, issue.fields.summary, assignee);
+        self.matcher.is_match(&text, &self.text_filter)
+    }
+
+    /// Narrow the list to issues matching `filter`, keeping the selected issue
+    /// selected while it still matches
+    pub fn set_text_filter(&mut self, filter: String) {
+        let selected = self.selected_issue().map(|i| i.key.clone());
+        self.text_filter = filter;
+        let visible = self.visible_issues().len();
+        self.state.select(if visible == 0 { None } else { Some(0) });
+        if let Some(key) = selected {
+            self.select_key(&key);
         }
     }
 
+    /// Distinct labels across all loaded issues, used for filter autocompletion
+    pub fn labels(&self) -> Vec<String> {
+        let mut labels: Vec<String> = self
+            .issues
+            .iter()
+            .flat_map(|issue| issue.fields.labels.iter().flatten().cloned())
+            .collect();
+        labels.sort();
+        labels.dedup();
+        labels
+    }
+
+    /// Set the key prefix filter; a bare project key like "ABC" becomes "ABC-"
+    pub fn set_key_prefix(&mut self, prefix: Option<String>) {
+        self.key_prefix = prefix.map(|p| {
+            if p.ends_with('-') { p } else { format!("{}-", p) }
+        });
+        let visible = self.visible_issues().len();
+        self.state.select(if visible == 0 { None } else { Some(0) });
+    }
+
+    pub fn set_label_filter(&mut self, label: Option<String>) {
+        self.label_filter = label;
+        let visible = self.visible_issues().len();
+        self.state.select(if visible == 0 { None } else { Some(0) });
+    }
+
     pub fn next(&mut self) {
+        let len = self.visible_issues().len();
+        if len == 0 {
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
-                if i >= self.issues.len() - 1 {
+                if i >= len - 1 {
                     0
                 } else {
                     i + 1
@@ -42,10 +174,14 @@ impl BacklogView {
     }
 
     pub fn previous(&mut self) {
+        let len = self.visible_issues().len();
+        if len == 0 {
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.issues.len() - 1
+                    len - 1
                 } else {
                     i - 1
                 }
@@ -55,24 +191,94 @@ impl BacklogView {
         self.state.select(Some(i));
     }
 
+    /// Move the selection to the next visible issue assigned to `account_id`,
+    /// wrapping around. Returns false if there is no such issue.
+    pub fn select_next_assigned_to(&mut self, account_id: &str) -> bool {
+        let visible = self.visible_issues();
+        let len = visible.len();
+        let start = self.state.selected().map_or(0, |i| i + 1);
+        let next = (0..len).map(|offset| (start + offset) % len).find(|&i| {
+            visible[i].fields.assignee.as_ref().is_some_and(|u| u.account_id == account_id)
+        });
+        if let Some(i) = next {
+            self.state.select(Some(i));
+        }
+        next.is_some()
+    }
+
+    /// Move `key` to the top or bottom of the local list and keep it selected
+    pub fn move_issue(&mut self, key: &str, to_top: bool) {
+        if let Some(index) = self.issues.iter().position(|i| i.key == key) {
+            let issue = self.issues.remove(index);
+            if to_top {
+                self.issues.insert(0, issue);
+            } else {
+                self.issues.push(issue);
+            }
+        }
+        self.select_key(key);
+    }
+
+    /// Drop `key` from the local list, keeping the cursor on the same row
+    pub fn remove_issue(&mut self, key: &str) {
+        self.issues.retain(|i| i.key != key);
+        self.marked.remove(key);
+        let visible = self.visible_issues().len();
+        let row = self.state.selected().unwrap_or(0).min(visible.saturating_sub(1));
+        self.state.select((visible > 0).then_some(row));
+    }
+
+    pub fn select_key(&mut self, key: &str) {
+        if let Some(index) = self.visible_issues().iter().position(|i| i.key == key) {
+            self.state.select(Some(index));
+        }
+    }
+
+    /// Mark or unmark the selected issue and move to the next one
+    pub fn toggle_mark(&mut self) {
+        if let Some(key) = self.selected_issue().map(|i| i.key.clone()) {
+            if !self.marked.remove(&key) {
+                self.marked.insert(key);
+            }
+            self.next();
+        }
+    }
+
+    /// Marked issues in list order
+    pub fn marked_issues(&self) -> Vec<Issue> {
+        self.issues
+            .iter()
+            .filter(|i| self.marked.contains(&i.key))
+            .cloned()
+            .collect()
+    }
+
     pub fn selected_issue(&self) -> Option<&Issue> {
-        self.state.selected().and_then(|i| self.issues.get(i))
+        self.state.selected().and_then(|i| self.visible_issues().get(i).copied())
     }
 
-    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+    pub fn render(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let today = due_date::today();
         let items: Vec<ListItem> = self
-            .issues
-            .iter()
+            .visible_issues()
+            .into_iter()
             .map(|issue| {
-                let priority_color = match issue.fields.priority.as_ref().map(|p| p.name.as_str()) {
-                    Some("Highest") | Some("High") => Color::Red,
-                    Some("Medium") => Color::Yellow,
-                    Some("Low") | Some("Lowest") => Color::Green,
-                    _ => Color::White,
+                let priority_color = theme.priority_color(issue.fields.priority.as_ref().map(|p| p.name.as_str()));
+
+                let changed = self.is_highlighted(&issue.key);
+                let marker = if changed { "● " } else { "" };
+                let flag = if issue.is_flagged(&self.flag_field) {
+                    format!("{} ", self.glyphs.flag())
+                } else {
+                    String::new()
                 };
+                let mark = if self.marked.contains(&issue.key) { "[x] " } else { "" };
 
                 let content = format!(
-                    "{} [{}] {} - {}",
+                    "{}{}{}{} [{}] {} - {}",
+                    mark,
+                    marker,
+                    flag,
                     issue.key,
                     issue.fields.priority
                         .as_ref()
@@ -85,15 +291,53 @@ impl BacklogView {
                         .unwrap_or("Unassigned")
                 );
 
-                ListItem::new(content).style(Style::default().fg(priority_color))
+                let style = if changed {
+                    Style::default().fg(theme.marked).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(priority_color)
+                };
+                let mut spans = vec![
+                    self.type_icons.span(&issue.fields.issuetype.name, theme),
+                    Span::raw(content),
+                ];
+                spans.extend(due_date::marker(issue, today, self.glyphs, theme));
+                ListItem::new(Line::from(spans)).style(style)
             })
             .collect();
 
+        let mut title = format!(
+            "{}: {}",
+            if self.kanban { "Board" } else { "Backlog" },
+            status_counts::issue_count(self.issues.len())
+        );
+        if let Some(prefix) = &self.key_prefix {
+            title.push_str(&format!(" [prefix: {}]", prefix));
+        }
+        if let Some(filter) = &self.quick_filter {
+            title.push_str(&format!(" [quick filter: {}]", filter));
+        }
+        if let Some(assignee) = &self.assignee_filter {
+            title.push_str(&format!(" [assignee: {}]", assignee));
+        }
+        if let Some(label) = &self.label_filter {
+            title.push_str(&format!(" [label: {}]", label));
+        }
+        if !self.text_filter.is_empty() {
+            title.push_str(&format!(" [filter: {}]", self.text_filter));
+        }
+        if !self.marked.is_empty() {
+            title.push_str(&format!(" [{} marked]", self.marked.len()));
+        }
+
+        let mut block = Block::default().borders(Borders::ALL).title(title);
+        if self.show_status_counts {
+            block = block.title(Title::from(status_counts::footer(&self.issues, theme)).position(Position::Bottom));
+        }
         let backlog_list = List::new(items)
-            .block(Block::default().borders(Borders::ALL).title("Backlog"))
+            .block(block)
             .highlight_style(
                 Style::default()
-                    .bg(Color::LightBlue)
+                    .bg(theme.highlight)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol(">> ");
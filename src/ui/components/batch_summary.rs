@@ -0,0 +1,75 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use crate::ui::theme::Theme;
+
+/// Per-issue outcome of a bulk action, shown once the whole batch has run
+pub struct BatchSummary {
+    pub title: String,
+    pub succeeded: Vec<String>,
+    /// Issue key and the error it failed with
+    pub failed: Vec<(String, String)>,
+}
+
+impl BatchSummary {
+    pub fn new(title: String) -> Self {
+        Self {
+            title,
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let mut lines = vec![Line::from(vec![
+            Span::styled(
+                format!("{} succeeded", self.succeeded.len()),
+                Style::default().fg(theme.success).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(", "),
+            Span::styled(
+                format!("{} failed", self.failed.len()),
+                Style::default()
+                    .fg(if self.failed.is_empty() { theme.muted } else { theme.error })
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ])];
+
+        if !self.failed.is_empty() {
+            lines.push(Line::from(""));
+            for (key, error) in &self.failed {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{}: ", key), Style::default().fg(theme.accent)),
+                    Span::raw(error.as_str()),
+                ]));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "r: retry failed  Enter/Esc: close",
+                Style::default().fg(theme.hint),
+            )));
+        } else {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Enter/Esc: close",
+                Style::default().fg(theme.hint),
+            )));
+        }
+
+        let summary = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.title.as_str())
+                    .border_style(Style::default().fg(theme.accent)),
+            )
+            .style(Style::default().fg(theme.text).bg(theme.popup))
+            .wrap(Wrap { trim: true });
+        f.render_widget(Clear, area);
+        f.render_widget(summary, area);
+    }
+}
@@ -0,0 +1,128 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use crate::jira::{Issue, LinkedIssue};
+use crate::ui::theme::Theme;
+
+/// Open issues that are blocked by another still-open issue, with the
+/// blockers' statuses, for unblocking work at standup
+pub struct BlockedView {
+    /// Blocked issue and its open blockers
+    pub rows: Vec<(Issue, Vec<LinkedIssue>)>,
+    pub state: ListState,
+    /// What was scanned, e.g. a sprint name or "Board"
+    pub scope: String,
+}
+
+impl BlockedView {
+    pub fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            state: ListState::default(),
+            scope: String::new(),
+        }
+    }
+
+    /// Keep the issues of `issues` with open blockers, staying on the
+    /// selected issue across refreshes
+    pub fn set_issues(&mut self, issues: &[Issue], scope: String) {
+        let selected_key = self.selected_issue().map(|issue| issue.key.clone());
+        self.rows = issues
+            .iter()
+            .filter(|issue| !issue.is_done())
+            .filter_map(|issue| {
+                let blockers: Vec<LinkedIssue> = issue.open_blockers().into_iter().cloned().collect();
+                (!blockers.is_empty()).then(|| (issue.clone(), blockers))
+            })
+            .collect();
+        self.scope = scope;
+        let index = selected_key
+            .and_then(|key| self.rows.iter().position(|(issue, _)| issue.key == key))
+            .unwrap_or(0);
+        self.state.select((!self.rows.is_empty()).then_some(index));
+    }
+
+    /// Drop `key` from the list, keeping the cursor on the same row
+    pub fn remove_issue(&mut self, key: &str) {
+        self.rows.retain(|(issue, _)| issue.key != key);
+        let row = self.state.selected().unwrap_or(0).min(self.rows.len().saturating_sub(1));
+        self.state.select((!self.rows.is_empty()).then_some(row));
+    }
+
+    pub fn next(&mut self) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let i = self.state.selected().map_or(0, |i| (i + 1) % self.rows.len());
+        self.state.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map_or(0, |i| (i + self.rows.len() - 1) % self.rows.len());
+        self.state.select(Some(i));
+    }
+
+    pub fn selected_issue(&self) -> Option<&Issue> {
+        self.state.selected().and_then(|i| self.rows.get(i)).map(|(issue, _)| issue)
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let title = format!("Blocked Issues: {} ({})", self.scope, self.rows.len());
+        if self.rows.is_empty() {
+            let nothing = Paragraph::new("Nothing is blocked by an open issue")
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .style(Style::default().fg(theme.success));
+            f.render_widget(nothing, area);
+            return;
+        }
+
+        let dim = Style::default().fg(theme.hint);
+        let items: Vec<ListItem> = self
+            .rows
+            .iter()
+            .map(|(issue, blockers)| {
+                let mut lines = vec![Line::from(vec![
+                    Span::styled(format!("{} ", issue.key), Style::default().fg(theme.border)),
+                    Span::styled(
+                        format!("[{}] ", issue.fields.status.name),
+                        Style::default().fg(theme.status_color(&issue.fields.status)),
+                    ),
+                    Span::raw(issue.fields.summary.clone()),
+                ])];
+                lines.extend(blockers.iter().map(|blocker| {
+                    Line::from(vec![
+                        Span::styled("    blocked by ", dim),
+                        Span::styled(format!("{} ", blocker.key), Style::default().fg(theme.error)),
+                        Span::styled(
+                            format!("[{}] ", blocker.fields.status.name),
+                            Style::default().fg(theme.status_color(&blocker.fields.status)),
+                        ),
+                        Span::styled(blocker.fields.summary.clone(), dim),
+                    ])
+                }));
+                ListItem::new(lines)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_style(
+                Style::default()
+                    .bg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+
+        f.render_stateful_widget(list, area, &mut self.state);
+    }
+}
@@ -1,15 +1,23 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
 use crate::jira::Board;
+use crate::ui::fuzzy::{self, Matcher};
+use crate::ui::glyphs::Glyphs;
+use crate::ui::theme::Theme;
+use std::collections::HashMap;
 
 pub struct BoardSelector {
     pub boards: Vec<Board>,
     pub state: ListState,
     pub is_active: bool,
+    /// Matched char indices of each board name (by id) while finding with `/`
+    pub found: HashMap<u32, Vec<usize>>,
+    pub glyphs: Glyphs,
 }
 
 impl BoardSelector {
@@ -18,6 +26,8 @@ impl BoardSelector {
             boards: Vec::new(),
             state: ListState::default(),
             is_active: false,
+            found: HashMap::new(),
+            glyphs: Glyphs::default(),
         }
     }
 
@@ -82,11 +92,29 @@ impl BoardSelector {
         self.selected_board().map(|b| b.id)
     }
 
-    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+    /// Highlight the boards whose name matches `query` and select the best one;
+    /// returns how many matched
+    pub fn find(&mut self, query: &str, matcher: Matcher) -> usize {
+        self.found.clear();
+        if query.trim().is_empty() {
+            return 0;
+        }
+        let names: Vec<String> = self.boards.iter().map(|b| b.name.clone()).collect();
+        let ranked = matcher.rank(&names, query.trim());
+        if let Some((best, _)) = ranked.first() {
+            self.state.select(Some(*best));
+        }
+        for (i, indices) in &ranked {
+            self.found.insert(self.boards[*i].id, indices.clone());
+        }
+        ranked.len()
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
         if self.boards.is_empty() {
             let no_boards = Paragraph::new("No boards available")
                 .block(Block::default().borders(Borders::ALL).title("Board Selector"))
-                .style(Style::default().fg(Color::Gray));
+                .style(Style::default().fg(theme.muted));
             f.render_widget(no_boards, area);
             return;
         }
@@ -95,20 +123,15 @@ impl BoardSelector {
             .boards
             .iter()
             .enumerate()
-            .map(|(_i, board)| {
+            .map(|(i, board)| {
                 let board_type_color = match board.board_type.as_str() {
-                    "scrum" => Color::Green,
-                    "kanban" => Color::Blue,
-                    "simple" => Color::Yellow,
-                    _ => Color::White,
+                    "scrum" => theme.success,
+                    "kanban" => theme.info,
+                    "simple" => theme.accent,
+                    _ => theme.text,
                 };
 
-                let type_symbol = match board.board_type.as_str() {
-                    "scrum" => "🏃",
-                    "kanban" => "📋",
-                    "simple" => "📝",
-                    _ => "📊",
-                };
+                let type_symbol = self.glyphs.board_type(&board.board_type);
 
                 let project_info = if let Some(location) = &board.location {
                     if let Some(project_key) = &location.project_key {
@@ -120,15 +143,25 @@ impl BoardSelector {
                     String::new()
                 };
 
-                let content = format!(
-                    "{} {} [{}]{}",
-                    type_symbol,
-                    board.name,
-                    board.board_type.to_uppercase(),
-                    project_info
-                );
+                // The first nine boards can be switched to directly with their digit
+                let shortcut = if i < 9 {
+                    format!("{}.", i + 1)
+                } else {
+                    "  ".to_string()
+                };
+
+                let style = Style::default().fg(board_type_color);
+                let mut spans = vec![Span::styled(format!("{} {} ", shortcut, type_symbol), style)];
+                match self.found.get(&board.id) {
+                    Some(indices) => spans.extend(fuzzy::highlight(&board.name, indices, style)),
+                    None => spans.push(Span::styled(board.name.clone(), style)),
+                }
+                spans.push(Span::styled(
+                    format!(" [{}]{}", board.board_type.to_uppercase(), project_info),
+                    style,
+                ));
 
-                ListItem::new(content).style(Style::default().fg(board_type_color))
+                ListItem::new(Line::from(spans))
             })
             .collect();
 
@@ -139,16 +172,16 @@ impl BoardSelector {
         };
 
         let border_style = if self.is_active {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(theme.accent)
         } else {
-            Style::default().fg(Color::White)
+            Style::default().fg(theme.text)
         };
 
         let boards_list = List::new(items)
             .block(Block::default().borders(Borders::ALL).title(title).border_style(border_style))
             .highlight_style(
                 Style::default()
-                    .bg(Color::LightBlue)
+                    .bg(theme.highlight)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol(">> ");
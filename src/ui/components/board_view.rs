@@ -0,0 +1,219 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use crate::jira::{BoardColumn, Issue};
+use crate::ui::icons::IssueTypeIcons;
+use crate::ui::theme::Theme;
+
+/// Columns used when the board configuration can't be read, by status category
+const CATEGORY_COLUMNS: [(&str, &str); 3] = [
+    ("new", "To Do"),
+    ("indeterminate", "In Progress"),
+    ("done", "Done"),
+];
+
+/// One column of the board and the issues in it
+pub struct Column {
+    /// Name and WIP limit, highlighted in the title once exceeded
+    pub config: BoardColumn,
+    pub issues: Vec<Issue>,
+    pub state: ListState,
+}
+
+/// The sprint or board issues laid out in the board's columns, side by side
+pub struct BoardView {
+    pub type_icons: IssueTypeIcons,
+    pub columns: Vec<Column>,
+    /// Index of the focused column
+    pub focus: usize,
+    /// What is shown, e.g. a sprint name or "Board"
+    pub scope: String,
+}
+
+impl BoardView {
+    pub fn new() -> Self {
+        Self {
+            type_icons: IssueTypeIcons::default(),
+            columns: Vec::new(),
+            focus: 0,
+            scope: String::new(),
+        }
+    }
+
+    /// Sort `issues` into the board's `columns` by status, or into To Do /
+    /// In Progress / Done by status category when there are none. Issues whose
+    /// status has no column are left out, as on the board in Jira. The
+    /// selected issue stays selected across refreshes.
+    pub fn set_issues(&mut self, issues: Vec<Issue>, columns: &[BoardColumn], scope: String) {
+        let selected_key = self.selected_issue().map(|issue| issue.key.clone());
+        self.columns = if columns.is_empty() {
+            CATEGORY_COLUMNS
+                .iter()
+                .map(|(category, name)| Column {
+                    config: BoardColumn {
+                        name: name.to_string(),
+                        statuses: Vec::new(),
+                        min: None,
+                        max: None,
+                    },
+                    issues: issues
+                        .iter()
+                        .filter(|issue| issue.fields.status.status_category.key == *category)
+                        .cloned()
+                        .collect(),
+                    state: ListState::default(),
+                })
+                .collect()
+        } else {
+            columns
+                .iter()
+                .map(|column| Column {
+                    config: column.clone(),
+                    issues: issues
+                        .iter()
+                        .filter(|issue| column.contains(&issue.fields.status.id))
+                        .cloned()
+                        .collect(),
+                    state: ListState::default(),
+                })
+                .collect()
+        };
+        self.scope = scope;
+
+        let found = selected_key.and_then(|key| {
+            self.columns.iter().enumerate().find_map(|(c, column)| {
+                column.issues.iter().position(|issue| issue.key == key).map(|row| (c, row))
+            })
+        });
+        for column in &mut self.columns {
+            column.state.select((!column.issues.is_empty()).then_some(0));
+        }
+        match found {
+            Some((c, row)) => {
+                self.focus = c;
+                self.columns[c].state.select(Some(row));
+            }
+            None => self.focus = self.focus.min(self.columns.len().saturating_sub(1)),
+        }
+    }
+
+    /// Drop `key` from whichever column holds it, keeping that column's cursor
+    /// on the same row
+    pub fn remove_issue(&mut self, key: &str) {
+        for column in &mut self.columns {
+            if let Some(index) = column.issues.iter().position(|issue| issue.key == key) {
+                column.issues.remove(index);
+                let row = column.state.selected().unwrap_or(0).min(column.issues.len().saturating_sub(1));
+                column.state.select((!column.issues.is_empty()).then_some(row));
+            }
+        }
+    }
+
+    pub fn next_column(&mut self) {
+        if !self.columns.is_empty() {
+            self.focus = (self.focus + 1) % self.columns.len();
+        }
+    }
+
+    pub fn previous_column(&mut self) {
+        if !self.columns.is_empty() {
+            self.focus = (self.focus + self.columns.len() - 1) % self.columns.len();
+        }
+    }
+
+    pub fn next(&mut self) {
+        let Some(column) = self.columns.get_mut(self.focus).filter(|c| !c.issues.is_empty()) else {
+            return;
+        };
+        let i = column.state.selected().map_or(0, |i| (i + 1) % column.issues.len());
+        column.state.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        let Some(column) = self.columns.get_mut(self.focus).filter(|c| !c.issues.is_empty()) else {
+            return;
+        };
+        let len = column.issues.len();
+        let i = column.state.selected().map_or(0, |i| (i + len - 1) % len);
+        column.state.select(Some(i));
+    }
+
+    pub fn selected_issue(&self) -> Option<&Issue> {
+        let column = self.columns.get(self.focus)?;
+        column.state.selected().and_then(|i| column.issues.get(i))
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        if self.columns.is_empty() {
+            let nothing = Paragraph::new("The board has no columns")
+                .block(Block::default().borders(Borders::ALL).title(format!("Board: {}", self.scope)));
+            f.render_widget(nothing, area);
+            return;
+        }
+
+        let count = self.columns.len() as u32;
+        let areas = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, count); self.columns.len()])
+            .split(area);
+
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            let focused = i == self.focus;
+            let over_limit = column.config.exceeded_limit(column.issues.len()).is_some();
+            let title = match column.config.max {
+                Some(max) => format!("{} ({}/{})", column.config.name, column.issues.len(), max),
+                None => format!("{} ({})", column.config.name, column.issues.len()),
+            };
+            let title_style = if over_limit {
+                Style::default().fg(theme.error).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let border_style = if focused {
+                Style::default().fg(theme.border)
+            } else {
+                Style::default().fg(theme.hint)
+            };
+
+            let items: Vec<ListItem> = column
+                .issues
+                .iter()
+                .map(|issue| {
+                    let assignee = issue
+                        .fields
+                        .assignee
+                        .as_ref()
+                        .map_or("Unassigned", |u| u.display_name.as_str());
+                    ListItem::new(vec![
+                        Line::from(vec![
+                            self.type_icons.span(&issue.fields.issuetype.name, theme),
+                            Span::styled(issue.key.clone(), Style::default().add_modifier(Modifier::BOLD)),
+                            Span::styled(format!(" {}", assignee), Style::default().fg(theme.muted)),
+                        ]),
+                        Line::from(issue.fields.summary.clone()),
+                    ])
+                })
+                .collect();
+
+            let mut list = List::new(items).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(Span::styled(title, title_style))
+                    .border_style(border_style),
+            );
+            // Only the focused column shows its selection
+            if focused {
+                list = list.highlight_style(
+                    Style::default()
+                        .bg(theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                );
+            }
+            f.render_stateful_widget(list, areas[i], &mut column.state);
+        }
+    }
+}
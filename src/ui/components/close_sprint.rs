@@ -0,0 +1,144 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+use crate::jira::{Issue, Sprint};
+use crate::ui::theme::Theme;
+
+/// Where the incomplete issues of a closing sprint go
+#[derive(Debug, Clone, PartialEq)]
+pub enum MoveTarget {
+    Sprint { id: u32, name: String },
+    Backlog,
+}
+
+impl MoveTarget {
+    pub fn label(&self) -> &str {
+        match self {
+            MoveTarget::Sprint { name, .. } => name,
+            MoveTarget::Backlog => "Backlog",
+        }
+    }
+}
+
+/// Close-sprint dialog: lists the sprint's incomplete issues and picks where
+/// they move before the sprint is closed, like the web UI's "Complete sprint"
+pub struct CloseSprintView {
+    pub sprint: Option<Sprint>,
+    pub incomplete: Vec<Issue>,
+    pub done_count: usize,
+    pub targets: Vec<MoveTarget>,
+    pub selected_target: usize,
+}
+
+impl CloseSprintView {
+    pub fn new() -> Self {
+        Self {
+            sprint: None,
+            incomplete: Vec::new(),
+            done_count: 0,
+            targets: vec![MoveTarget::Backlog],
+            selected_target: 0,
+        }
+    }
+
+    /// Start closing `sprint`; future sprints are offered in order, with the
+    /// nearest one preselected and the backlog last
+    pub fn open(&mut self, sprint: Sprint, issues: Vec<Issue>, future_sprints: &[Sprint]) {
+        let (done, incomplete): (Vec<Issue>, Vec<Issue>) =
+            issues.into_iter().partition(|issue| issue.is_done());
+        self.done_count = done.len();
+        self.incomplete = incomplete;
+
+        let mut future: Vec<&Sprint> = future_sprints
+            .iter()
+            .filter(|s| s.state == "future" && s.id != sprint.id)
+            .collect();
+        future.sort_by_key(|s| (s.start_date, s.id));
+        self.targets = future
+            .into_iter()
+            .map(|s| MoveTarget::Sprint { id: s.id, name: s.name.clone() })
+            .chain(std::iter::once(MoveTarget::Backlog))
+            .collect();
+        self.selected_target = 0;
+        self.sprint = Some(sprint);
+    }
+
+    pub fn next_target(&mut self) {
+        self.selected_target = (self.selected_target + 1) % self.targets.len();
+    }
+
+    pub fn previous_target(&mut self) {
+        self.selected_target = (self.selected_target + self.targets.len() - 1) % self.targets.len();
+    }
+
+    pub fn target(&self) -> MoveTarget {
+        self.targets[self.selected_target].clone()
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let sprint_name = self.sprint.as_ref().map(|s| s.name.as_str()).unwrap_or("");
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Close Sprint: {}", sprint_name))
+            .border_style(Style::default().fg(theme.accent));
+        let inner = block.inner(area);
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(1), Constraint::Length(3)])
+            .split(inner);
+
+        let counts = Paragraph::new(format!(
+            "{} completed, {} incomplete",
+            self.done_count,
+            self.incomplete.len()
+        ))
+        .style(Style::default().fg(theme.muted));
+        f.render_widget(counts, chunks[0]);
+
+        let items: Vec<ListItem> = if self.incomplete.is_empty() {
+            vec![ListItem::new("All issues are done").style(Style::default().fg(theme.success))]
+        } else {
+            self.incomplete
+                .iter()
+                .map(|issue| {
+                    ListItem::new(Line::from(vec![
+                        Span::styled(format!("{} ", issue.key), Style::default().fg(theme.border)),
+                        Span::styled(
+                            format!("[{}] ", issue.fields.status.name),
+                            Style::default().fg(theme.status_color(&issue.fields.status)),
+                        ),
+                        Span::styled(issue.fields.summary.as_str(), Style::default().fg(theme.text)),
+                    ]))
+                })
+                .collect()
+        };
+        f.render_widget(List::new(items), chunks[1]);
+
+        let mut footer = Vec::new();
+        if !self.incomplete.is_empty() {
+            footer.push(Line::from(vec![
+                Span::raw("Move incomplete issues to: "),
+                Span::styled(
+                    format!("◀ {} ▶", self.target().label()),
+                    Style::default().fg(theme.success).add_modifier(Modifier::BOLD),
+                ),
+            ]));
+        }
+        footer.push(Line::from(Span::styled(
+            if self.incomplete.is_empty() {
+                "Enter: close sprint  Esc: cancel"
+            } else {
+                "←/→/Tab: change destination  Enter: move and close  Esc: cancel"
+            },
+            Style::default().fg(theme.hint),
+        )));
+        f.render_widget(Paragraph::new(footer), chunks[2]);
+    }
+}
@@ -0,0 +1,46 @@
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use crate::ui::theme::Theme;
+
+/// Yes/no question asked before an action that can't be undone
+pub struct ConfirmDialog {
+    pub title: String,
+    pub message: String,
+    /// Irreversible actions such as deletes get a red border
+    pub destructive: bool,
+}
+
+impl ConfirmDialog {
+    pub fn new() -> Self {
+        Self {
+            title: String::new(),
+            message: String::new(),
+            destructive: false,
+        }
+    }
+
+    pub fn open(&mut self, title: impl Into<String>, message: impl Into<String>, destructive: bool) {
+        self.title = title.into();
+        self.message = message.into();
+        self.destructive = destructive;
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let border = if self.destructive { theme.error } else { theme.accent };
+        let prompt = Paragraph::new(format!("{}\n\n(y/n)", self.message))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.title.as_str())
+                    .border_style(Style::default().fg(border)),
+            )
+            .style(Style::default().fg(theme.text).bg(theme.popup))
+            .wrap(Wrap { trim: true });
+        f.render_widget(Clear, area);
+        f.render_widget(prompt, area);
+    }
+}
@@ -0,0 +1,176 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use crate::ui::components::InputView;
+use crate::ui::fuzzy::{self, Matcher};
+use crate::ui::theme::Theme;
+
+/// Field of the create-issue form that has the focus
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CreateField {
+    Project,
+    IssueType,
+    Summary,
+    Description,
+}
+
+impl CreateField {
+    const ALL: [CreateField; 4] = [
+        CreateField::Project,
+        CreateField::IssueType,
+        CreateField::Summary,
+        CreateField::Description,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            CreateField::Project => "Project",
+            CreateField::IssueType => "Issue Type",
+            CreateField::Summary => "Summary",
+            CreateField::Description => "Description (optional)",
+        }
+    }
+}
+
+/// Form for a new issue: project and issue type (both completed from what
+/// Jira offers), summary and a one-line description
+pub struct CreateIssueForm {
+    pub project: InputView,
+    pub issue_type: InputView,
+    pub summary: InputView,
+    pub description: InputView,
+    pub focus: CreateField,
+    /// Project whose issue types `issue_type` suggests
+    pub types_project: Option<String>,
+}
+
+impl CreateIssueForm {
+    pub fn new() -> Self {
+        Self {
+            project: InputView::new(String::new()),
+            issue_type: InputView::new(String::new()),
+            summary: InputView::new(String::new()),
+            description: InputView::new(String::new()),
+            focus: CreateField::Summary,
+            types_project: None,
+        }
+    }
+
+    /// Start a new issue, with `project` filled in when the board has one
+    pub fn open(&mut self, project_keys: Vec<String>, project: Option<String>, matcher: Matcher) {
+        *self = Self::new();
+        self.project.set_suggestions(project_keys, matcher);
+        match project {
+            Some(key) => {
+                self.project.input = key;
+                self.project.cursor_position = self.project.input.len();
+            }
+            None => self.focus = CreateField::Project,
+        }
+    }
+
+    /// Offer `types` for `project`, keeping a typed type if the project has it
+    pub fn set_issue_types(&mut self, project: String, types: Vec<String>, matcher: Matcher) {
+        let keep = types.iter().any(|t| t.eq_ignore_ascii_case(self.issue_type.input.trim()));
+        if !keep {
+            self.issue_type.input = types.first().cloned().unwrap_or_default();
+            self.issue_type.cursor_position = self.issue_type.input.len();
+        }
+        self.issue_type.set_suggestions(types, matcher);
+        self.types_project = Some(project);
+    }
+
+    /// The project key as chosen from the suggestions
+    pub fn project_key(&self) -> Option<String> {
+        self.project.chosen_suggestion().cloned()
+    }
+
+    pub fn issue_type_name(&self) -> Option<String> {
+        self.issue_type.chosen_suggestion().cloned()
+    }
+
+    fn focused(&mut self) -> &mut InputView {
+        match self.focus {
+            CreateField::Project => &mut self.project,
+            CreateField::IssueType => &mut self.issue_type,
+            CreateField::Summary => &mut self.summary,
+            CreateField::Description => &mut self.description,
+        }
+    }
+
+    /// Move the focus by `step` fields, completing the field being left
+    pub fn move_focus(&mut self, step: isize) {
+        self.focused().complete();
+        let len = CreateField::ALL.len() as isize;
+        let index = CreateField::ALL.iter().position(|f| *f == self.focus).unwrap_or(0) as isize;
+        self.focus = CreateField::ALL[(index + step).rem_euclid(len) as usize];
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode, modifiers: KeyModifiers) {
+        self.focused().handle_key(key, modifiers);
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        f.render_widget(Clear, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Create Issue (Tab/Shift+Tab: fields, Enter: create, Esc: cancel)")
+            .border_style(Style::default().fg(theme.border));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(1),
+            ])
+            .split(inner);
+
+        for (i, field) in CreateField::ALL.into_iter().enumerate() {
+            let input = match field {
+                CreateField::Project => &self.project,
+                CreateField::IssueType => &self.issue_type,
+                CreateField::Summary => &self.summary,
+                CreateField::Description => &self.description,
+            };
+            let focused = field == self.focus;
+            let text = if focused {
+                let (before, after) = input.input.split_at(input.cursor_position);
+                format!("{}|{}", before, after)
+            } else {
+                input.input.clone()
+            };
+            let border = if focused { theme.accent } else { theme.hint };
+            let widget = Paragraph::new(text).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(field.label())
+                    .border_style(Style::default().fg(border)),
+            );
+            f.render_widget(widget, chunks[i]);
+        }
+
+        // What Tab completes to in the focused field
+        let focused = match self.focus {
+            CreateField::Project => Some(&self.project),
+            CreateField::IssueType => Some(&self.issue_type),
+            _ => None,
+        };
+        let style = Style::default().fg(theme.hint);
+        let mut spans = Vec::new();
+        for (i, (suggestion, indices)) in focused.map(|f| f.matching_suggestions()).unwrap_or_default().iter().enumerate() {
+            spans.push(Span::styled(if i == 0 { "Tab: " } else { ", " }, style));
+            spans.extend(fuzzy::highlight(suggestion, indices, style));
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)).wrap(Wrap { trim: false }), chunks[4]);
+    }
+}
@@ -1,10 +1,11 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
+use crate::ui::theme::Theme;
 
 pub struct HelpView;
 
@@ -13,7 +14,7 @@ impl HelpView {
         Self
     }
 
-    pub fn render(&self, f: &mut Frame, area: Rect) {
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &Theme) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
@@ -21,7 +22,7 @@ impl HelpView {
 
         let title = Paragraph::new("Jira TUI - Keyboard Shortcuts")
             .block(Block::default().borders(Borders::ALL).title("Help"))
-            .style(Style::default().fg(Color::Cyan));
+            .style(Style::default().fg(theme.border));
         f.render_widget(title, chunks[0]);
 
         let keybindings = vec![
@@ -29,7 +30,18 @@ impl HelpView {
                 ("q", "Quit application"),
                 ("h", "Show/hide help"),
                 ("Tab", "Switch between views"),
+                ("O", "Settings (from sprint view; e edits the config file in $EDITOR)"),
+                ("1-9", "Switch to the Nth board (0 opens the board selector)"),
+                ("Space", "Preview the highlighted board's sprint (board selector)"),
+                ("/", "Find a board by name (board selector)"),
+                ("Ctrl+^ / ^", "Toggle back to the previous board"),
+                ("S", "Pick board and sprint together (from sprint view)"),
+                ("s", "Start the highlighted future sprint (sprint selector)"),
+                ("C", "Close active sprint, moving incomplete issues (sprint selector)"),
+                ("o", "Load the closed sprints too (sprint selectors)"),
                 ("Esc", "Go back/cancel"),
+                ("Ctrl+X", "Dismiss all notifications"),
+                ("Ctrl+T", "Toggle the compact layout (saved)"),
             ]),
             ("Navigation", vec![
                 ("j/↓", "Move down"),
@@ -38,19 +50,75 @@ impl HelpView {
             ]),
             ("Sprint/Backlog View", vec![
                 ("r", "Refresh issues"),
+                ("R", "Refresh only the selected issue"),
+                ("u", "Jump to next issue assigned to me"),
+                ("l", "Filter by label"),
+                ("f", "Type to narrow the list by key, summary or assignee"),
+                ("Q", "Apply a board quick filter (empty clears it)"),
+                ("d", "Show/hide done issues (sprint view)"),
+                ("C", "Show/hide counts per status category (saved)"),
+                ("/", "JQL search (Tab: board/project/all scope)"),
+                ("G", "Go to an issue by key (Tab completes)"),
+                ("!", "Issues blocked by open issues (Tab: sprint/board)"),
+                ("W", "My issues: everything assigned to me, on any board"),
+                ("c", "Board columns view (h/l: columns, j/k: issues, ?: help)"),
+                ("X", "Export the listed issues to a CSV file"),
+                ("n", "Create an issue (project, type, summary, description)"),
+                ("a", "Show only one assignee's issues (empty clears)"),
+                ("m", "Copy the selected issue as Markdown"),
+                ("i", "Assign to me and start progress"),
+                ("#", "Set story points (empty clears)"),
+                ("T", "Set the due date (YYYY-MM-DD, +3, +2w; empty clears)"),
+                ("v", "Quick peek at the selected issue (any key closes)"),
+                ("p", "Toggle backlog key prefix filter"),
+                ("K/J", "Move backlog issue to the top/bottom"),
+                ("M", "Move backlog issue (or marked ones) into the sprint"),
+                ("F", "Flag/unflag the issue as an impediment"),
+                ("Space", "Mark/unmark issue for bulk actions (Esc clears)"),
+                ("g", "Group sprint by status/assignee/epic/none"),
+                ("Space (header)", "Collapse/expand the group"),
+                ("zc/zo", "Collapse/expand all groups"),
+                ("Y", "Copy my standup update (in progress + done today)"),
+                ("F (marked)", "Flag/unflag all marked issues after confirming"),
                 ("Enter", "View issue details"),
                 ("s", "Switch to sprint view"),
                 ("b", "Switch to backlog view"),
             ]),
             ("Issue Detail View", vec![
-                ("e", "Edit issue"),
-                ("c", "Add comment"),
+                ("e", "Edit the summary (diff preview before saving)"),
+                ("d", "Edit the description in $EDITOR (diff preview before saving)"),
+                ("E", "Edit environment (empty clears it)"),
+                ("p", "Profile card of the assignee/reporter (Tab switches)"),
+                ("a", "Assign to someone, or unassign"),
+                ("c", "Add comment (service desk: Tab toggles internal/public)"),
+                ("Ctrl+S", "Submit a comment (Enter starts a new line)"),
+                ("[/]", "Select a comment; then e edits and d deletes your own"),
+                ("Ctrl+R", "Restrict the comment to a role or group (while writing)"),
                 ("t", "Show transitions"),
+                ("j/k", "Scroll description"),
+                ("</>", "Scroll description sideways (unwraps long lines)"),
+                ("z", "Toggle wrapping long description lines (saved)"),
+                ("+/-", "Grow/shrink the comments pane (remembered)"),
+                ("M", "Maximize description, then comments, then split"),
+                ("m", "Copy the issue as Markdown"),
+                ("i", "Assign to me and start progress"),
+                ("#", "Set story points (empty clears)"),
+                ("T", "Set the due date (YYYY-MM-DD, +3, +2w; empty clears)"),
+                ("w", "Log work, e.g. \"1h 30m\" or \"2h; code review\""),
+                ("v", "Preview image attachments (n/p to cycle)"),
+                ("D", "Delete issue (type the key to confirm)"),
                 ("Enter", "Apply transition (when in transition mode)"),
+                ("c", "Apply transition with a comment (when in transition mode)"),
+                ("Tab", "Complete the resolution, for transitions that need one"),
+                ("Tab/Shift+Tab", "Select a label chip (Enter lists the board's issues with it)"),
             ]),
             ("Edit Mode", vec![
                 ("Ctrl+s", "Save changes"),
                 ("Esc", "Cancel editing"),
+                ("Home/End", "Jump to start/end of line"),
+                ("Ctrl+←/→", "Jump by word"),
+                ("Ctrl+w", "Delete previous word"),
+                ("Ctrl+u", "Clear line"),
             ]),
         ];
 
@@ -58,13 +126,13 @@ impl HelpView {
         for (category, bindings) in keybindings {
             items.push(ListItem::new(Line::from(Span::styled(
                 category,
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
             ))));
             
             for (key, description) in bindings {
                 items.push(ListItem::new(Line::from(vec![
-                    Span::styled(format!("  {:<10}", key), Style::default().fg(Color::Green)),
-                    Span::raw(description),
+                    Span::styled(format!("  {:<10}", key), Style::default().fg(theme.success)),
+                    Span::styled(description, Style::default().fg(theme.text)),
                 ])));
             }
             items.push(ListItem::new(""));
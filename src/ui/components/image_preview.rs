@@ -0,0 +1,113 @@
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use crate::ui::theme::Theme;
+#[cfg(feature = "image-preview")]
+use ratatui_image::{
+    picker::{Picker, ProtocolType},
+    protocol::StatefulProtocol,
+    StatefulImage,
+};
+
+/// Overlay for image attachments. Images are only drawn when built with the
+/// `image-preview` feature on a terminal with a graphics protocol
+/// (kitty, sixel or iTerm2); otherwise it explains why there is no preview.
+pub struct ImagePreview {
+    pub title: String,
+    pub message: Option<String>,
+    /// Position among the issue's image attachments
+    pub index: usize,
+    #[cfg(feature = "image-preview")]
+    picker: Option<Picker>,
+    #[cfg(feature = "image-preview")]
+    image: Option<Box<dyn StatefulProtocol>>,
+}
+
+impl ImagePreview {
+    pub fn new() -> Self {
+        Self {
+            title: "Attachment".to_string(),
+            message: None,
+            index: 0,
+            #[cfg(feature = "image-preview")]
+            picker: None,
+            #[cfg(feature = "image-preview")]
+            image: None,
+        }
+    }
+
+    /// Probe the terminal for a graphics protocol. The probe reads replies from
+    /// stdin, so it has to run before the event loop starts consuming keys.
+    #[cfg(feature = "image-preview")]
+    pub fn detect(&mut self) {
+        // Half-blocks work everywhere but are too coarse to count as a preview
+        self.picker = Picker::from_termios()
+            .ok()
+            .and_then(|mut picker| match picker.guess_protocol() {
+                ProtocolType::Halfblocks => None,
+                _ => Some(picker),
+            });
+    }
+
+    #[cfg(feature = "image-preview")]
+    pub fn is_supported(&self) -> bool {
+        self.picker.is_some()
+    }
+
+    /// Decode downloaded attachment bytes and show them
+    #[cfg(feature = "image-preview")]
+    pub fn set_image(&mut self, title: String, bytes: &[u8]) {
+        self.title = title;
+        let decoded = image::load_from_memory(bytes);
+        match (self.picker.as_mut(), decoded) {
+            (Some(picker), Ok(image)) => {
+                self.image = Some(picker.new_resize_protocol(image));
+                self.message = None;
+            }
+            (None, _) => self.show_unsupported(self.title.clone()),
+            (_, Err(e)) => self.show_message(self.title.clone(), format!("Could not decode image: {}", e)),
+        }
+    }
+
+    pub fn show_message(&mut self, title: String, message: String) {
+        self.title = title;
+        self.message = Some(message);
+        #[cfg(feature = "image-preview")]
+        {
+            self.image = None;
+        }
+    }
+
+    pub fn show_unsupported(&mut self, title: String) {
+        let reason = if cfg!(feature = "image-preview") {
+            "Preview unsupported: this terminal has no kitty, sixel or iTerm2 image support"
+        } else {
+            "Preview unsupported: built without the image-preview feature"
+        };
+        self.show_message(title, reason.to_string());
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.title.as_str())
+            .border_style(Style::default().fg(theme.accent));
+        let inner = block.inner(area);
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        #[cfg(feature = "image-preview")]
+        if let Some(image) = self.image.as_mut() {
+            f.render_stateful_widget(StatefulImage::new(None), inner, image);
+            return;
+        }
+
+        let message = Paragraph::new(self.message.as_deref().unwrap_or("No preview"))
+            .style(Style::default().fg(theme.muted))
+            .wrap(Wrap { trim: true });
+        f.render_widget(message, inner);
+    }
+}
@@ -1,14 +1,24 @@
+use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
-    widgets::{Block, Borders, Paragraph},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
+use crate::ui::fuzzy::{self, Matcher};
+use crate::ui::theme::Theme;
 
 pub struct InputView {
     pub input: String,
     pub title: String,
     pub cursor_position: usize,
+    pub suggestions: Vec<String>,
+    pub matcher: Matcher,
+    /// Dimmed line shown under the input, e.g. a preview of what Enter will do
+    pub hint: Option<String>,
+    /// Enter starts a new line and Ctrl+S submits, for comments
+    pub multiline: bool,
 }
 
 impl InputView {
@@ -17,30 +27,158 @@ impl InputView {
             input: String::new(),
             title,
             cursor_position: 0,
+            suggestions: Vec::new(),
+            matcher: Matcher::default(),
+            hint: None,
+            multiline: false,
         }
     }
 
+    pub fn multiline(title: String) -> Self {
+        Self { multiline: true, ..Self::new(title) }
+    }
+
+    /// Whether `key` submits the input: Enter on a single line; Ctrl+S, or
+    /// Ctrl/Alt+Enter where the terminal reports them, on several
+    pub fn is_submit(&self, key: KeyCode, modifiers: KeyModifiers) -> bool {
+        let modified = modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        match key {
+            KeyCode::Enter => !self.multiline || modified,
+            KeyCode::Char('s') => self.multiline && modifiers.contains(KeyModifiers::CONTROL),
+            _ => false,
+        }
+    }
+
+    // `cursor_position` is a byte offset into `input` and always sits on a char boundary
+
     pub fn push_char(&mut self, c: char) {
         self.input.insert(self.cursor_position, c);
-        self.cursor_position += 1;
+        self.cursor_position += c.len_utf8();
     }
 
     pub fn pop_char(&mut self) {
-        if self.cursor_position > 0 {
-            self.cursor_position -= 1;
-            self.input.remove(self.cursor_position);
+        if let Some((index, _)) = self.input[..self.cursor_position].char_indices().next_back() {
+            self.input.remove(index);
+            self.cursor_position = index;
         }
     }
 
     pub fn move_cursor_left(&mut self) {
-        if self.cursor_position > 0 {
-            self.cursor_position -= 1;
+        if let Some((index, _)) = self.input[..self.cursor_position].char_indices().next_back() {
+            self.cursor_position = index;
         }
     }
 
     pub fn move_cursor_right(&mut self) {
-        if self.cursor_position < self.input.len() {
-            self.cursor_position += 1;
+        if let Some(c) = self.input[self.cursor_position..].chars().next() {
+            self.cursor_position += c.len_utf8();
+        }
+    }
+
+    pub fn move_cursor_home(&mut self) {
+        self.cursor_position = 0;
+    }
+
+    pub fn move_cursor_end(&mut self) {
+        self.cursor_position = self.input.len();
+    }
+
+    /// Byte offset where the cursor's line starts
+    fn line_start(&self) -> usize {
+        self.input[..self.cursor_position].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    /// Byte offset where the cursor's line ends, before its newline
+    fn line_end(&self) -> usize {
+        self.input[self.cursor_position..]
+            .find('\n')
+            .map_or(self.input.len(), |i| self.cursor_position + i)
+    }
+
+    /// Put the cursor `column` chars into the line starting at `start`, or at
+    /// its end when the line is shorter
+    fn move_to_column(&mut self, start: usize, column: usize) {
+        let line = &self.input[start..];
+        let line = &line[..line.find('\n').unwrap_or(line.len())];
+        self.cursor_position = start + line.char_indices().nth(column).map_or(line.len(), |(i, _)| i);
+    }
+
+    pub fn move_cursor_up(&mut self) {
+        let start = self.line_start();
+        if start == 0 {
+            return;
+        }
+        let column = self.input[start..self.cursor_position].chars().count();
+        let previous_start = self.input[..start - 1].rfind('\n').map_or(0, |i| i + 1);
+        self.move_to_column(previous_start, column);
+    }
+
+    pub fn move_cursor_down(&mut self) {
+        let end = self.line_end();
+        if end == self.input.len() {
+            return;
+        }
+        let column = self.input[self.line_start()..self.cursor_position].chars().count();
+        self.move_to_column(end + 1, column);
+    }
+
+    /// Start of the word before the cursor, skipping whitespace first
+    fn previous_word_start(&self) -> usize {
+        let before = &self.input[..self.cursor_position];
+        let trimmed = before.trim_end();
+        trimmed
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(0, |(i, c)| i + c.len_utf8())
+    }
+
+    /// End of the word after the cursor, skipping whitespace first
+    fn next_word_end(&self) -> usize {
+        let after = &self.input[self.cursor_position..];
+        let skipped = after.len() - after.trim_start().len();
+        let word = &after[skipped..];
+        let word_len = word.find(char::is_whitespace).unwrap_or(word.len());
+        self.cursor_position + skipped + word_len
+    }
+
+    pub fn move_word_left(&mut self) {
+        self.cursor_position = self.previous_word_start();
+    }
+
+    pub fn move_word_right(&mut self) {
+        self.cursor_position = self.next_word_end();
+    }
+
+    pub fn delete_word_before(&mut self) {
+        let start = self.previous_word_start();
+        self.input.replace_range(start..self.cursor_position, "");
+        self.cursor_position = start;
+    }
+
+    /// Readline-style editing keys shared by every input prompt
+    pub fn handle_key(&mut self, key: KeyCode, modifiers: KeyModifiers) {
+        let ctrl = modifiers.contains(KeyModifiers::CONTROL);
+        match key {
+            KeyCode::Left if ctrl => self.move_word_left(),
+            KeyCode::Right if ctrl => self.move_word_right(),
+            KeyCode::Char('w') if ctrl => self.delete_word_before(),
+            KeyCode::Char('u') if ctrl => self.clear(),
+            KeyCode::Char('a') if ctrl => self.move_cursor_home(),
+            KeyCode::Char('e') if ctrl => self.move_cursor_end(),
+            KeyCode::Char(_) if ctrl => {}
+            KeyCode::Backspace => self.pop_char(),
+            KeyCode::Left => self.move_cursor_left(),
+            KeyCode::Right => self.move_cursor_right(),
+            KeyCode::Enter if self.multiline => self.push_char('\n'),
+            KeyCode::Up if self.multiline => self.move_cursor_up(),
+            KeyCode::Down if self.multiline => self.move_cursor_down(),
+            KeyCode::Home if self.multiline => self.cursor_position = self.line_start(),
+            KeyCode::End if self.multiline => self.cursor_position = self.line_end(),
+            KeyCode::Home => self.move_cursor_home(),
+            KeyCode::End => self.move_cursor_end(),
+            KeyCode::Char(c) => self.push_char(c),
+            _ => {}
         }
     }
 
@@ -53,21 +191,81 @@ impl InputView {
         &self.input
     }
 
-    pub fn render(&self, f: &mut Frame, area: Rect) {
+    pub fn set_suggestions(&mut self, suggestions: Vec<String>, matcher: Matcher) {
+        self.suggestions = suggestions;
+        self.matcher = matcher;
+    }
 
-        // Add cursor indicator
-        let display_text = if self.cursor_position < self.input.len() {
-            format!("{}|{}", 
-                &self.input[..self.cursor_position], 
-                &self.input[self.cursor_position..]
-            )
-        } else {
-            format!("{}|", self.input)
-        };
+    /// Suggestions matching the current input, best first, with the matched char indices
+    pub fn matching_suggestions(&self) -> Vec<(&String, Vec<usize>)> {
+        self.matcher
+            .rank(&self.suggestions, self.input.trim())
+            .into_iter()
+            .map(|(i, indices)| (&self.suggestions[i], indices))
+            .collect()
+    }
+
+    /// The suggestion Enter picks: an exact (case-insensitive) match, else the
+    /// best one; `None` for empty input
+    pub fn chosen_suggestion(&self) -> Option<&String> {
+        let input = self.input.trim().to_lowercase();
+        if input.is_empty() {
+            return None;
+        }
+        self.suggestions
+            .iter()
+            .find(|s| s.to_lowercase() == input)
+            .or_else(|| self.matching_suggestions().first().map(|(s, _)| *s))
+    }
+
+    /// Replace the input with the best matching suggestion
+    pub fn complete(&mut self) {
+        if let Some((suggestion, _)) = self.matching_suggestions().first() {
+            self.input = suggestion.to_string();
+            self.cursor_position = self.input.len();
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        // Add cursor indicator; the offset is on a char boundary, so the split is safe
+        let display_text = format!(
+            "{}|{}",
+            &self.input[..self.cursor_position],
+            &self.input[self.cursor_position..]
+        );
+
+        // One line each, since a Line doesn't break at '\n'
+        let mut lines: Vec<Line> = display_text.split('\n').map(|line| Line::from(line.to_string())).collect();
+        let matches = self.matching_suggestions();
+        if !matches.is_empty() {
+            lines.push(Line::from(""));
+            let style = Style::default().fg(theme.hint);
+            let mut spans = vec![Span::styled("Tab: ", style)];
+            for (i, (suggestion, indices)) in matches.iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::styled(", ", style));
+                }
+                spans.extend(fuzzy::highlight(suggestion, indices, style));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        if let Some(hint) = &self.hint {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(hint.as_str(), Style::default().fg(theme.hint))));
+        }
+        if self.multiline {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Enter: new line · Ctrl+S: submit",
+                Style::default().fg(theme.hint),
+            )));
+        }
 
-        let input_widget = Paragraph::new(display_text)
+        let input_widget = Paragraph::new(lines)
             .block(Block::default().borders(Borders::ALL).title(self.title.as_str()))
-            .style(Style::default().fg(Color::White));
+            .style(Style::default().fg(theme.text))
+            .wrap(Wrap { trim: false });
 
         f.render_widget(input_widget, area);
     }
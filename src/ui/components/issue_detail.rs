@@ -1,17 +1,64 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
-use crate::jira::{Issue, Transition};
+use crate::jira::{Comment, Issue, Transition, WorklogEntry};
+use crate::ui::{due_date, highlight, time_spent};
+use crate::ui::icons::IssueTypeIcons;
+use crate::ui::theme::Theme;
+
+const HSCROLL_STEP: u16 = 8;
+/// Rows the comments pane grows or shrinks by per key press
+const COMMENTS_STEP: u16 = 2;
+/// Borders plus one line of content
+const MIN_PANE_HEIGHT: u16 = 3;
+const MAX_COMMENTS_HEIGHT: u16 = 60;
+/// Most worklog rows shown before the pane stops growing
+const MAX_WORKLOG_ROWS: u16 = 5;
+
+/// Pane given all the space below the metadata, hiding the other one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Maximized {
+    None,
+    Description,
+    Comments,
+}
 
 pub struct IssueDetailView {
     pub issue: Option<Issue>,
     pub transitions: Vec<Transition>,
     pub transition_state: ListState,
     pub show_transitions: bool,
+    pub description_scroll: u16,
+    pub description_hscroll: u16,
+    pub date_format: String,
+    pub type_icons: IssueTypeIcons,
+    /// Height of the comments pane when neither pane is maximized
+    pub comments_height: u16,
+    pub maximized: Maximized,
+    /// Wrap long description lines rather than truncate them
+    pub wrap_description: bool,
+    /// Custom fields listed under the metadata, as (label, field id)
+    pub custom_fields: Vec<(String, String)>,
+    pub story_points_field: String,
+    /// Label chip picked with Tab, for a label search with Enter
+    pub selected_label: Option<usize>,
+    /// Work logged on the issue, fetched when it is opened
+    pub worklogs: Vec<WorklogEntry>,
+    /// Comment picked with `[`/`]`, for editing or deleting
+    pub selected_comment: Option<usize>,
+    /// The signed-in user; only their comments can be edited, others are dimmed
+    pub account_id: Option<String>,
+    /// Styled description, rebuilt when the issue or the pane width changes
+    /// rather than every frame
+    description_lines: Vec<Line<'static>>,
+    /// Pane width `description_lines` was laid out for; 0 when stale
+    description_width: u16,
+    /// Theme `description_lines` were styled with
+    description_theme: Theme,
 }
 
 impl IssueDetailView {
@@ -21,11 +68,59 @@ impl IssueDetailView {
             transitions: Vec::new(),
             transition_state: ListState::default(),
             show_transitions: false,
+            description_scroll: 0,
+            description_hscroll: 0,
+            date_format: "%d/%b/%y".to_string(),
+            type_icons: IssueTypeIcons::default(),
+            comments_height: 8,
+            maximized: Maximized::None,
+            wrap_description: true,
+            custom_fields: Vec::new(),
+            story_points_field: String::new(),
+            selected_label: None,
+            worklogs: Vec::new(),
+            selected_comment: None,
+            account_id: None,
+            description_lines: Vec::new(),
+            description_width: 0,
+            description_theme: Theme::default(),
         }
     }
 
     pub fn set_issue(&mut self, issue: Issue) {
+        // Keep the scroll position when the same issue is refreshed
+        if self.issue.as_ref().map(|i| &i.key) != Some(&issue.key) {
+            self.description_scroll = 0;
+            self.description_hscroll = 0;
+            self.selected_label = None;
+            self.worklogs.clear();
+            self.selected_comment = None;
+        }
+        self.description_width = 0;
         self.issue = Some(issue);
+        // A deleted comment may have been the last one
+        let count = self.comments().len();
+        self.selected_comment = self.selected_comment.filter(|_| count > 0).map(|i| i.min(count - 1));
+    }
+
+    pub fn set_worklogs(&mut self, worklogs: Vec<WorklogEntry>) {
+        self.worklogs = worklogs;
+    }
+
+    pub fn scroll_description_down(&mut self) {
+        self.description_scroll = self.description_scroll.saturating_add(1);
+    }
+
+    pub fn scroll_description_up(&mut self) {
+        self.description_scroll = self.description_scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_description_right(&mut self) {
+        self.description_hscroll = self.description_hscroll.saturating_add(HSCROLL_STEP);
+    }
+
+    pub fn scroll_description_left(&mut self) {
+        self.description_hscroll = self.description_hscroll.saturating_sub(HSCROLL_STEP);
     }
 
     pub fn set_transitions(&mut self, transitions: Vec<Transition>) {
@@ -35,6 +130,61 @@ impl IssueDetailView {
         }
     }
 
+    fn labels(&self) -> &[String] {
+        self.issue
+            .as_ref()
+            .and_then(|issue| issue.fields.labels.as_deref())
+            .unwrap_or_default()
+    }
+
+    pub fn next_label(&mut self) {
+        let count = self.labels().len();
+        if count > 0 {
+            self.selected_label = Some(self.selected_label.map_or(0, |i| (i + 1) % count));
+        }
+    }
+
+    pub fn previous_label(&mut self) {
+        let count = self.labels().len();
+        if count > 0 {
+            self.selected_label = Some(self.selected_label.map_or(count - 1, |i| (i + count - 1) % count));
+        }
+    }
+
+    pub fn selected_label(&self) -> Option<String> {
+        self.labels().get(self.selected_label?).cloned()
+    }
+
+    fn comments(&self) -> &[Comment] {
+        self.issue
+            .as_ref()
+            .and_then(|issue| issue.fields.comment.as_ref())
+            .map_or(&[], |comments| comments.comments.as_slice())
+    }
+
+    pub fn next_comment(&mut self) {
+        let count = self.comments().len();
+        if count > 0 {
+            self.selected_comment = Some(self.selected_comment.map_or(0, |i| (i + 1) % count));
+        }
+    }
+
+    pub fn previous_comment(&mut self) {
+        let count = self.comments().len();
+        if count > 0 {
+            self.selected_comment = Some(self.selected_comment.map_or(count - 1, |i| (i + count - 1) % count));
+        }
+    }
+
+    pub fn selected_comment(&self) -> Option<&Comment> {
+        self.comments().get(self.selected_comment?)
+    }
+
+    /// Whether `comment` was written by the signed-in user
+    pub fn is_own(&self, comment: &Comment) -> bool {
+        self.account_id.as_deref() == Some(comment.author.account_id.as_str())
+    }
+
     pub fn next_transition(&mut self) {
         let i = match self.transition_state.selected() {
             Some(i) => {
@@ -67,12 +217,47 @@ impl IssueDetailView {
         self.transition_state.selected().and_then(|i| self.transitions.get(i))
     }
 
-    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+    pub fn grow_comments(&mut self) {
+        self.comments_height = (self.comments_height + COMMENTS_STEP).min(MAX_COMMENTS_HEIGHT);
+    }
+
+    pub fn shrink_comments(&mut self) {
+        self.comments_height = self.comments_height.saturating_sub(COMMENTS_STEP).max(MIN_PANE_HEIGHT);
+    }
+
+    /// Cycle: split → description maximized → comments maximized → split
+    pub fn cycle_maximized(&mut self) {
+        self.maximized = match self.maximized {
+            Maximized::None => Maximized::Description,
+            Maximized::Description => Maximized::Comments,
+            Maximized::Comments => Maximized::None,
+        };
+    }
+
+    /// Lay the description out again when the pane width changed, since tables
+    /// fit themselves to it, or when the theme changed
+    fn fit_description(&mut self, width: u16, theme: &Theme) {
+        if width == self.description_width && *theme == self.description_theme {
+            return;
+        }
+        let description = self
+            .issue
+            .as_ref()
+            .and_then(|issue| issue.fields.description.as_deref())
+            .unwrap_or("No description");
+        self.description_lines = highlight::text_lines(description, width, theme);
+        self.description_width = width;
+        self.description_theme = *theme;
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        // The description pane spans the full width, inside its borders
+        self.fit_description(area.width.saturating_sub(2).max(1), theme);
         if let Some(ref issue) = self.issue {
             if self.show_transitions {
-                self.render_transitions(f, area);
+                self.render_transitions(f, area, theme);
             } else {
-                self.render_issue_details(f, area, issue);
+                self.render_issue_details(f, area, issue, theme);
             }
         } else {
             let no_issue = Paragraph::new("No issue selected")
@@ -81,29 +266,12 @@ impl IssueDetailView {
         }
     }
 
-    fn render_issue_details(&self, f: &mut Frame, area: Rect, issue: &Issue) {
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3),
-                Constraint::Length(5),
-                Constraint::Min(10),
-                Constraint::Length(8),
-            ])
-            .split(area);
-
-        // Title
-        let title = Paragraph::new(format!("{}: {}", issue.key, issue.fields.summary))
-            .block(Block::default().borders(Borders::ALL).title("Issue"))
-            .style(Style::default().fg(Color::Cyan))
-            .wrap(Wrap { trim: true });
-        f.render_widget(title, chunks[0]);
-
+    fn render_issue_details(&self, f: &mut Frame, area: Rect, issue: &Issue, theme: &Theme) {
         // Metadata
-        let metadata_lines = vec![
+        let mut metadata_lines = vec![
             Line::from(vec![
                 Span::styled("Status: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(&issue.fields.status.name),
+                Span::styled(&issue.fields.status.name, Style::default().fg(theme.status_color(&issue.fields.status))),
             ]),
             Line::from(vec![
                 Span::styled("Assignee: ", Style::default().add_modifier(Modifier::BOLD)),
@@ -119,23 +287,145 @@ impl IssueDetailView {
                 Span::raw(&issue.fields.issuetype.name),
             ]),
         ];
+        if let Some(resolved) = issue.fields.resolutiondate {
+            let mut spans = vec![
+                Span::styled("Resolved: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(resolved.format(&self.date_format).to_string()),
+            ];
+            if let Some(created) = issue.fields.created {
+                spans.push(Span::styled(
+                    format!(" (took {})", human_duration(resolved - created)),
+                    Style::default().fg(theme.muted),
+                ));
+            }
+            metadata_lines.push(Line::from(spans));
+        }
+        if let Some(due) = issue.fields.duedate {
+            let mut spans = vec![
+                Span::styled("Due: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(due.format(&self.date_format).to_string()),
+            ];
+            if issue.is_overdue(due_date::today()) {
+                spans.push(Span::styled(
+                    " (overdue)",
+                    Style::default().fg(theme.error).add_modifier(Modifier::BOLD),
+                ));
+            }
+            metadata_lines.push(Line::from(spans));
+        }
+        if let Some(points) = issue.field_text(&self.story_points_field) {
+            metadata_lines.push(Line::from(vec![
+                Span::styled("Story Points: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(points),
+            ]));
+        }
+        if let Some(labels) = issue.fields.labels.as_ref().filter(|l| !l.is_empty()) {
+            let mut spans = vec![Span::styled("Labels: ", Style::default().add_modifier(Modifier::BOLD))];
+            for (i, label) in labels.iter().enumerate() {
+                let style = if self.selected_label == Some(i) {
+                    Style::default().fg(theme.on_accent).bg(theme.accent).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text).bg(theme.hint)
+                };
+                spans.push(Span::styled(format!(" {} ", label), style));
+                spans.push(Span::raw(" "));
+            }
+            metadata_lines.push(Line::from(spans));
+        }
+        for (label, field) in &self.custom_fields {
+            metadata_lines.push(Line::from(vec![
+                Span::styled(format!("{}: ", label), Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(issue.field_text(field).unwrap_or_else(|| "None".to_string())),
+            ]));
+        }
+        if let Some(attachments) = issue.fields.attachment.as_ref().filter(|a| !a.is_empty()) {
+            let images = attachments.iter().filter(|a| a.is_image()).count();
+            metadata_lines.push(Line::from(vec![
+                Span::styled("Attachments: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("{} ({} image, a to preview)", attachments.len(), images)),
+            ]));
+        }
+
+        // Environment only takes space when set, up to 4 lines plus borders
+        let environment_height = issue
+            .fields
+            .environment
+            .as_ref()
+            .map_or(0, |env| env.lines().count().clamp(1, 4) as u16 + 2);
+        let worklog_height = match self.worklogs.len() {
+            0 => 0,
+            rows => (rows as u16).min(MAX_WORKLOG_ROWS) + 2,
+        };
+        let (description_constraint, comments_constraint) = match self.maximized {
+            Maximized::None => (Constraint::Min(MIN_PANE_HEIGHT), Constraint::Length(self.comments_height)),
+            Maximized::Description => (Constraint::Min(MIN_PANE_HEIGHT), Constraint::Length(0)),
+            Maximized::Comments => (Constraint::Length(0), Constraint::Min(MIN_PANE_HEIGHT)),
+        };
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(metadata_lines.len() as u16 + 2),
+                Constraint::Length(environment_height),
+                description_constraint,
+                Constraint::Length(worklog_height),
+                comments_constraint,
+            ])
+            .split(area);
+
+        // Title
+        let title = Paragraph::new(Line::from(vec![
+            self.type_icons.span(&issue.fields.issuetype.name, theme),
+            Span::raw(format!("{}: {}", issue.key, issue.fields.summary)),
+        ]))
+            .block(Block::default().borders(Borders::ALL).title("Issue"))
+            .style(Style::default().fg(theme.border))
+            .wrap(Wrap { trim: true });
+        f.render_widget(title, chunks[0]);
 
         let metadata = Paragraph::new(metadata_lines)
             .block(Block::default().borders(Borders::ALL).title("Details"));
         f.render_widget(metadata, chunks[1]);
 
+        if let Some(environment) = &issue.fields.environment {
+            let environment = Paragraph::new(environment.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Environment"))
+                .wrap(Wrap { trim: true });
+            f.render_widget(environment, chunks[2]);
+        }
+
         // Description
-        let default_description = "No description".to_string();
-        let description_text = issue.fields.description
-            .as_ref()
-            .unwrap_or(&default_description);
-        
-        let description = Paragraph::new(description_text.as_str())
-            .block(Block::default().borders(Borders::ALL).title("Description"))
-            .wrap(Wrap { trim: true });
-        f.render_widget(description, chunks[2]);
+        // Horizontal offsets only apply to unwrapped text, so scrolling sideways
+        // switches the pane to truncated lines until it is scrolled back to column 0
+        let wrapped = self.wrap_description && self.description_hscroll == 0;
+        let description_title = if self.description_hscroll > 0 {
+            format!("Description (col {})", self.description_hscroll + 1)
+        } else if !wrapped {
+            "Description (unwrapped)".to_string()
+        } else {
+            "Description".to_string()
+        };
+        let description = Paragraph::new(self.description_lines.clone())
+            .block(Block::default().borders(Borders::ALL).title(description_title))
+            .scroll((self.description_scroll, self.description_hscroll));
+        let description = if wrapped {
+            // Keep indentation in code blocks
+            description.wrap(Wrap { trim: false })
+        } else {
+            description
+        };
+        if self.maximized != Maximized::Comments {
+            f.render_widget(description, chunks[3]);
+        }
+
+        if self.maximized != Maximized::Comments && !self.worklogs.is_empty() {
+            self.render_worklogs(f, chunks[4], theme);
+        }
 
         // Comments
+        if self.maximized == Maximized::Description {
+            return;
+        }
         if let Some(ref comments) = issue.fields.comment {
             let comment_items: Vec<ListItem> = comments
                 .comments
@@ -146,21 +436,56 @@ impl IssueDetailView {
                         comment.author.display_name,
                         comment.body
                     );
-                    ListItem::new(content)
+                    // Comments that can't be edited are dimmed once the user is known
+                    let style = match self.account_id {
+                        Some(_) if !self.is_own(comment) => Style::default().fg(theme.hint),
+                        _ => Style::default(),
+                    };
+                    ListItem::new(content).style(style)
                 })
                 .collect();
 
+            let title = if self.selected_comment.is_some() {
+                "Comments (e: edit, d: delete, Esc: deselect)"
+            } else {
+                "Comments"
+            };
             let comments_list = List::new(comment_items)
-                .block(Block::default().borders(Borders::ALL).title("Comments"));
-            f.render_widget(comments_list, chunks[3]);
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .highlight_style(Style::default().bg(theme.highlight).add_modifier(Modifier::BOLD));
+            let mut state = ListState::default().with_selected(self.selected_comment);
+            f.render_stateful_widget(comments_list, chunks[5], &mut state);
         } else {
             let no_comments = Paragraph::new("No comments")
                 .block(Block::default().borders(Borders::ALL).title("Comments"));
-            f.render_widget(no_comments, chunks[3]);
+            f.render_widget(no_comments, chunks[5]);
         }
     }
 
-    fn render_transitions(&mut self, f: &mut Frame, area: Rect) {
+    /// Newest entries first, as author, time spent and the day it started
+    fn render_worklogs(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let total: u64 = self.worklogs.iter().map(|w| w.time_spent_seconds).sum();
+        let items: Vec<ListItem> = self
+            .worklogs
+            .iter()
+            .rev()
+            .map(|worklog| {
+                let mut spans = vec![
+                    Span::styled(format!("{:>8}  ", worklog.time_spent), Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(format!("{}  ", worklog.author.display_name)),
+                    Span::styled(worklog.started.format(&self.date_format).to_string(), Style::default().fg(theme.muted)),
+                ];
+                if let Some(comment) = worklog.comment.as_ref().filter(|c| !c.is_empty()) {
+                    spans.push(Span::raw(format!("  {}", comment.lines().next().unwrap_or_default())));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let title = format!("Work Log ({} logged, W to log work)", time_spent::format_total(total));
+        f.render_widget(List::new(items).block(Block::default().borders(Borders::ALL).title(title)), area);
+    }
+
+    fn render_transitions(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
         let items: Vec<ListItem> = self
             .transitions
             .iter()
@@ -173,7 +498,7 @@ impl IssueDetailView {
             .block(Block::default().borders(Borders::ALL).title("Available Transitions"))
             .highlight_style(
                 Style::default()
-                    .bg(Color::LightBlue)
+                    .bg(theme.highlight)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol(">> ");
@@ -181,3 +506,15 @@ impl IssueDetailView {
         f.render_stateful_widget(transitions_list, area, &mut self.transition_state);
     }
 }
+
+/// Largest two units of a duration, e.g. "3d 4h" or "25m"
+fn human_duration(duration: chrono::Duration) -> String {
+    let minutes = duration.num_minutes().max(0);
+    let (days, hours, minutes) = (minutes / (24 * 60), minutes / 60 % 24, minutes % 60);
+    match (days, hours) {
+        (0, 0) if minutes == 0 => "under a minute".to_string(),
+        (0, 0) => format!("{}m", minutes),
+        (0, _) => format!("{}h {}m", hours, minutes),
+        _ => format!("{}d {}h", days, hours),
+    }
+}
@@ -6,6 +6,16 @@ pub mod input;
 pub mod sprint_selector;
 pub mod board_selector;
 pub mod project_selector;
+pub mod settings;
+pub mod image_preview;
+pub mod batch_summary;
+pub mod close_sprint;
+pub mod confirm_dialog;
+pub mod create_issue;
+pub mod blocked_view;
+pub mod board_view;
+pub mod search_view;
+pub mod toast;
 
 pub use backlog_view::BacklogView;
 pub use help::HelpView;
@@ -15,3 +25,13 @@ pub use sprint_view::SprintView;
 pub use sprint_selector::SprintSelector;
 pub use board_selector::BoardSelector;
 pub use project_selector::ProjectSelector;
+pub use settings::SettingsView;
+pub use image_preview::ImagePreview;
+pub use batch_summary::BatchSummary;
+pub use close_sprint::{CloseSprintView, MoveTarget};
+pub use confirm_dialog::ConfirmDialog;
+pub use create_issue::CreateIssueForm;
+pub use blocked_view::BlockedView;
+pub use board_view::BoardView;
+pub use search_view::SearchView;
+pub use toast::Toasts;
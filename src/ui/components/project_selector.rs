@@ -1,15 +1,18 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
 use crate::jira::Project;
+use crate::ui::glyphs::Glyphs;
+use crate::ui::theme::Theme;
 
 pub struct ProjectSelector {
     pub projects: Vec<Project>,
     pub state: ListState,
     pub is_active: bool,
+    pub glyphs: Glyphs,
 }
 
 impl ProjectSelector {
@@ -18,6 +21,7 @@ impl ProjectSelector {
             projects: Vec::new(),
             state: ListState::default(),
             is_active: false,
+            glyphs: Glyphs::default(),
         }
     }
 
@@ -78,15 +82,16 @@ impl ProjectSelector {
         self.state.selected().and_then(|i| self.projects.get(i))
     }
 
+    #[allow(dead_code)]
     pub fn selected_project_id(&self) -> Option<String> {
         self.selected_project().map(|p| p.id.clone())
     }
 
-    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+    pub fn render(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
         if self.projects.is_empty() {
             let no_projects = Paragraph::new("No projects available")
                 .block(Block::default().borders(Borders::ALL).title("Project Selector"))
-                .style(Style::default().fg(Color::Gray));
+                .style(Style::default().fg(theme.muted));
             f.render_widget(no_projects, area);
             return;
         }
@@ -94,21 +99,15 @@ impl ProjectSelector {
         let items: Vec<ListItem> = self
             .projects
             .iter()
-            .enumerate()
-            .map(|(_i, project)| {
+            .map(|project| {
                 let project_type_color = match project.project_type_key.as_str() {
-                    "software" => Color::Green,
-                    "service_desk" => Color::Blue,
-                    "business" => Color::Yellow,
-                    _ => Color::White,
+                    "software" => theme.success,
+                    "service_desk" => theme.info,
+                    "business" => theme.accent,
+                    _ => theme.text,
                 };
 
-                let type_symbol = match project.project_type_key.as_str() {
-                    "software" => "💻",
-                    "service_desk" => "🎧",
-                    "business" => "📊",
-                    _ => "📁",
-                };
+                let type_symbol = self.glyphs.project_type(&project.project_type_key);
 
                 let content = format!(
                     "{} {} [{}]",
@@ -128,16 +127,16 @@ impl ProjectSelector {
         };
 
         let border_style = if self.is_active {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(theme.accent)
         } else {
-            Style::default().fg(Color::White)
+            Style::default().fg(theme.text)
         };
 
         let projects_list = List::new(items)
             .block(Block::default().borders(Borders::ALL).title(title).border_style(border_style))
             .highlight_style(
                 Style::default()
-                    .bg(Color::LightBlue)
+                    .bg(theme.highlight)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol(">> ");
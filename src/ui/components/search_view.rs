@@ -0,0 +1,125 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{layout::Rect, Frame};
+use crate::jira::Issue;
+use crate::ui::app::AppMode;
+use crate::ui::components::{InputView, SprintView};
+use crate::ui::theme::Theme;
+
+/// What a JQL search is narrowed to before it runs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchScope {
+    /// The sprint currently shown for the board
+    Board,
+    /// The board's project
+    Project,
+    All,
+}
+
+impl SearchScope {
+    fn next(self) -> Self {
+        match self {
+            SearchScope::Board => SearchScope::Project,
+            SearchScope::Project => SearchScope::All,
+            SearchScope::All => SearchScope::Board,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SearchScope::Board => "Board",
+            SearchScope::Project => "Project",
+            SearchScope::All => "All",
+        }
+    }
+}
+
+/// JQL search: the query prompt, and the issues the last search returned
+/// listed like a sprint
+pub struct SearchView {
+    /// The JQL being typed
+    pub input: InputView,
+    pub scope: SearchScope,
+    pub results: SprintView,
+    /// Where Esc leaves the results for
+    pub return_mode: AppMode,
+}
+
+impl SearchView {
+    pub fn new() -> Self {
+        Self {
+            input: InputView::new(String::new()),
+            scope: SearchScope::Board,
+            results: SprintView::new(),
+            return_mode: AppMode::Sprint,
+        }
+    }
+
+    /// Open an empty prompt; Esc from the results goes back to `return_mode`
+    pub fn begin(&mut self, return_mode: AppMode) {
+        self.input = InputView::new(String::new());
+        self.return_mode = return_mode;
+    }
+
+    /// The query Enter runs: the typed JQL within `scope_clause`
+    pub fn jql(&self, scope_clause: Option<&str>) -> String {
+        scoped_jql(scope_clause, self.input.get_input())
+    }
+
+    /// Show the scope in the title and the query that Enter will run below the input
+    pub fn update_prompt(&mut self, scope_clause: Option<&str>) {
+        self.input.title = format!("JQL Search [{}] (Tab: scope)", self.scope.label());
+        self.input.hint = Some(format!("Runs: {}", self.jql(scope_clause)));
+    }
+
+    pub fn next_scope(&mut self) {
+        self.scope = self.scope.next();
+    }
+
+    /// List `issues` under `title` and clear the prompt
+    pub fn set_results(&mut self, issues: Vec<Issue>, title: String) {
+        self.results.set_issues(issues, title, None);
+        self.input.clear();
+    }
+
+    /// Drop an issue that no longer exists from the results, without searching again
+    pub fn remove_issue(&mut self, key: &str) {
+        let mut issues = std::mem::take(&mut self.results.issues);
+        issues.retain(|issue| issue.key != key);
+        let title = self.results.sprint_name.clone();
+        self.results.set_issues(issues, title, None);
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode, modifiers: KeyModifiers) {
+        self.input.handle_key(key, modifiers);
+    }
+
+    pub fn render_prompt(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        self.input.render(f, area, theme);
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        self.results.render(f, area, theme);
+    }
+}
+
+/// `query` within `clause`, keeping any ORDER BY at the end
+fn scoped_jql(clause: Option<&str>, query: &str) -> String {
+    let query = query.trim();
+    let Some(clause) = clause else {
+        return query.to_string();
+    };
+    let (condition, order) = match query.to_ascii_lowercase().find("order by") {
+        Some(index) => (query[..index].trim(), query[index..].trim()),
+        None => (query, ""),
+    };
+    let jql = if condition.is_empty() {
+        clause.to_string()
+    } else {
+        format!("{} AND ({})", clause, condition)
+    };
+    if order.is_empty() {
+        jql
+    } else {
+        format!("{} {}", jql, order)
+    }
+}
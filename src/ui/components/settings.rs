@@ -0,0 +1,206 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+use crate::config::UiConfig;
+use crate::ui::color_depth::COLOR_DEPTHS;
+use crate::ui::components::sprint_selector::SPRINT_SORTS;
+use crate::ui::theme::{Theme, THEMES};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingField {
+    Theme,
+    RefreshInterval,
+    DateFormat,
+    PageSize,
+    ColorDepth,
+    SprintSort,
+    Compact,
+    FuzzyMatching,
+    UseEmoji,
+}
+
+impl SettingField {
+    const ALL: [SettingField; 9] = [
+        SettingField::Theme,
+        SettingField::RefreshInterval,
+        SettingField::DateFormat,
+        SettingField::PageSize,
+        SettingField::ColorDepth,
+        SettingField::SprintSort,
+        SettingField::Compact,
+        SettingField::FuzzyMatching,
+        SettingField::UseEmoji,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SettingField::Theme => "Theme",
+            SettingField::RefreshInterval => "Refresh interval (s, 0 = off)",
+            SettingField::DateFormat => "Date format",
+            SettingField::PageSize => "Page size",
+            SettingField::ColorDepth => "Color depth",
+            SettingField::SprintSort => "Sprint order",
+            SettingField::Compact => "Compact layout",
+            SettingField::FuzzyMatching => "Fuzzy matching",
+            SettingField::UseEmoji => "Emoji markers",
+        }
+    }
+
+    /// Fields picked from a fixed list with ←/→ rather than typed
+    pub fn is_cycled(&self) -> bool {
+        matches!(
+            self,
+            SettingField::Theme
+                | SettingField::ColorDepth
+                | SettingField::SprintSort
+                | SettingField::Compact
+                | SettingField::FuzzyMatching
+                | SettingField::UseEmoji
+        )
+    }
+}
+
+/// Edits a draft copy of `UiConfig`; the app copies it back on save
+pub struct SettingsView {
+    pub draft: UiConfig,
+    pub state: ListState,
+}
+
+impl SettingsView {
+    pub fn new(ui: UiConfig) -> Self {
+        let mut state = ListState::default();
+        state.select(Some(0));
+        Self { draft: ui, state }
+    }
+
+    pub fn next(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) if i + 1 < SettingField::ALL.len() => i + 1,
+            _ => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        let i = match self.state.selected() {
+            Some(0) | None => SettingField::ALL.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn selected_field(&self) -> SettingField {
+        SettingField::ALL[self.state.selected().unwrap_or(0)]
+    }
+
+    /// Step a cycled field (theme, color depth, sprint order) through its allowed values
+    pub fn cycle(&mut self, field: SettingField, forward: bool) {
+        if field == SettingField::Compact {
+            self.draft.compact = !self.draft.compact;
+            return;
+        }
+        if field == SettingField::FuzzyMatching {
+            self.draft.fuzzy_matching = !self.draft.fuzzy_matching;
+            return;
+        }
+        if field == SettingField::UseEmoji {
+            self.draft.use_emoji = !self.draft.use_emoji;
+            return;
+        }
+        let (options, value): (&[&str], &mut String) = match field {
+            SettingField::Theme => (&THEMES, &mut self.draft.theme),
+            SettingField::ColorDepth => (&COLOR_DEPTHS, &mut self.draft.color_depth),
+            SettingField::SprintSort => (&SPRINT_SORTS, &mut self.draft.sprint_sort),
+            _ => return,
+        };
+        let current = options.iter().position(|o| o == value).unwrap_or(0);
+        let next = if forward {
+            (current + 1) % options.len()
+        } else {
+            (current + options.len() - 1) % options.len()
+        };
+        *value = options[next].to_string();
+    }
+
+    pub fn value(&self, field: SettingField) -> String {
+        match field {
+            SettingField::Theme => self.draft.theme.clone(),
+            SettingField::RefreshInterval => self.draft.refresh_interval.to_string(),
+            SettingField::DateFormat => self.draft.date_format.clone(),
+            SettingField::PageSize => self.draft.page_size.to_string(),
+            SettingField::ColorDepth => self.draft.color_depth.clone(),
+            SettingField::SprintSort => self.draft.sprint_sort.clone(),
+            SettingField::Compact => if self.draft.compact { "on" } else { "off" }.to_string(),
+            SettingField::FuzzyMatching => if self.draft.fuzzy_matching { "on" } else { "off" }.to_string(),
+            SettingField::UseEmoji => if self.draft.use_emoji { "on" } else { "off" }.to_string(),
+        }
+    }
+
+    /// Validate and store a typed value for a text/numeric field
+    pub fn set_value(&mut self, field: SettingField, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        match field {
+            SettingField::Theme => self.draft.theme = value.to_string(),
+            SettingField::ColorDepth => self.draft.color_depth = value.to_string(),
+            SettingField::SprintSort => self.draft.sprint_sort = value.to_string(),
+            SettingField::Compact => self.draft.compact = matches!(value, "on" | "true"),
+            SettingField::FuzzyMatching => self.draft.fuzzy_matching = matches!(value, "on" | "true"),
+            SettingField::UseEmoji => self.draft.use_emoji = matches!(value, "on" | "true"),
+            SettingField::RefreshInterval => {
+                self.draft.refresh_interval = value
+                    .parse()
+                    .map_err(|_| format!("'{}' is not a number of seconds", value))?;
+            }
+            SettingField::DateFormat => {
+                let invalid = chrono::format::StrftimeItems::new(value)
+                    .any(|item| matches!(item, chrono::format::Item::Error));
+                if value.is_empty() || invalid {
+                    return Err(format!("'{}' is not a valid date format", value));
+                }
+                self.draft.date_format = value.to_string();
+            }
+            SettingField::PageSize => match value.parse::<u32>() {
+                Ok(size) if (1..=100).contains(&size) => self.draft.page_size = size,
+                _ => return Err("Page size must be between 1 and 100".to_string()),
+            },
+        }
+        Ok(())
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let items: Vec<ListItem> = SettingField::ALL
+            .iter()
+            .map(|field| {
+                let hint = if field.is_cycled() { "  (←/→)" } else { "" };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<32}", field.label()),
+                        Style::default().fg(theme.accent),
+                    ),
+                    Span::raw(self.value(*field)),
+                    Span::styled(hint, Style::default().fg(theme.hint)),
+                ]))
+            })
+            .collect();
+
+        let settings_list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Settings")
+                    .border_style(Style::default().fg(theme.accent)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+
+        f.render_stateful_widget(settings_list, area, &mut self.state);
+    }
+}
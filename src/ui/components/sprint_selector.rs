@@ -1,15 +1,59 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
 use crate::jira::Sprint;
+use crate::ui::theme::Theme;
+use std::cmp::Ordering;
+
+/// Values accepted by `ui.sprint_sort`
+pub const SPRINT_SORTS: [&str; 2] = ["id", "start_date"];
+
+/// Newest-first comparison: by id, or by start date (created date when not
+/// started yet) with undated sprints last. Ids are not always chronological,
+/// e.g. after a board migration.
+fn newest_first(a: &Sprint, b: &Sprint, order: &str) -> Ordering {
+    if order != "start_date" {
+        return b.id.cmp(&a.id);
+    }
+    match (a.start_date.or(a.created_date), b.start_date.or(b.created_date)) {
+        (Some(a_date), Some(b_date)) => b_date.cmp(&a_date),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => b.id.cmp(&a.id),
+    }
+}
+
+/// The sprint to show when none is selected. By id this is the last one the
+/// board lists; by start date the most recently started.
+pub fn most_recent_sprint<'a>(sprints: &'a [Sprint], order: &str) -> Option<&'a Sprint> {
+    if order != "start_date" {
+        return sprints.last();
+    }
+    sprints.iter().min_by(|a, b| newest_first(a, b, order))
+}
+
+/// The sprint opened by default. With `prefer_active` that is the newest active
+/// sprint, falling back to the most recent one when none is active.
+pub fn default_sprint<'a>(sprints: &'a [Sprint], order: &str, prefer_active: bool) -> Option<&'a Sprint> {
+    let active = sprints
+        .iter()
+        .filter(|s| prefer_active && s.state == "active")
+        .min_by(|a, b| newest_first(a, b, order));
+    active.or_else(|| most_recent_sprint(sprints, order))
+}
 
 pub struct SprintSelector {
     pub sprints: Vec<Sprint>,
     pub state: ListState,
     pub is_active: bool,
+    pub date_format: String,
+    /// `ui.sprint_sort`
+    pub sort: String,
+    /// Only open sprints are listed; `o` loads the closed ones
+    pub closed_hidden: bool,
 }
 
 impl SprintSelector {
@@ -18,11 +62,14 @@ impl SprintSelector {
             sprints: Vec::new(),
             state: ListState::default(),
             is_active: false,
+            date_format: "%d/%b/%y".to_string(),
+            sort: "id".to_string(),
+            closed_hidden: false,
         }
     }
 
     pub fn set_sprints(&mut self, mut sprints: Vec<Sprint>) {
-        sprints.sort_by(|a, b| b.id.cmp(&a.id));
+        sprints.sort_by(|a, b| newest_first(a, b, &self.sort));
         self.sprints = sprints;
         // Select the first (most recent) sprint by default
         if !self.sprints.is_empty() {
@@ -30,6 +77,13 @@ impl SprintSelector {
         }
     }
 
+    /// Highlight the sprint with `id`, if it is listed
+    pub fn select_sprint(&mut self, id: u32) {
+        if let Some(index) = self.sprints.iter().position(|s| s.id == id) {
+            self.state.select(Some(index));
+        }
+    }
+
     pub fn activate(&mut self) {
         self.is_active = true;
     }
@@ -82,11 +136,11 @@ impl SprintSelector {
         self.selected_sprint().map(|s| s.id)
     }
 
-    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+    pub fn render(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
         if self.sprints.is_empty() {
             let no_sprints = Paragraph::new("No sprints available")
                 .block(Block::default().borders(Borders::ALL).title("Sprint Selector"))
-                .style(Style::default().fg(Color::Gray));
+                .style(Style::default().fg(theme.muted));
             f.render_widget(no_sprints, area);
             return;
         }
@@ -94,13 +148,12 @@ impl SprintSelector {
         let items: Vec<ListItem> = self
             .sprints
             .iter()
-            .enumerate()
-            .map(|(_i, sprint)| {
+            .map(|sprint| {
                 let status_color = match sprint.state.as_str() {
-                    "active" => Color::Green,
-                    "closed" => Color::Gray,
-                    "future" => Color::Blue,
-                    _ => Color::White,
+                    "active" => theme.success,
+                    "closed" => theme.muted,
+                    "future" => theme.info,
+                    _ => theme.text,
                 };
 
                 let status_symbol = match sprint.state.as_str() {
@@ -111,12 +164,12 @@ impl SprintSelector {
                 };
 
                 let date_info = if let Some(complete) = &sprint.complete_date {
-                    format!(" (Completed: {})", complete.format("%d/%b/%y"))
+                    format!(" (Completed: {})", complete.format(&self.date_format))
                 } else if let (Some(start), Some(end)) = (&sprint.start_date, &sprint.end_date) {
                     format!(
                         " ({} - {})",
-                        start.format("%d/%b"),
-                        end.format("%d/%b")
+                        start.format(&self.date_format),
+                        end.format(&self.date_format)
                     )
                 } else {
                     String::new()
@@ -134,23 +187,26 @@ impl SprintSelector {
             })
             .collect();
 
-        let title = if self.is_active {
-            "Sprint Selector (ACTIVE)"
+        let mut title = if self.is_active {
+            "Sprint Selector (ACTIVE)".to_string()
         } else {
-            "Sprint Selector"
+            "Sprint Selector".to_string()
         };
+        if self.closed_hidden {
+            title.push_str(" - closed hidden, o loads them");
+        }
 
         let border_style = if self.is_active {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(theme.accent)
         } else {
-            Style::default().fg(Color::White)
+            Style::default().fg(theme.text)
         };
 
         let sprints_list = List::new(items)
             .block(Block::default().borders(Borders::ALL).title(title).border_style(border_style))
             .highlight_style(
                 Style::default()
-                    .bg(Color::LightBlue)
+                    .bg(theme.highlight)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol(">> ");
@@ -1,16 +1,124 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{block::{Position, Title}, Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
-use crate::jira::Issue;
+use crate::jira::{BoardColumn, Issue};
+use crate::ui::fuzzy::Matcher;
+use crate::ui::glyphs::Glyphs;
+use crate::ui::icons::IssueTypeIcons;
+use crate::ui::theme::Theme;
+use crate::ui::{due_date, status_counts};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// How long issues that changed since the last look stay highlighted
+const CHANGE_HIGHLIGHT: Duration = Duration::from_secs(5);
+
+/// Optional grouping of the sprint's issues under collapsible headers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupBy {
+    None,
+    Status,
+    Assignee,
+    Epic,
+}
+
+impl GroupBy {
+    pub fn next(self) -> Self {
+        match self {
+            GroupBy::None => GroupBy::Status,
+            GroupBy::Status => GroupBy::Assignee,
+            GroupBy::Assignee => GroupBy::Epic,
+            GroupBy::Epic => GroupBy::None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GroupBy::None => "none",
+            GroupBy::Status => "status",
+            GroupBy::Assignee => "assignee",
+            GroupBy::Epic => "epic",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        [GroupBy::None, GroupBy::Status, GroupBy::Assignee, GroupBy::Epic]
+            .into_iter()
+            .find(|group_by| group_by.label() == label)
+    }
+
+    /// Group name for `issue`, and whether it is the catch-all group listed last
+    fn group_of(self, issue: &Issue) -> (String, bool) {
+        match self {
+            GroupBy::None => (String::new(), false),
+            GroupBy::Status => (issue.fields.status.name.clone(), false),
+            GroupBy::Assignee => match &issue.fields.assignee {
+                Some(user) => (user.display_name.clone(), false),
+                None => ("Unassigned".to_string(), true),
+            },
+            // Company-managed and team-managed projects both expose the epic as `parent`
+            GroupBy::Epic => match issue.fields.other.get("parent") {
+                Some(parent) if parent["key"].is_string() => {
+                    let key = parent["key"].as_str().unwrap_or_default();
+                    match parent["fields"]["summary"].as_str() {
+                        Some(summary) => (format!("{} {}", key, summary), false),
+                        None => (key.to_string(), false),
+                    }
+                }
+                _ => ("No Epic".to_string(), true),
+            },
+        }
+    }
+}
+
+/// A line of the issue list: a group header or an issue
+pub enum Row<'a> {
+    Group { name: String, count: usize, collapsed: bool },
+    Issue(&'a Issue),
+}
 
 pub struct SprintView {
     pub issues: Vec<Issue>,
     pub state: ListState,
+    pub label_filter: Option<String>,
+    /// Name of the board quick filter the issues were fetched with
+    pub quick_filter: Option<String>,
+    /// Display name of the user the issues were narrowed to
+    pub assignee_filter: Option<String>,
+    /// Text typed with `t`, matched against key, summary and assignee
+    pub text_filter: String,
+    pub matcher: Matcher,
+    /// Leave out issues in the done status category
+    pub hide_done: bool,
+    /// The board's columns, for WIP limits on status group headers
+    pub columns: Vec<BoardColumn>,
+    /// Count the loaded issues per status category along the bottom border
+    pub show_status_counts: bool,
+    /// Custom field checked for the flagged marker
+    pub flag_field: String,
+    /// Custom field holding story point estimates, shown on each row and totalled in the header
+    pub story_points_field: String,
+    pub type_icons: IssueTypeIcons,
+    pub glyphs: Glyphs,
+    /// Keys marked with Space for bulk actions
+    pub marked: HashSet<String>,
+    /// Issues updated since the list was last loaded, highlighted until `highlight_until`
+    pub changed_keys: HashSet<String>,
+    pub highlight_until: Option<Instant>,
+    /// When each sprint's issues were last loaded, keyed by sprint name
+    pub last_seen: HashMap<String, DateTime<Utc>>,
     pub sprint_name: String,
     pub sprint_goal: Option<String>,
+    /// End date of the shown sprint and whether it is still active
+    pub sprint_end: Option<(DateTime<Utc>, bool)>,
+    pub group_by: GroupBy,
+    /// Collapsed group names, remembered separately for each grouping
+    pub collapsed: HashMap<GroupBy, HashSet<String>>,
 }
 
 impl SprintView {
@@ -18,25 +126,268 @@ impl SprintView {
         Self {
             issues: Vec::new(),
             state: ListState::default(),
+            label_filter: None,
+            quick_filter: None,
+            assignee_filter: None,
+            text_filter: String::new(),
+            matcher: Matcher::default(),
+            hide_done: false,
+            columns: Vec::new(),
+            show_status_counts: false,
+            flag_field: String::new(),
+            story_points_field: String::new(),
+            type_icons: IssueTypeIcons::default(),
+            glyphs: Glyphs::default(),
+            marked: HashSet::new(),
+            changed_keys: HashSet::new(),
+            highlight_until: None,
+            last_seen: HashMap::new(),
             sprint_name: "Sprint".to_string(),
             sprint_goal: None,
+            sprint_end: None,
+            group_by: GroupBy::None,
+            collapsed: HashMap::new(),
         }
     }
 
     pub fn set_issues(&mut self, mut issues: Vec<Issue>, sprint_name: String, sprint_goal: Option<String>) {
-        issues.sort_by(|a, b| b.key.cmp(&a.key));
+        // Workflow order, highest priority first within it, then newest first
+        issues.sort_by(|a, b| {
+            let category = |issue: &Issue| status_counts::category_rank(&issue.fields.status.status_category.key);
+            category(a)
+                .cmp(&category(b))
+                .then(a.priority_rank().cmp(&b.priority_rank()))
+                .then_with(|| key_order(&b.key).cmp(&key_order(&a.key)))
+        });
+
+        let now = Utc::now();
+        if let Some(seen) = self.last_seen.insert(sprint_name.clone(), now) {
+            self.changed_keys = issues
+                .iter()
+                .filter(|i| i.fields.updated.is_some_and(|updated| updated > seen))
+                .map(|i| i.key.clone())
+                .collect();
+            self.highlight_until = Some(Instant::now() + CHANGE_HIGHLIGHT);
+        } else {
+            self.changed_keys.clear();
+        }
+
+        // Marks survive a refresh as long as the issue is still listed
+        self.marked.retain(|key| issues.iter().any(|i| &i.key == key));
         self.issues = issues;
         self.sprint_name = sprint_name;
         self.sprint_goal = sprint_goal;
-        if !self.issues.is_empty() {
-            self.state.select(Some(0));
+        self.sprint_end = None;
+        self.select_first_row();
+    }
+
+    /// Show time left in the sprint header; call after `set_issues`
+    pub fn set_sprint_end(&mut self, end_date: Option<DateTime<Utc>>, state: &str) {
+        self.sprint_end = end_date.map(|end| (end, state == "active"));
+    }
+
+    fn select_first_row(&mut self) {
+        let rows = self.rows().len();
+        self.state.select(if rows == 0 { None } else { Some(0) });
+    }
+
+    fn is_highlighted(&self, key: &str) -> bool {
+        self.highlight_until.is_some_and(|until| Instant::now() < until) && self.changed_keys.contains(key)
+    }
+
+    pub fn visible_issues(&self) -> Vec<&Issue> {
+        self.issues
+            .iter()
+            .filter(|issue| match &self.label_filter {
+                Some(label) => issue.fields.labels.as_ref().is_some_and(|l| l.contains(label)),
+                None => true,
+            })
+            .filter(|issue| !(self.hide_done && issue.is_done()))
+            .filter(|issue| self.matches_text_filter(issue))
+            .collect()
+    }
+
+    fn matches_text_filter(&self, issue: &Issue) -> bool {
+        let assignee = issue.fields.assignee.as_ref().map_or("", |u| u.display_name.as_str());
+        let text = format!("{} {} {}", issue.key, issue.fields.summary, assignee);
+        self.matcher.is_match(&text, &self.text_filter)
+    }
+
+    /// Narrow the list to issues matching `filter`, keeping the selected issue
+    /// selected while it still matches
+    pub fn set_text_filter(&mut self, filter: String) {
+        let selected = self.selected_issue().map(|i| i.key.clone());
+        self.text_filter = filter;
+        self.select_first_row();
+        if let Some(key) = selected {
+            self.select_key(&key);
         }
     }
 
+    pub fn toggle_hide_done(&mut self) {
+        self.hide_done = !self.hide_done;
+        self.select_first_row();
+    }
+
+    /// Distinct labels across all loaded issues, used for filter autocompletion
+    pub fn labels(&self) -> Vec<String> {
+        let mut labels: Vec<String> = self
+            .issues
+            .iter()
+            .flat_map(|issue| issue.fields.labels.iter().flatten().cloned())
+            .collect();
+        labels.sort();
+        labels.dedup();
+        labels
+    }
+
+    pub fn set_label_filter(&mut self, label: Option<String>) {
+        self.label_filter = label;
+        self.select_first_row();
+    }
+
+    /// Visible issues as list rows, under group headers when grouping is on.
+    /// Issues in collapsed groups are left out.
+    pub fn rows(&self) -> Vec<Row<'_>> {
+        let visible = self.visible_issues();
+        if self.group_by == GroupBy::None {
+            return visible.into_iter().map(Row::Issue).collect();
+        }
+
+        let mut groups: Vec<((bool, String), Vec<&Issue>)> = Vec::new();
+        for issue in visible {
+            let (name, catch_all) = self.group_by.group_of(issue);
+            let group_key = (catch_all, name);
+            match groups.iter_mut().find(|(key, _)| *key == group_key) {
+                Some((_, issues)) => issues.push(issue),
+                None => groups.push((group_key, vec![issue])),
+            }
+        }
+        groups.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let collapsed = self.collapsed.get(&self.group_by);
+        let mut rows = Vec::new();
+        for ((_, name), issues) in groups {
+            let is_collapsed = collapsed.is_some_and(|c| c.contains(&name));
+            rows.push(Row::Group {
+                name,
+                count: issues.len(),
+                collapsed: is_collapsed,
+            });
+            if !is_collapsed {
+                rows.extend(issues.into_iter().map(Row::Issue));
+            }
+        }
+        rows
+    }
+
+    /// Issue count and limit of the column holding `status`, when the count
+    /// is over the column's WIP limit
+    fn wip_exceeded(&self, status: &str) -> Option<(usize, u32)> {
+        let status_id = &self.issues.iter().find(|i| i.fields.status.name == status)?.fields.status.id;
+        let column = self.columns.iter().find(|c| c.contains(status_id))?;
+        let count = self
+            .issues
+            .iter()
+            .filter(|i| column.contains(&i.fields.status.id))
+            .count();
+        column.exceeded_limit(count).map(|max| (count, max))
+    }
+
+    /// Switch to the next grouping, keeping the selected issue selected
+    pub fn cycle_group_by(&mut self) {
+        let selected = self.selected_issue().map(|i| i.key.clone());
+        self.group_by = self.group_by.next();
+        self.select_first_row();
+        if let Some(key) = selected {
+            self.select_key(&key);
+        }
+    }
+
+    /// Drop `key` from the local list, keeping the cursor on the same row
+    pub fn remove_issue(&mut self, key: &str) {
+        self.issues.retain(|i| i.key != key);
+        self.marked.remove(key);
+        let rows = self.rows().len();
+        let row = self.state.selected().unwrap_or(0).min(rows.saturating_sub(1));
+        self.state.select((rows > 0).then_some(row));
+    }
+
+    pub fn select_key(&mut self, key: &str) {
+        if let Some(i) = self
+            .rows()
+            .iter()
+            .position(|row| matches!(row, Row::Issue(issue) if issue.key == key))
+        {
+            self.state.select(Some(i));
+        }
+    }
+
+    /// Collapse/expand the group header under the cursor, or mark the issue
+    pub fn toggle_selected(&mut self) {
+        if !self.toggle_selected_group() {
+            self.toggle_mark();
+        }
+    }
+
+    /// Collapsed groups by grouping label, as kept in `ui.collapsed_groups`
+    pub fn collapsed_groups(&self) -> BTreeMap<String, BTreeSet<String>> {
+        self.collapsed
+            .iter()
+            .filter(|(_, names)| !names.is_empty())
+            .map(|(group_by, names)| (group_by.label().to_string(), names.iter().cloned().collect()))
+            .collect()
+    }
+
+    pub fn set_collapsed_groups(&mut self, groups: &BTreeMap<String, BTreeSet<String>>) {
+        self.collapsed = groups
+            .iter()
+            .filter_map(|(label, names)| Some((GroupBy::from_label(label)?, names.iter().cloned().collect())))
+            .collect();
+    }
+
+    /// Collapse or expand the group under the cursor; false if an issue is selected
+    fn toggle_selected_group(&mut self) -> bool {
+        let rows = self.rows();
+        let Some(Row::Group { name, .. }) = self.state.selected().and_then(|i| rows.get(i)) else {
+            return false;
+        };
+        let name = name.clone();
+        let collapsed = self.collapsed.entry(self.group_by).or_default();
+        if !collapsed.remove(&name) {
+            collapsed.insert(name);
+        }
+        true
+    }
+
+    /// Collapse (or expand) every group of the current grouping
+    pub fn set_all_collapsed(&mut self, collapse: bool) {
+        if self.group_by == GroupBy::None {
+            return;
+        }
+        let names: HashSet<String> = self
+            .visible_issues()
+            .into_iter()
+            .map(|issue| self.group_by.group_of(issue).0)
+            .collect();
+        let collapsed = self.collapsed.entry(self.group_by).or_default();
+        if collapse {
+            collapsed.extend(names);
+        } else {
+            collapsed.clear();
+        }
+        // Rows shift when groups fold or unfold, so restart at the top
+        self.select_first_row();
+    }
+
     pub fn next(&mut self) {
+        let len = self.rows().len();
+        if len == 0 {
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
-                if i >= self.issues.len() - 1 {
+                if i >= len - 1 {
                     0
                 } else {
                     i + 1
@@ -48,10 +399,14 @@ impl SprintView {
     }
 
     pub fn previous(&mut self) {
+        let len = self.rows().len();
+        if len == 0 {
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.issues.len() - 1
+                    len - 1
                 } else {
                     i - 1
                 }
@@ -61,11 +416,49 @@ impl SprintView {
         self.state.select(Some(i));
     }
 
+    /// Move the selection to the next visible issue assigned to `account_id`,
+    /// wrapping around. Returns false if there is no such issue.
+    pub fn select_next_assigned_to(&mut self, account_id: &str) -> bool {
+        let rows = self.rows();
+        let len = rows.len();
+        let start = self.state.selected().map_or(0, |i| i + 1);
+        let next = (0..len).map(|offset| (start + offset) % len).find(|&i| {
+            matches!(rows[i], Row::Issue(issue)
+                if issue.fields.assignee.as_ref().is_some_and(|u| u.account_id == account_id))
+        });
+        if let Some(i) = next {
+            self.state.select(Some(i));
+        }
+        next.is_some()
+    }
+
+    /// Mark or unmark the selected issue and move to the next one
+    pub fn toggle_mark(&mut self) {
+        if let Some(key) = self.selected_issue().map(|i| i.key.clone()) {
+            if !self.marked.remove(&key) {
+                self.marked.insert(key);
+            }
+            self.next();
+        }
+    }
+
+    /// Marked issues in list order
+    pub fn marked_issues(&self) -> Vec<Issue> {
+        self.issues
+            .iter()
+            .filter(|i| self.marked.contains(&i.key))
+            .cloned()
+            .collect()
+    }
+
     pub fn selected_issue(&self) -> Option<&Issue> {
-        self.state.selected().and_then(|i| self.issues.get(i))
+        match self.state.selected().and_then(|i| self.rows().into_iter().nth(i)) {
+            Some(Row::Issue(issue)) => Some(issue),
+            _ => None,
+        }
     }
 
-    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+    pub fn render(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
@@ -77,26 +470,76 @@ impl SprintView {
         } else {
             format!("Sprint: {}", self.sprint_name)
         };
-        let header = Paragraph::new(header_text)
+        let mut header_spans = vec![Span::raw(header_text)];
+        if let Some((text, overdue)) = self
+            .sprint_end
+            .and_then(|(end, active)| time_left(end, active, Utc::now()))
+        {
+            let color = if overdue { theme.error } else { theme.accent };
+            header_spans.push(Span::raw(" │ "));
+            header_spans.push(Span::styled(text, Style::default().fg(color)));
+        }
+        let points: Vec<(f64, bool)> = self
+            .issues
+            .iter()
+            .filter_map(|issue| issue.story_points(&self.story_points_field).map(|p| (p, issue.is_done())))
+            .collect();
+        if !points.is_empty() {
+            let committed: f64 = points.iter().map(|(p, _)| p).sum();
+            let done: f64 = points.iter().filter(|(_, done)| *done).map(|(p, _)| p).sum();
+            header_spans.push(Span::raw(format!(
+                " │ {} pts committed, {} done",
+                points_text(committed),
+                points_text(done)
+            )));
+        }
+        let header = Paragraph::new(Line::from(header_spans))
             .block(Block::default().borders(Borders::ALL).title("Current Sprint"))
-            .style(Style::default().fg(Color::Cyan));
+            .style(Style::default().fg(theme.border));
         f.render_widget(header, chunks[0]);
 
         // Issues list
+        let today = due_date::today();
         let items: Vec<ListItem> = self
-            .issues
-            .iter()
-            .enumerate()
-            .map(|(_i, issue)| {
-                let status_color = match issue.fields.status.name.as_str() {
-                    "To Do" | "Open" => Color::Red,
-                    "In Progress" => Color::Yellow,
-                    "Done" | "Closed" => Color::Green,
-                    _ => Color::White,
+            .rows()
+            .into_iter()
+            .map(|row| {
+                let issue = match row {
+                    Row::Issue(issue) => issue,
+                    Row::Group { name, count, collapsed } => {
+                        let arrow = if collapsed { "▸" } else { "▾" };
+                        let wip = match self.group_by {
+                            GroupBy::Status => self.wip_exceeded(&name),
+                            _ => None,
+                        };
+                        let (text, color) = match wip {
+                            Some((in_column, max)) => (
+                                format!("{} {} ({}) WIP {}/{}", arrow, name, count, in_column, max),
+                                theme.error,
+                            ),
+                            None => (format!("{} {} ({})", arrow, name, count), theme.border),
+                        };
+                        return ListItem::new(text)
+                            .style(Style::default().fg(color).add_modifier(Modifier::BOLD));
+                    }
                 };
+                let status_color = theme.status_color(&issue.fields.status);
+                let priority = issue.fields.priority.as_ref().map(|p| p.name.as_str());
+
+                let changed = self.is_highlighted(&issue.key);
+                let marker = if changed { "● " } else { "" };
+                let flag = if issue.is_flagged(&self.flag_field) {
+                    format!("{} ", self.glyphs.flag())
+                } else {
+                    String::new()
+                };
+                let mark = if self.marked.contains(&issue.key) { "[x] " } else { "" };
 
                 let content = format!(
-                    "{} [{}] {} - {}",
+                    "{}{}{}{} [{}] {} - {}",
+                    mark,
+                    marker,
+                    flag,
                     issue.key,
                     issue.fields.status.name,
                     issue.fields.summary,
@@ -106,15 +549,60 @@ impl SprintView {
                         .unwrap_or("Unassigned")
                 );
 
-                ListItem::new(content).style(Style::default().fg(status_color))
+                let style = if changed {
+                    Style::default().fg(theme.marked).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(status_color)
+                };
+                let mut spans = vec![
+                    Span::styled(
+                        format!("{} ", self.glyphs.priority(priority)),
+                        Style::default().fg(theme.priority_color(priority)),
+                    ),
+                    self.type_icons.span(&issue.fields.issuetype.name, theme),
+                    Span::raw(content),
+                ];
+                if let Some(points) = issue.story_points(&self.story_points_field) {
+                    spans.push(Span::styled(format!(" ({} pts)", points_text(points)), Style::default().fg(theme.muted)));
+                }
+                spans.extend(due_date::marker(issue, today, self.glyphs, theme));
+                ListItem::new(Line::from(spans)).style(style)
             })
             .collect();
 
+        let mut title = status_counts::issue_count(self.issues.len());
+        if let Some(label) = &self.label_filter {
+            title.push_str(&format!(" [label: {}]", label));
+        }
+        if !self.text_filter.is_empty() {
+            title.push_str(&format!(" [filter: {}]", self.text_filter));
+        }
+        if let Some(filter) = &self.quick_filter {
+            title.push_str(&format!(" [quick filter: {}]", filter));
+        }
+        if let Some(assignee) = &self.assignee_filter {
+            title.push_str(&format!(" [assignee: {}]", assignee));
+        }
+        if self.hide_done {
+            let hidden = self.issues.iter().filter(|issue| issue.is_done()).count();
+            title.push_str(&format!(" [{} done hidden]", hidden));
+        }
+        if self.group_by != GroupBy::None {
+            title.push_str(&format!(" [by {}]", self.group_by.label()));
+        }
+        if !self.marked.is_empty() {
+            title.push_str(&format!(" [{} marked]", self.marked.len()));
+        }
+
+        let mut block = Block::default().borders(Borders::ALL).title(title);
+        if self.show_status_counts {
+            block = block.title(Title::from(status_counts::footer(&self.issues, theme)).position(Position::Bottom));
+        }
         let issues_list = List::new(items)
-            .block(Block::default().borders(Borders::ALL).title("Issues"))
+            .block(block)
             .highlight_style(
                 Style::default()
-                    .bg(Color::LightBlue)
+                    .bg(theme.highlight)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol(">> ");
@@ -122,3 +610,95 @@ impl SprintView {
         f.render_stateful_widget(issues_list, chunks[1], &mut self.state);
     }
 }
+
+/// "ends in 3 days" for sprints still running, or an overdue notice (true) for
+/// active sprints past their end date. Closed sprints get nothing.
+fn time_left(end: DateTime<Utc>, active: bool, now: DateTime<Utc>) -> Option<(String, bool)> {
+    let remaining = end - now;
+    if remaining.num_seconds() < 0 {
+        if !active {
+            return None;
+        }
+        let text = match (-remaining).num_days() {
+            0 => "ended today".to_string(),
+            1 => "overdue by 1 day".to_string(),
+            days => format!("overdue by {} days", days),
+        };
+        return Some((text, true));
+    }
+    // Round partial days up, so 5 hours left reads "ends in 1 day"
+    let text = match (remaining.num_hours() + 23) / 24 {
+        0 => "ends today".to_string(),
+        1 => "ends in 1 day".to_string(),
+        days => format!("ends in {} days", days),
+    };
+    Some((text, false))
+}
+
+/// Story points without a trailing ".0", e.g. "3" or "0.5"
+fn points_text(points: f64) -> String {
+    if points.fract() == 0.0 {
+        format!("{}", points as i64)
+    } else {
+        format!("{}", points)
+    }
+}
+
+/// Project and number of an issue key, so that `ABC-10` sorts after `ABC-9`
+fn key_order(key: &str) -> (&str, u64) {
+    match key.rsplit_once('-') {
+        Some((project, number)) => (project, number.parse().unwrap_or(0)),
+        None => (key, 0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(key: &str, category: &str, priority: Option<&str>) -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "id": key,
+            "key": key,
+            "fields": {
+                "summary": key,
+                "status": { "id": "1", "name": category, "statusCategory": { "id": 1, "name": category, "key": category } },
+                "issuetype": { "id": "1", "name": "Story" },
+                "priority": priority.map(|name| serde_json::json!({ "id": name, "name": name }))
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn issues_sort_by_status_then_priority_then_newest_key() {
+        let mut view = SprintView::new();
+        view.set_issues(
+            vec![
+                issue("A-9", "new", Some("Low")),
+                issue("A-2", "done", Some("Highest")),
+                issue("A-10", "new", Some("Low")),
+                issue("A-3", "indeterminate", None),
+                issue("A-1", "new", Some("High")),
+            ],
+            "Sprint".to_string(),
+            None,
+        );
+        let keys: Vec<&str> = view.issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["A-1", "A-10", "A-9", "A-3", "A-2"]);
+    }
+
+    #[test]
+    fn collapsed_groups_round_trip_through_the_config() {
+        let mut view = SprintView::new();
+        view.set_issues(vec![issue("A-1", "new", None), issue("A-2", "done", None)], "Sprint".to_string(), None);
+        view.group_by = GroupBy::Status;
+        view.set_all_collapsed(true);
+        let saved = view.collapsed_groups();
+        assert_eq!(saved["status"], BTreeSet::from(["done".to_string(), "new".to_string()]));
+
+        let mut restored = SprintView::new();
+        restored.set_collapsed_groups(&saved);
+        assert_eq!(restored.collapsed, view.collapsed);
+    }
+}
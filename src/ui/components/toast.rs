@@ -0,0 +1,127 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use crate::ui::theme::Theme;
+
+/// How long info and success toasts stay up; errors get twice as long
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+/// Toasts drawn at once, newest at the bottom
+const MAX_VISIBLE: usize = 3;
+const TOAST_WIDTH: u16 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Error,
+}
+
+impl ToastKind {
+    fn color(self, theme: &Theme) -> Color {
+        match self {
+            ToastKind::Info => theme.border,
+            ToastKind::Success => theme.success,
+            ToastKind::Error => theme.error,
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            ToastKind::Info => "Info",
+            ToastKind::Success => "Done",
+            ToastKind::Error => "Error",
+        }
+    }
+}
+
+pub struct Toast {
+    pub kind: ToastKind,
+    pub message: String,
+    pub expires_at: Instant,
+}
+
+/// Short-lived notifications stacked in the bottom-right corner; expired ones
+/// are dropped on `Event::Tick`
+pub struct Toasts {
+    pub queue: VecDeque<Toast>,
+}
+
+impl Toasts {
+    pub fn new() -> Self {
+        Self {
+            queue: VecDeque::new(),
+        }
+    }
+
+    pub fn push(&mut self, kind: ToastKind, message: impl Into<String>) {
+        let duration = if kind == ToastKind::Error {
+            TOAST_DURATION * 2
+        } else {
+            TOAST_DURATION
+        };
+        self.queue.push_back(Toast {
+            kind,
+            message: message.into(),
+            expires_at: Instant::now() + duration,
+        });
+    }
+
+    pub fn info(&mut self, message: impl Into<String>) {
+        self.push(ToastKind::Info, message);
+    }
+
+    pub fn success(&mut self, message: impl Into<String>) {
+        self.push(ToastKind::Success, message);
+    }
+
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.push(ToastKind::Error, message);
+    }
+
+    pub fn expire(&mut self) {
+        let now = Instant::now();
+        self.queue.retain(|toast| toast.expires_at > now);
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Draw the newest toasts in the bottom-right corner of `area`
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let width = TOAST_WIDTH.min(area.width);
+        let mut bottom = area.y + area.height;
+        for toast in self.queue.iter().rev().take(MAX_VISIBLE) {
+            // Borders plus up to two wrapped lines of message
+            let text_width = width.saturating_sub(2).max(1) as usize;
+            let lines = toast.message.chars().count().div_ceil(text_width).clamp(1, 2) as u16;
+            let height = lines + 2;
+            if bottom < area.y + height {
+                break;
+            }
+            bottom -= height;
+            let rect = Rect::new(area.x + area.width - width, bottom, width, height);
+            let color = toast.kind.color(theme);
+            let widget = Paragraph::new(toast.message.as_str())
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(toast.kind.title())
+                        .border_style(Style::default().fg(color)),
+                )
+                .style(Style::default().fg(theme.text).bg(theme.popup))
+                .wrap(Wrap { trim: true });
+            f.render_widget(Clear, rect);
+            f.render_widget(widget, rect);
+        }
+    }
+}
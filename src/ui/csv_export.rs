@@ -0,0 +1,96 @@
+use crate::jira::Issue;
+use anyhow::Result;
+use chrono::Local;
+use std::path::{Path, PathBuf};
+
+const HEADER: [&str; 7] = ["Key", "Summary", "Status", "Assignee", "Priority", "Story Points", "Updated"];
+
+/// Write `issues` to `path` as CSV, a header row then one row per issue.
+/// Quoting of commas, quotes and line breaks is left to the `csv` crate.
+pub fn write(path: &Path, issues: &[&Issue], story_points_field: &str) -> Result<()> {
+    let mut writer = csv::Writer::from_path(path)?;
+    writer.write_record(HEADER)?;
+    for issue in issues {
+        writer.write_record(row(issue, story_points_field))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn row(issue: &Issue, story_points_field: &str) -> [String; 7] {
+    let fields = &issue.fields;
+    [
+        issue.key.clone(),
+        fields.summary.clone(),
+        fields.status.name.clone(),
+        fields.assignee.as_ref().map(|u| u.display_name.clone()).unwrap_or_default(),
+        fields.priority.as_ref().map(|p| p.name.clone()).unwrap_or_default(),
+        issue.field_text(story_points_field).unwrap_or_default(),
+        // Spreadsheets recognize this as a date and time
+        fields
+            .updated
+            .map(|at| at.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default(),
+    ]
+}
+
+/// File name for an export of `view`, e.g. "sprint-42-2024-05-01.csv"
+pub fn default_file_name(view: &str) -> String {
+    let slug: String = view
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+        .collect();
+    let parts: Vec<&str> = slug.split('-').filter(|part| !part.is_empty()).collect();
+    let name = if parts.is_empty() { "issues".to_string() } else { parts.join("-") };
+    format!("{}-{}.csv", name, Local::now().format("%Y-%m-%d"))
+}
+
+/// `path` with a leading `~/` expanded to the home directory
+pub fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(key: &str, summary: &str) -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "id": key,
+            "key": key,
+            "fields": {
+                "summary": summary,
+                "status": { "id": "1", "name": "To Do", "statusCategory": { "id": 2, "name": "To Do", "key": "new" } },
+                "issuetype": { "id": "1", "name": "Story" },
+                "assignee": { "accountId": "1", "displayName": "Smith, Jane" },
+                "customfield_10016": 3
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn summaries_with_commas_quotes_and_newlines_read_back_unchanged() {
+        let issues = [
+            issue("A-1", "Fix login, then logout"),
+            issue("A-2", "Rename \"Save\" to \"Apply\""),
+            issue("A-3", "First line\nsecond line"),
+        ];
+        let path = std::env::temp_dir().join(format!("jira-tui-export-{}.csv", std::process::id()));
+        write(&path, &issues.iter().collect::<Vec<_>>(), "customfield_10016").unwrap();
+
+        let mut reader = csv::Reader::from_path(&path).unwrap();
+        assert_eq!(reader.headers().unwrap(), HEADER.as_slice());
+        let rows: Vec<csv::StringRecord> = reader.records().map(|row| row.unwrap()).collect();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(rows.len(), issues.len());
+        for (row, issue) in rows.iter().zip(&issues) {
+            let expected = [issue.key.as_str(), issue.fields.summary.as_str(), "To Do", "Smith, Jane", "", "3", ""];
+            assert_eq!(row.iter().collect::<Vec<_>>(), expected);
+        }
+    }
+}
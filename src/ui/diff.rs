@@ -0,0 +1,49 @@
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+use crate::ui::theme::Theme;
+
+/// Line diff of `before` → `after`: removed lines in the error color with `-`,
+/// added ones in the success color with `+`, and unchanged ones dimmed
+pub fn lines(before: &str, after: &str, theme: &Theme) -> Vec<Line<'static>> {
+    let old: Vec<&str> = before.lines().collect();
+    let new: Vec<&str> = after.lines().collect();
+
+    // Longest common subsequence lengths of every pair of suffixes
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let removed = |line: &str| line_with("- ", line, theme.error);
+    let added = |line: &str| line_with("+ ", line, theme.success);
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            out.push(line_with("  ", old[i], theme.hint));
+            i += 1;
+            j += 1;
+        } else if common[i + 1][j] >= common[i][j + 1] {
+            out.push(removed(old[i]));
+            i += 1;
+        } else {
+            out.push(added(new[j]));
+            j += 1;
+        }
+    }
+    out.extend(old[i..].iter().map(|line| removed(line)));
+    out.extend(new[j..].iter().map(|line| added(line)));
+    out
+}
+
+fn line_with(marker: &str, text: &str, color: Color) -> Line<'static> {
+    Line::from(Span::styled(format!("{}{}", marker, text), Style::default().fg(color)))
+}
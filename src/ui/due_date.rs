@@ -0,0 +1,48 @@
+use crate::jira::Issue;
+use crate::ui::glyphs::Glyphs;
+use crate::ui::theme::Theme;
+use chrono::{Duration, Local, NaiveDate};
+use ratatui::{
+    style::{Modifier, Style},
+    text::Span,
+};
+
+/// Today by the local clock, which is what "overdue" is judged against
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+/// List marker for an issue with a due date: in the error color once it is overdue
+pub fn marker(issue: &Issue, today: NaiveDate, glyphs: Glyphs, theme: &Theme) -> Option<Span<'static>> {
+    let due = issue.fields.duedate?;
+    let style = if issue.is_overdue(today) {
+        Style::default().fg(theme.error).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.muted)
+    };
+    Some(Span::styled(format!(" {} {}", glyphs.due(), due.format("%d %b")), style))
+}
+
+/// Due date typed into the prompt: "YYYY-MM-DD", "today", "tomorrow", or
+/// "+N"/"+Nw" for N days/weeks from today. Empty clears the date (`Ok(None)`).
+pub fn parse(input: &str, today: NaiveDate) -> Result<Option<NaiveDate>, String> {
+    let input = input.trim().to_lowercase();
+    let days = match input.as_str() {
+        "" => return Ok(None),
+        "today" => 0,
+        "tomorrow" => 1,
+        _ => {
+            let Some(offset) = input.strip_prefix('+') else {
+                return NaiveDate::parse_from_str(&input, "%Y-%m-%d")
+                    .map(Some)
+                    .map_err(|_| format!("'{}' is not a date like 2024-05-31 or +3", input));
+            };
+            let days = match offset.strip_suffix('w') {
+                Some(weeks) => weeks.parse::<i64>().map(|weeks| weeks * 7),
+                None => offset.trim_end_matches('d').parse::<i64>(),
+            };
+            days.map_err(|_| format!("'{}' is not a number of days like +3 or +2w", input))?
+        }
+    };
+    Ok(Some(today + Duration::days(days)))
+}
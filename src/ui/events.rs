@@ -1,11 +1,19 @@
 use crossterm::event::{self, KeyCode, KeyModifiers};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
+use crate::ui::app::{AppData, InitialData};
 
 #[derive(Debug, Clone)]
 pub enum Event {
     Key(KeyCode, KeyModifiers),
     Tick,
+    /// Result of the background load started by `App::start_initialize`
+    Initialized(Box<Result<InitialData, String>>),
+    /// A sprint or backlog list fetched in the background
+    DataLoaded(Box<AppData>),
+    #[allow(dead_code)]
     Quit,
 }
 
@@ -13,29 +21,35 @@ pub struct EventHandler {
     sender: mpsc::UnboundedSender<Event>,
     receiver: mpsc::UnboundedReceiver<Event>,
     handler: tokio::task::JoinHandle<()>,
+    /// Set while another program owns the terminal, e.g. `$EDITOR`
+    paused: Arc<AtomicBool>,
+    tick_rate: Duration,
 }
 
 impl EventHandler {
     pub fn new(tick_rate: Duration) -> Self {
         let (sender, receiver) = mpsc::unbounded_channel();
         let event_sender = sender.clone();
+        let paused = Arc::new(AtomicBool::new(false));
+        let reader_paused = paused.clone();
 
         let handler = tokio::spawn(async move {
             let mut last_tick = Instant::now();
             loop {
+                if reader_paused.load(Ordering::Relaxed) {
+                    std::thread::sleep(tick_rate);
+                    continue;
+                }
+
                 let timeout = tick_rate
                     .checked_sub(last_tick.elapsed())
                     .unwrap_or_else(|| Duration::from_secs(0));
 
-                if event::poll(timeout).unwrap_or(false) {
-                    match event::read().unwrap() {
-                        event::Event::Key(key) => {
-                            if key.kind == event::KeyEventKind::Press {
-                                let _ = event_sender.send(Event::Key(key.code, key.modifiers));
-                            }
-                        }
-                        _ => {}
-                    }
+                if event::poll(timeout).unwrap_or(false)
+                    && let event::Event::Key(key) = event::read().unwrap()
+                    && key.kind == event::KeyEventKind::Press
+                {
+                    let _ = event_sender.send(Event::Key(key.code, key.modifiers));
                 }
 
                 if last_tick.elapsed() >= tick_rate {
@@ -49,9 +63,27 @@ impl EventHandler {
             sender,
             receiver,
             handler,
+            paused,
+            tick_rate,
         }
     }
 
+    /// Stop reading the terminal, waiting out a poll in flight so that a
+    /// program started next gets every key
+    pub async fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+        tokio::time::sleep(self.tick_rate).await;
+    }
+
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+    }
+
+    /// Sender for background tasks that report back into the event loop
+    pub fn sender(&self) -> mpsc::UnboundedSender<Event> {
+        self.sender.clone()
+    }
+
     pub async fn next(&mut self) -> Option<Event> {
         self.receiver.recv().await
     }
@@ -0,0 +1,95 @@
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use ratatui::{
+    style::{Modifier, Style},
+    text::Span,
+};
+
+/// Matches typed text against list entries: fzf-style fuzzy matching, or a
+/// case-insensitive substring when `ui.fuzzy_matching` is off
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matcher {
+    fuzzy: bool,
+}
+
+impl Default for Matcher {
+    fn default() -> Self {
+        Self { fuzzy: true }
+    }
+}
+
+impl Matcher {
+    pub fn new(fuzzy: bool) -> Self {
+        Self { fuzzy }
+    }
+
+    /// Positions of the choices matching `pattern`, best first, with the char
+    /// indices that matched. An empty pattern keeps every choice in order.
+    pub fn rank(&self, choices: &[String], pattern: &str) -> Vec<(usize, Vec<usize>)> {
+        if pattern.is_empty() {
+            return (0..choices.len()).map(|i| (i, Vec::new())).collect();
+        }
+        let skim = SkimMatcherV2::default().ignore_case();
+        let mut scored: Vec<(i64, usize, Vec<usize>)> = choices
+            .iter()
+            .enumerate()
+            .filter_map(|(i, choice)| {
+                let (score, indices) = if self.fuzzy {
+                    skim.fuzzy_indices(choice, pattern)?
+                } else {
+                    substring_indices(choice, pattern)?
+                };
+                Some((score, i, indices))
+            })
+            .collect();
+        // Stable, so equal scores keep the original order
+        scored.sort_by_key(|(score, _, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, i, indices)| (i, indices)).collect()
+    }
+
+    /// Whether `text` matches `pattern`; an empty pattern matches everything
+    pub fn is_match(&self, text: &str, pattern: &str) -> bool {
+        if pattern.is_empty() {
+            return true;
+        }
+        if self.fuzzy {
+            SkimMatcherV2::default().ignore_case().fuzzy_match(text, pattern).is_some()
+        } else {
+            substring_indices(text, pattern).is_some()
+        }
+    }
+}
+
+/// Earlier matches score higher, like a prefix match would
+fn substring_indices(choice: &str, pattern: &str) -> Option<(i64, Vec<usize>)> {
+    let choice_chars: Vec<char> = choice.chars().flat_map(char::to_lowercase).collect();
+    let pattern_chars: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    // Lowercasing can change the char count (e.g. 'İ'); such choices only match fuzzily
+    if choice_chars.len() != choice.chars().count() {
+        return None;
+    }
+    let start = choice_chars
+        .windows(pattern_chars.len())
+        .position(|window| window == pattern_chars.as_slice())?;
+    Some((-(start as i64), (start..start + pattern_chars.len()).collect()))
+}
+
+/// `text` as spans with the matched chars emphasized on top of `style`
+pub fn highlight(text: &str, indices: &[usize], style: Style) -> Vec<Span<'static>> {
+    let matched = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (i, c) in text.chars().enumerate() {
+        let is_match = indices.contains(&i);
+        if is_match != run_matched && !run.is_empty() {
+            spans.push(Span::styled(std::mem::take(&mut run), if run_matched { matched } else { style }));
+        }
+        run_matched = is_match;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        spans.push(Span::styled(run, if run_matched { matched } else { style }));
+    }
+    spans
+}
@@ -0,0 +1,90 @@
+/// Markers that have an emoji and a plain-text form, picked by `ui.use_emoji`
+/// for terminals and fonts without emoji
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glyphs {
+    pub emoji: bool,
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        Self { emoji: true }
+    }
+}
+
+impl Glyphs {
+    pub fn new(emoji: bool) -> Self {
+        Self { emoji }
+    }
+
+    /// Flagged (impediment) issues
+    pub fn flag(self) -> &'static str {
+        if self.emoji { "🚩" } else { "[!]" }
+    }
+
+    /// Issues with a due date
+    pub fn due(self) -> &'static str {
+        if self.emoji { "⏰" } else { "due" }
+    }
+
+    /// Priority marker in the sprint list, two columns wide; blank for
+    /// priorities other than Jira's default five
+    pub fn priority(self, priority: Option<&str>) -> &'static str {
+        match (self.emoji, priority) {
+            (true, Some("Highest")) => "⇈ ",
+            (true, Some("High")) => "↑ ",
+            (true, Some("Medium")) => "= ",
+            (true, Some("Low")) => "↓ ",
+            (true, Some("Lowest")) => "⇊ ",
+            (false, Some("Highest")) => "^^",
+            (false, Some("High")) => "^ ",
+            (false, Some("Medium")) => "= ",
+            (false, Some("Low")) => "v ",
+            (false, Some("Lowest")) => "vv",
+            _ => "  ",
+        }
+    }
+
+    /// Frame `frame` of the loading spinner, cycling
+    pub fn spinner(self, frame: usize) -> &'static str {
+        const BRAILLE: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+        const ASCII: [&str; 4] = ["|", "/", "-", "\\"];
+        if self.emoji {
+            BRAILLE[frame % BRAILLE.len()]
+        } else {
+            ASCII[frame % ASCII.len()]
+        }
+    }
+
+    pub fn board_type(self, board_type: &str) -> &'static str {
+        match (self.emoji, board_type) {
+            (true, "scrum") => "🏃",
+            (true, "kanban") => "📋",
+            (true, "simple") => "📝",
+            (true, _) => "📊",
+            (false, "scrum") => "[S]",
+            (false, "kanban") => "[K]",
+            (false, "simple") => "[B]",
+            (false, _) => "[?]",
+        }
+    }
+
+    pub fn project_type(self, project_type: &str) -> &'static str {
+        match (self.emoji, project_type) {
+            (true, "software") => "💻",
+            (true, "service_desk") => "🎧",
+            (true, "business") => "📊",
+            (true, _) => "📁",
+            (false, "software") => "[SW]",
+            (false, "service_desk") => "[SD]",
+            (false, "business") => "[BU]",
+            (false, _) => "[P]",
+        }
+    }
+}
+
+/// Whether `text` holds an emoji, judged by the Unicode blocks they live in
+pub fn has_emoji(text: &str) -> bool {
+    text.chars().any(|c| {
+        matches!(c as u32, 0x1F000..=0x1FAFF | 0x2600..=0x26FF | 0x2B00..=0x2BFF | 0x23E9..=0x23FA)
+    })
+}
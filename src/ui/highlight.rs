@@ -0,0 +1,285 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Row, Table, Widget},
+};
+use crate::jira::adf;
+use crate::ui::theme::Theme;
+
+/// Blank columns between table cells
+const COLUMN_SPACING: u16 = 2;
+
+/// The theme's code background sets code apart from prose
+fn code_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.code).bg(theme.code_bg)
+}
+
+/// Split rich text into lines, styling ```-fenced code blocks and laying out
+/// tables fenced as ```table (which is how ADF `codeBlock` and `table` nodes
+/// are flattened) to fit `width` columns
+pub fn text_lines(text: &str, width: u16, theme: &Theme) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    // Language and body of the fenced block being collected
+    let mut block: Option<(String, Vec<&str>)> = None;
+    for line in text.lines() {
+        let fence = line.trim_start().strip_prefix("```");
+        match (block.take(), fence) {
+            (None, Some(language)) => block = Some((language.trim().to_string(), Vec::new())),
+            (Some((language, body)), Some(_)) => lines.extend(block_lines(&language, &body, width, theme)),
+            (Some((language, mut body)), None) => {
+                body.push(line);
+                block = Some((language, body));
+            }
+            (None, None) => lines.push(Line::from(line.to_string())),
+        }
+    }
+    // Unterminated fence: still show the rest as the block
+    if let Some((language, body)) = block {
+        lines.extend(block_lines(&language, &body, width, theme));
+    }
+    lines
+}
+
+fn block_lines(language: &str, body: &[&str], width: u16, theme: &Theme) -> Vec<Line<'static>> {
+    if language == adf::TABLE_FENCE {
+        table_lines(body, width, theme)
+    } else {
+        code_lines(language, body, theme)
+    }
+}
+
+/// Cells of a `| a | b |` row, with escaped `\|` kept as a literal pipe
+fn table_cells(row: &str) -> Vec<String> {
+    let row = row.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = row.strip_suffix('|').filter(|_| !row.ends_with("\\|")).unwrap_or(row);
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = row.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cell.push('|');
+                chars.next();
+            }
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+/// A `|---|:--:|` rule, marking the row above it as the header
+fn is_rule(cells: &[String]) -> bool {
+    cells
+        .iter()
+        .all(|cell| !cell.is_empty() && cell.chars().all(|c| c == '-' || c == ':'))
+}
+
+/// Draw a table with ratatui's `Table` and copy the cells back out as lines, so
+/// it scrolls with the rest of the text. Tables wider than `width` stay as
+/// pipe-delimited text.
+fn table_lines(rows: &[&str], width: u16, theme: &Theme) -> Vec<Line<'static>> {
+    if rows.is_empty() {
+        return Vec::new();
+    }
+    let mut rows: Vec<Vec<String>> = rows.iter().map(|row| table_cells(row)).collect();
+    let header = (rows.len() > 1 && is_rule(&rows[1])).then(|| {
+        rows.remove(1);
+        rows.remove(0)
+    });
+    let columns = header.iter().chain(&rows).map(Vec::len).max().unwrap_or(0);
+    let mut widths = vec![0usize; columns];
+    for row in header.iter().chain(&rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(Span::raw(cell.as_str()).width());
+        }
+    }
+    // Borders plus the gaps between columns, summed in usize so very wide
+    // cells can't overflow
+    let needed = widths.iter().sum::<usize>() + columns.saturating_sub(1) * usize::from(COLUMN_SPACING) + 2;
+    let height = rows.len() + usize::from(header.is_some()) + 2;
+    if needed > usize::from(width) || height > usize::from(u16::MAX) {
+        return pipe_lines(header.as_ref(), &rows, theme);
+    }
+
+    // Both fit in a u16 now
+    let area = Rect::new(0, 0, needed as u16, height as u16);
+    let mut buffer = Buffer::empty(area);
+    let constraints = widths.into_iter().map(|width| Constraint::Length(width as u16));
+    let mut table = Table::new(rows.into_iter().map(Row::new), constraints)
+        .column_spacing(COLUMN_SPACING)
+        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.hint)));
+    if let Some(header) = header {
+        table = table.header(
+            Row::new(header)
+                .style(Style::default().fg(theme.border).add_modifier(Modifier::BOLD | Modifier::UNDERLINED)),
+        );
+    }
+    Widget::render(table, area, &mut buffer);
+    buffer_lines(&buffer)
+}
+
+fn pipe_lines(header: Option<&Vec<String>>, rows: &[Vec<String>], theme: &Theme) -> Vec<Line<'static>> {
+    let row_text = |row: &Vec<String>| {
+        let cells: Vec<String> = row.iter().map(|cell| cell.replace('|', "\\|")).collect();
+        format!("| {} |", cells.join(" | "))
+    };
+    let mut lines = Vec::new();
+    if let Some(header) = header {
+        let style = Style::default().fg(theme.border).add_modifier(Modifier::BOLD);
+        lines.push(Line::from(Span::styled(row_text(header), style)));
+    }
+    lines.extend(rows.iter().map(|row| Line::from(row_text(row))));
+    lines
+}
+
+/// One line per buffer row, merging neighbouring cells of the same style
+fn buffer_lines(buffer: &Buffer) -> Vec<Line<'static>> {
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .map(|y| {
+            let mut spans: Vec<Span<'static>> = Vec::new();
+            let mut skip = 0;
+            for x in area.left()..area.right() {
+                // Cells after a wide char are padding
+                if skip > 0 {
+                    skip -= 1;
+                    continue;
+                }
+                let cell = buffer.get(x, y);
+                skip = Span::raw(cell.symbol()).width().saturating_sub(1);
+                let style = cell.style();
+                match spans.last_mut() {
+                    Some(span) if span.style == style => span.content.to_mut().push_str(cell.symbol()),
+                    _ => spans.push(Span::styled(cell.symbol().to_string(), style)),
+                }
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+#[cfg_attr(not(feature = "syntax-highlight"), allow(unused_variables))]
+fn code_lines(language: &str, body: &[&str], theme: &Theme) -> Vec<Line<'static>> {
+    #[cfg(feature = "syntax-highlight")]
+    if let Some(lines) = syntax::highlight(language, body, theme) {
+        return lines;
+    }
+    // Unknown language: plain code styling
+    body.iter()
+        .map(|line| Line::from(Span::styled(line.to_string(), code_style(theme))))
+        .collect()
+}
+
+#[cfg(feature = "syntax-highlight")]
+mod syntax {
+    use super::code_style;
+    use crate::ui::theme::Theme as UiTheme;
+    use ratatui::{
+        style::Color,
+        text::{Line, Span},
+    };
+    use std::sync::OnceLock;
+    use syntect::{
+        easy::HighlightLines,
+        highlighting::{Theme, ThemeSet},
+        parsing::SyntaxSet,
+    };
+
+    /// Loading the bundled syntaxes takes a noticeable moment, so do it once
+    fn assets() -> &'static (SyntaxSet, Theme) {
+        static ASSETS: OnceLock<(SyntaxSet, Theme)> = OnceLock::new();
+        ASSETS.get_or_init(|| {
+            let mut themes = ThemeSet::load_defaults().themes;
+            let theme = themes.remove("base16-ocean.dark").unwrap_or_default();
+            (SyntaxSet::load_defaults_newlines(), theme)
+        })
+    }
+
+    /// Highlight `body` as `language` (a name or file extension such as
+    /// "rust", "py" or "json"); `None` if the language is not recognised
+    pub fn highlight(language: &str, body: &[&str], ui_theme: &UiTheme) -> Option<Vec<Line<'static>>> {
+        if language.is_empty() {
+            return None;
+        }
+        let (syntaxes, theme) = assets();
+        let syntax = syntaxes.find_syntax_by_token(language)?;
+        let mut highlighter = HighlightLines::new(syntax, theme);
+        body.iter()
+            .map(|line| {
+                let line = format!("{}\n", line);
+                let ranges = highlighter.highlight_line(&line, syntaxes).ok()?;
+                let spans: Vec<Span<'static>> = ranges
+                    .into_iter()
+                    .filter(|(_, text)| *text != "\n")
+                    .map(|(style, text)| {
+                        let fg = style.foreground;
+                        Span::styled(
+                            text.trim_end_matches('\n').to_string(),
+                            code_style(ui_theme).fg(Color::Rgb(fg.r, fg.g, fg.b)),
+                        )
+                    })
+                    .collect();
+                Some(Line::from(spans))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.spans.iter().map(|span| span.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn table_cells_split_on_unescaped_pipes() {
+        assert_eq!(table_cells("| a | b |"), vec!["a", "b"]);
+        assert_eq!(table_cells("  | pass \\| flaky |  | x |"), vec!["pass | flaky", "", "x"]);
+        assert_eq!(table_cells("|"), vec![""]);
+        assert_eq!(table_cells("é"), vec!["é"]);
+    }
+
+    #[test]
+    fn rules_are_dashes_and_colons_only() {
+        let cells = |row: &str| table_cells(row);
+        assert!(is_rule(&cells("|---|---|")));
+        assert!(is_rule(&cells("| :-- | :-: | --: |")));
+        assert!(!is_rule(&cells("| --- | |")));
+        assert!(!is_rule(&cells("| a | --- |")));
+    }
+
+    #[test]
+    fn only_fenced_tables_are_laid_out() {
+        let theme = Theme::default();
+        let lines = text(&text_lines("| not | a table |", 80, &theme));
+        assert_eq!(lines, vec!["| not | a table |"]);
+
+        let lines = text(&text_lines("```table\n| a | b |\n|---|---|\n| 1 | 2 |\n```", 80, &theme));
+        assert_eq!(lines.len(), 4, "borders, header and one row: {:?}", lines);
+        assert!(lines[0].starts_with('┌'));
+        assert!(lines[1].contains('a') && lines[1].contains('b'));
+    }
+
+    #[test]
+    fn tables_too_wide_fall_back_to_pipe_rows() {
+        let theme = Theme::default();
+        let lines = text(&text_lines("```table\n| Env | Result |\n|---|---|\n| staging | pass \\| flaky |\n```", 20, &theme));
+        assert_eq!(lines, vec!["| Env | Result |", "| staging | pass \\| flaky |"]);
+
+        // Cell widths that would overflow a u16 when summed
+        let wide = "x".repeat(40_000);
+        let table = format!("```table\n| {} | {} |\n```", wide, wide);
+        let lines = text(&text_lines(&table, 80, &theme));
+        assert_eq!(lines, vec![format!("| {} | {} |", wide, wide)]);
+    }
+}
@@ -0,0 +1,49 @@
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use ratatui::{
+    style::{Color, Style},
+    text::Span,
+};
+use crate::config::IssueTypeIcon;
+use crate::ui::glyphs::{self, Glyphs};
+use crate::ui::theme::Theme;
+
+/// Shown for issue types with no configured icon
+const DEFAULT_GLYPH: &str = "•";
+
+/// Issue type glyphs resolved from `ui.issue_type_icons`, keyed by lowercase name
+#[derive(Debug, Clone, Default)]
+pub struct IssueTypeIcons {
+    /// Glyph and color; `None` for the theme's muted color
+    icons: HashMap<String, (String, Option<Color>)>,
+}
+
+impl IssueTypeIcons {
+    /// Unparseable colors fall back to the theme's muted color, and emoji glyphs to
+    /// the type's initial when `glyphs` has emoji off
+    pub fn from_config(config: &BTreeMap<String, IssueTypeIcon>, glyphs: Glyphs) -> Self {
+        let icons = config
+            .iter()
+            .map(|(name, icon)| {
+                let color = Color::from_str(&icon.color).ok();
+                let glyph = if !glyphs.emoji && glyphs::has_emoji(&icon.glyph) {
+                    name.chars().next().map_or(DEFAULT_GLYPH.to_string(), |c| c.to_uppercase().to_string())
+                } else {
+                    icon.glyph.clone()
+                };
+                (name.to_lowercase(), (glyph, color))
+            })
+            .collect();
+        Self { icons }
+    }
+
+    /// The colored glyph for `issue_type`, followed by a space
+    pub fn span(&self, issue_type: &str, theme: &Theme) -> Span<'static> {
+        let (glyph, color) = self
+            .icons
+            .get(&issue_type.to_lowercase())
+            .map(|(glyph, color)| (glyph.as_str(), *color))
+            .unwrap_or((DEFAULT_GLYPH, None));
+        Span::styled(format!("{} ", glyph), Style::default().fg(color.unwrap_or(theme.muted)))
+    }
+}
@@ -0,0 +1,55 @@
+use crate::jira::Issue;
+
+/// Comments past this many are left out of the copy
+const MAX_COMMENTS: usize = 3;
+
+/// Format an issue as Markdown for pasting into docs or chat. Only the listed
+/// `sections` ("link", "status", "assignee", "description", "comments") follow
+/// the key/summary heading, always in that order.
+pub fn issue(issue: &Issue, sections: &[String], url: &str) -> String {
+    let wants = |section: &str| sections.iter().any(|s| s == section);
+    let fields = &issue.fields;
+
+    let mut out = format!("### {}: {}\n", issue.key, fields.summary);
+    let mut facts = Vec::new();
+    if wants("link") {
+        facts.push(format!("- **Link:** [{}]({})", issue.key, url));
+    }
+    if wants("status") {
+        facts.push(format!("- **Status:** {}", fields.status.name));
+    }
+    if wants("assignee") {
+        let assignee = fields.assignee.as_ref().map_or("Unassigned", |u| u.display_name.as_str());
+        facts.push(format!("- **Assignee:** {}", assignee));
+    }
+    if !facts.is_empty() {
+        out.push('\n');
+        out.push_str(&facts.join("\n"));
+        out.push('\n');
+    }
+
+    if wants("description")
+        && let Some(description) = fields.description.as_deref().filter(|d| !d.trim().is_empty())
+    {
+        out.push_str(&format!("\n#### Description\n\n{}\n", description.trim_end()));
+    }
+
+    let comments = fields.comment.as_ref().map_or(&[][..], |c| c.comments.as_slice());
+    if wants("comments") && !comments.is_empty() {
+        out.push_str("\n#### Comments\n");
+        for comment in comments.iter().take(MAX_COMMENTS) {
+            out.push_str(&format!("\n> **{}:**\n", comment.author.display_name));
+            for line in comment.body.lines() {
+                if line.is_empty() {
+                    out.push_str(">\n");
+                } else {
+                    out.push_str(&format!("> {}\n", line));
+                }
+            }
+        }
+        if comments.len() > MAX_COMMENTS {
+            out.push_str(&format!("\n_{} more comment(s) on the issue_\n", comments.len() - MAX_COMMENTS));
+        }
+    }
+    out
+}
@@ -1,6 +1,20 @@
 pub mod app;
+pub mod clipboard;
+pub mod color_depth;
 pub mod components;
+pub mod csv_export;
+pub mod diff;
+pub mod due_date;
 pub mod events;
+pub mod fuzzy;
+pub mod glyphs;
+pub mod highlight;
+pub mod icons;
+pub mod markdown;
+pub mod standup;
+pub mod status_counts;
+pub mod theme;
+pub mod time_spent;
 
 pub use app::App;
 pub use events::EventHandler;
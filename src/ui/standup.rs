@@ -0,0 +1,141 @@
+use crate::jira::Issue;
+use chrono::{DateTime, Local};
+
+/// Format `account_id`'s in-progress issues and the ones finished today as a
+/// bullet list for an async standup. `group` is "status" (each workflow
+/// status) or anything else for In Progress / Done; `item` is the line template with
+/// `{key}`, `{summary}` and `{status}` placeholders.
+pub fn summary(issues: &[Issue], account_id: &str, group: &str, item: &str, now: DateTime<Local>) -> String {
+    let today = now.date_naive();
+    let mine = issues.iter().filter(|issue| {
+        issue
+            .fields
+            .assignee
+            .as_ref()
+            .is_some_and(|u| u.account_id == account_id)
+    });
+
+    let mut groups: Vec<(String, Vec<&Issue>)> = Vec::new();
+    for issue in mine {
+        let finished = issue.fields.resolutiondate.or(issue.fields.updated);
+        let relevant = match issue.fields.status.status_category.key.as_str() {
+            "indeterminate" => true,
+            "done" => finished.is_some_and(|at| at.with_timezone(&Local).date_naive() == today),
+            _ => false,
+        };
+        if !relevant {
+            continue;
+        }
+        let name = if group == "status" {
+            issue.fields.status.name.clone()
+        } else if issue.is_done() {
+            "Done".to_string()
+        } else {
+            "In Progress".to_string()
+        };
+        match groups.iter_mut().find(|(group_name, _)| *group_name == name) {
+            Some((_, issues)) => issues.push(issue),
+            None => groups.push((name, vec![issue])),
+        }
+    }
+    // In-progress work first, then what got finished
+    groups.sort_by_key(|(_, issues)| issues[0].is_done());
+
+    let mut out = String::new();
+    for (name, issues) in groups {
+        out.push_str(&format!("{}:\n", name));
+        for issue in issues {
+            out.push_str(&fill(item, issue));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Substitute the placeholders of `item` in one pass, so a summary that
+/// happens to contain `{status}` is kept as written
+fn fill(item: &str, issue: &Issue) -> String {
+    let placeholders = [
+        ("{key}", issue.key.as_str()),
+        ("{summary}", issue.fields.summary.as_str()),
+        ("{status}", issue.fields.status.name.as_str()),
+    ];
+    let mut line = String::new();
+    let mut rest = item;
+    while let Some(c) = rest.chars().next() {
+        match placeholders.iter().find(|(placeholder, _)| rest.starts_with(placeholder)) {
+            Some((placeholder, value)) => {
+                line.push_str(value);
+                rest = &rest[placeholder.len()..];
+            }
+            None => {
+                line.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn issue(key: &str, assignee: &str, status: &str, category: &str, resolved: Option<DateTime<Local>>) -> Issue {
+        serde_json::from_value(json!({
+            "id": key,
+            "key": key,
+            "fields": {
+                "summary": format!("Summary of {}", key),
+                "status": {
+                    "id": "1",
+                    "name": status,
+                    "statusCategory": { "id": 2, "name": status, "key": category }
+                },
+                "issuetype": { "id": "10001", "name": "Story" },
+                "assignee": { "accountId": assignee, "displayName": assignee },
+                "resolutiondate": resolved.map(|at| at.to_rfc3339())
+            }
+        }))
+        .unwrap()
+    }
+
+    fn issues(now: DateTime<Local>) -> Vec<Issue> {
+        vec![
+            issue("A-1", "me", "In Review", "indeterminate", None),
+            issue("A-2", "me", "Done", "done", Some(now - chrono::Duration::hours(1))),
+            issue("A-3", "me", "Done", "done", Some(now - chrono::Duration::days(1))),
+            issue("A-4", "me", "To Do", "new", None),
+            issue("A-5", "someone", "In Progress", "indeterminate", None),
+            issue("A-6", "me", "In Progress", "indeterminate", None),
+        ]
+    }
+
+    #[test]
+    fn only_my_work_in_progress_and_done_today() {
+        let now = Local.with_ymd_and_hms(2024, 5, 31, 15, 0, 0).unwrap();
+        let text = summary(&issues(now), "me", "category", "- {key}", now);
+        assert_eq!(text, "In Progress:\n- A-1\n- A-6\nDone:\n- A-2\n");
+    }
+
+    #[test]
+    fn status_grouping_lists_each_status() {
+        let now = Local.with_ymd_and_hms(2024, 5, 31, 15, 0, 0).unwrap();
+        let text = summary(&issues(now), "me", "status", "- {key} ({status})", now);
+        assert_eq!(
+            text,
+            "In Review:\n- A-1 (In Review)\nIn Progress:\n- A-6 (In Progress)\nDone:\n- A-2 (Done)\n"
+        );
+    }
+
+    #[test]
+    fn placeholders_in_summaries_are_kept() {
+        let now = Local.with_ymd_and_hms(2024, 5, 31, 15, 0, 0).unwrap();
+        let mut wip = issue("A-1", "me", "In Review", "indeterminate", None);
+        wip.fields.summary = "Show {status} and {key} literally".to_string();
+        let text = summary(&[wip], "me", "category", "{key}: {summary} [{status}] {other}", now);
+        assert_eq!(text, "In Progress:\nA-1: Show {status} and {key} literally [In Review] {other}\n");
+    }
+}
@@ -0,0 +1,46 @@
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+use crate::jira::Issue;
+use crate::ui::theme::Theme;
+
+/// Status category keys in workflow order
+const CATEGORIES: [&str; 3] = ["new", "indeterminate", "done"];
+
+/// Position of a status category in workflow order; unknown categories come last
+pub fn category_rank(key: &str) -> usize {
+    CATEGORIES.iter().position(|k| *k == key).unwrap_or(CATEGORIES.len())
+}
+
+/// Number of issues for a list title, e.g. "42 issues"
+pub fn issue_count(count: usize) -> String {
+    format!("{} issue{}", count, if count == 1 { "" } else { "s" })
+}
+
+/// One-line summary of issues per status category, e.g.
+/// "To Do: 8 | In Progress: 5 | Done: 12"
+pub fn footer(issues: &[Issue], theme: &Theme) -> Line<'static> {
+    // (key, name, count) in order of first appearance
+    let mut counts: Vec<(String, String, usize)> = Vec::new();
+    for issue in issues {
+        let category = &issue.fields.status.status_category;
+        match counts.iter_mut().find(|(key, _, _)| *key == category.key) {
+            Some((_, _, count)) => *count += 1,
+            None => counts.push((category.key.clone(), category.name.clone(), 1)),
+        }
+    }
+    // Known categories first, in workflow order; anything else keeps its place after them
+    counts.sort_by_key(|(key, _, _)| category_rank(key));
+
+    let mut spans = vec![Span::raw(" ")];
+    for (i, (key, name, count)) in counts.into_iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" | ", Style::default().fg(theme.hint)));
+        }
+        let color = theme.category_color(&key);
+        spans.push(Span::styled(format!("{}: {}", name, count), Style::default().fg(color)));
+    }
+    spans.push(Span::raw(" "));
+    Line::from(spans)
+}
@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use anyhow::{anyhow, Result};
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+};
+use crate::config::Config;
+use crate::jira::Status;
+
+/// Built-in values of `ui.theme`; any other name is looked up as
+/// `themes/<name>.json` in the config directory
+pub const THEMES: [&str; 4] = ["default", "dark", "light", "high-contrast"];
+
+/// Named colors the views draw with, chosen by `ui.theme`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// Background of the status bar
+    pub status_bar: Color,
+    /// Background of the selected row in lists
+    pub highlight: Color,
+    /// Borders and titles of popups and headers
+    pub border: Color,
+    pub status_todo: Color,
+    pub status_in_progress: Color,
+    pub status_done: Color,
+    pub priority_high: Color,
+    pub priority_medium: Color,
+    pub priority_low: Color,
+    /// Text in lists, inputs and popups
+    pub text: Color,
+    /// Secondary text such as list titles and closed sprints
+    pub muted: Color,
+    /// Hints and separators
+    pub hint: Color,
+    /// Headings, the current item and warning borders
+    pub accent: Color,
+    /// Marks that are neither good nor bad, e.g. future sprints
+    pub info: Color,
+    pub success: Color,
+    pub error: Color,
+    /// Background of popups and toasts
+    pub popup: Color,
+    /// Text on accent and error backgrounds, e.g. status bar badges
+    pub on_accent: Color,
+    /// Issues marked for bulk actions
+    pub marked: Color,
+    /// Code blocks in descriptions; highlighted code keeps its own colors on `code_bg`
+    pub code: Color,
+    pub code_bg: Color,
+    /// Recolor the finished frame as bright text on black, with selections
+    /// as black on white
+    pub high_contrast: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            status_bar: Color::Black,
+            highlight: Color::LightBlue,
+            border: Color::Cyan,
+            status_todo: Color::Red,
+            status_in_progress: Color::Yellow,
+            status_done: Color::Green,
+            priority_high: Color::Red,
+            priority_medium: Color::Yellow,
+            priority_low: Color::Green,
+            text: Color::White,
+            muted: Color::Gray,
+            hint: Color::DarkGray,
+            accent: Color::Yellow,
+            info: Color::Blue,
+            success: Color::Green,
+            error: Color::Red,
+            popup: Color::Black,
+            on_accent: Color::Black,
+            marked: Color::Magenta,
+            code: Color::Gray,
+            code_bg: Color::Rgb(40, 44, 52),
+            high_contrast: false,
+        }
+    }
+}
+
+impl Theme {
+    /// Brighter colors for dark terminal backgrounds
+    fn dark() -> Self {
+        Self {
+            status_bar: Color::Rgb(40, 44, 52),
+            highlight: Color::Blue,
+            border: Color::LightCyan,
+            status_todo: Color::LightRed,
+            status_in_progress: Color::LightYellow,
+            status_done: Color::LightGreen,
+            priority_high: Color::LightRed,
+            priority_medium: Color::LightYellow,
+            priority_low: Color::LightGreen,
+            text: Color::White,
+            muted: Color::Gray,
+            hint: Color::DarkGray,
+            accent: Color::LightYellow,
+            info: Color::LightBlue,
+            success: Color::LightGreen,
+            error: Color::LightRed,
+            popup: Color::Black,
+            on_accent: Color::Black,
+            marked: Color::LightMagenta,
+            code: Color::Gray,
+            code_bg: Color::Rgb(40, 44, 52),
+            high_contrast: false,
+        }
+    }
+
+    /// Darker colors that stay readable on light terminal backgrounds
+    fn light() -> Self {
+        Self {
+            highlight: Color::LightCyan,
+            border: Color::Blue,
+            status_in_progress: Color::Blue,
+            priority_medium: Color::Rgb(204, 122, 0),
+            text: Color::Black,
+            muted: Color::DarkGray,
+            hint: Color::Gray,
+            accent: Color::Rgb(204, 122, 0),
+            popup: Color::White,
+            on_accent: Color::White,
+            ..Self::default()
+        }
+    }
+
+    /// The theme named by `ui.theme`. Names other than the built-in ones are
+    /// read from `themes/<name>.json` in the config directory, e.g.
+    /// `{"base": "dark", "highlight": "#3b4252", "status_done": "green"}`
+    pub fn load(name: &str) -> Result<Self> {
+        match name {
+            "" | "default" => Ok(Self::default()),
+            "dark" => Ok(Self::dark()),
+            "light" => Ok(Self::light()),
+            "high-contrast" => Ok(Self { high_contrast: true, ..Self::default() }),
+            custom => {
+                let path = Config::dir()?.join("themes").join(format!("{}.json", custom));
+                let content = std::fs::read_to_string(&path)
+                    .map_err(|e| anyhow!("Theme '{}' not found at {}: {}", custom, path.display(), e))?;
+                Self::from_json(&content).map_err(|e| anyhow!("Theme '{}': {}", custom, e))
+            }
+        }
+    }
+
+    /// A theme file: colors as names ("red", "lightblue") or "#rrggbb" on top
+    /// of a built-in `base` theme
+    fn from_json(content: &str) -> Result<Self> {
+        let mut slots: BTreeMap<String, String> = serde_json::from_str(content)?;
+        let mut theme = match slots.remove("base") {
+            Some(base) if THEMES.contains(&base.as_str()) => Self::load(&base)?,
+            Some(base) => return Err(anyhow!("base '{}' is not one of {}", base, THEMES.join(", "))),
+            None => Self::default(),
+        };
+        for (slot, value) in slots {
+            let color = Color::from_str(&value).map_err(|_| anyhow!("'{}' is not a color", value))?;
+            *theme.slot_mut(&slot).ok_or_else(|| anyhow!("unknown color slot '{}'", slot))? = color;
+        }
+        Ok(theme)
+    }
+
+    fn slot_mut(&mut self, name: &str) -> Option<&mut Color> {
+        Some(match name {
+            "status_bar" => &mut self.status_bar,
+            "highlight" => &mut self.highlight,
+            "border" => &mut self.border,
+            "status_todo" => &mut self.status_todo,
+            "status_in_progress" => &mut self.status_in_progress,
+            "status_done" => &mut self.status_done,
+            "priority_high" => &mut self.priority_high,
+            "priority_medium" => &mut self.priority_medium,
+            "priority_low" => &mut self.priority_low,
+            "text" => &mut self.text,
+            "muted" => &mut self.muted,
+            "hint" => &mut self.hint,
+            "accent" => &mut self.accent,
+            "info" => &mut self.info,
+            "success" => &mut self.success,
+            "error" => &mut self.error,
+            "popup" => &mut self.popup,
+            "on_accent" => &mut self.on_accent,
+            "marked" => &mut self.marked,
+            "code" => &mut self.code,
+            "code_bg" => &mut self.code_bg,
+            _ => return None,
+        })
+    }
+
+    /// Color of a status, by its category so custom workflow statuses match
+    pub fn status_color(&self, status: &Status) -> Color {
+        self.category_color(&status.status_category.key)
+    }
+
+    /// Color of a status category key: "new", "indeterminate" or "done"
+    pub fn category_color(&self, key: &str) -> Color {
+        match key {
+            "new" => self.status_todo,
+            "indeterminate" => self.status_in_progress,
+            "done" => self.status_done,
+            _ => self.text,
+        }
+    }
+
+    pub fn priority_color(&self, priority: Option<&str>) -> Color {
+        match priority {
+            Some("Highest") | Some("High") => self.priority_high,
+            Some("Medium") => self.priority_medium,
+            Some("Low") | Some("Lowest") => self.priority_low,
+            _ => self.text,
+        }
+    }
+
+    /// Finish a rendered frame: only the high-contrast theme recolors it
+    pub fn apply(&self, buffer: &mut Buffer) {
+        if !self.high_contrast {
+            return;
+        }
+        for cell in buffer.content.iter_mut() {
+            if matches!(cell.bg, Color::Reset | Color::Black) {
+                cell.bg = Color::Black;
+                cell.fg = bright(cell.fg);
+            } else {
+                // Highlighted rows, chips and badges
+                cell.bg = Color::White;
+                cell.fg = Color::Black;
+                cell.modifier.insert(Modifier::BOLD);
+            }
+            cell.modifier.remove(Modifier::DIM);
+        }
+    }
+}
+
+fn bright(color: Color) -> Color {
+    match color {
+        Color::Reset | Color::Black | Color::Gray | Color::DarkGray => Color::White,
+        Color::Red => Color::LightRed,
+        Color::Green => Color::LightGreen,
+        Color::Yellow => Color::LightYellow,
+        // Dark blue is hard to read on black
+        Color::Blue | Color::LightBlue | Color::Cyan => Color::LightCyan,
+        Color::Magenta => Color::LightMagenta,
+        other => other,
+    }
+}
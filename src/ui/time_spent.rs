@@ -0,0 +1,67 @@
+/// Time typed into the log-work prompt, in Jira's notation: whole or decimal
+/// numbers of weeks, days, hours and minutes such as "1h 30m", "2d" or "1.5h".
+/// Returns the duration normalized to space-separated parts ("1h30m" → "1h 30m").
+pub fn parse(input: &str) -> Result<String, String> {
+    let invalid = || format!("'{}' is not a duration like 1h 30m or 2d", input.trim());
+    let mut parts = Vec::new();
+    let mut number = String::new();
+    let mut nonzero = false;
+    for c in input.trim().to_lowercase().chars() {
+        match c {
+            '0'..='9' | '.' => number.push(c),
+            'w' | 'd' | 'h' | 'm' if !number.is_empty() => {
+                let value: f64 = number.parse().map_err(|_| invalid())?;
+                nonzero |= value > 0.0;
+                parts.push(format!("{}{}", number, c));
+                number.clear();
+            }
+            c if c.is_whitespace() && number.is_empty() => {}
+            _ => return Err(invalid()),
+        }
+    }
+    if !number.is_empty() || parts.is_empty() {
+        return Err(invalid());
+    }
+    if !nonzero {
+        return Err("Log more than no time".to_string());
+    }
+    Ok(parts.join(" "))
+}
+
+/// Total of `seconds` in hours and minutes, e.g. "12h 30m"; days are left
+/// out since Jira's working day length is configurable
+pub fn format_total(seconds: u64) -> String {
+    let (hours, minutes) = (seconds / 3600, seconds / 60 % 60);
+    match (hours, minutes) {
+        (0, _) => format!("{}m", minutes),
+        (_, 0) => format!("{}h", hours),
+        _ => format!("{}h {}m", hours, minutes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_are_normalized() {
+        assert_eq!(parse("1h 30m"), Ok("1h 30m".to_string()));
+        assert_eq!(parse("1h30m"), Ok("1h 30m".to_string()));
+        assert_eq!(parse("2d"), Ok("2d".to_string()));
+        assert_eq!(parse(" 1.5H "), Ok("1.5h".to_string()));
+    }
+
+    #[test]
+    fn malformed_durations_are_rejected() {
+        for input in ["", "5x", "h", "30", "0m"] {
+            assert!(parse(input).is_err(), "{:?} was accepted", input);
+        }
+    }
+
+    #[test]
+    fn totals_leave_out_zero_parts() {
+        assert_eq!(format_total(45 * 60), "45m");
+        assert_eq!(format_total(2 * 3600), "2h");
+        assert_eq!(format_total(12 * 3600 + 30 * 60), "12h 30m");
+    }
+}